@@ -0,0 +1,103 @@
+#ifndef WORDLE_GENERATOR_H
+#define WORDLE_GENERATOR_H
+
+/* This file is generated by cbindgen. Do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The status code returned by the fallible functions.
+ */
+typedef enum WordleStatus {
+  /**
+   * The call succeeded.
+   */
+  WORDLE_STATUS_OK = 0,
+  /**
+   * A required pointer argument is null.
+   */
+  WORDLE_STATUS_NULL_POINTER = 1,
+  /**
+   * A word is not valid UTF-8.
+   */
+  WORDLE_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The seed is neither a hexadecimal nor a base64 string of 32 bytes.
+   */
+  WORDLE_STATUS_INVALID_SEED = 3,
+  /**
+   * The word list is empty.
+   */
+  WORDLE_STATUS_EMPTY_WORDS = 4,
+  /**
+   * The window length is zero.
+   */
+  WORDLE_STATUS_ZERO_WINDOW_LEN = 5,
+  /**
+   * The configuration is invalid for another reason.
+   */
+  WORDLE_STATUS_INVALID_CONFIG = 6,
+} WordleStatus;
+
+/**
+ * An opaque generator over a list of words.
+ */
+typedef struct Wordle Wordle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Creates a generator over `words_len` NUL-terminated UTF-8 words.
+ *
+ * The seed is a NUL-terminated hexadecimal or base64 string. The words are
+ * copied, so they can be freed once this returns. On success, the handle is
+ * written to `out`, and must be released via [`wordle_free`].
+ *
+ * # Safety
+ *
+ * `words` must point to `words_len` valid NUL-terminated strings, and
+ * `seed` must be a valid NUL-terminated string. `out` must be valid for writes.
+ */
+enum WordleStatus wordle_new(const char *const *words,
+                             uintptr_t words_len,
+                             uint64_t window_len,
+                             const char *seed,
+                             struct Wordle **out);
+
+/**
+ * Writes the NUL-terminated word for the given day to `out`.
+ *
+ * The word is owned by the generator, and stays valid until it is freed.
+ *
+ * # Safety
+ *
+ * `wordle` must be a handle returned by [`wordle_new`] that wasn't freed,
+ * and `out` must be valid for writes.
+ */
+enum WordleStatus wordle_get(const struct Wordle *wordle, uint64_t day, const char **out);
+
+/**
+ * Releases a generator. Does nothing if `wordle` is null.
+ *
+ * # Safety
+ *
+ * `wordle` must be null or a handle returned by [`wordle_new`] that wasn't
+ * freed already.
+ */
+void wordle_free(struct Wordle *wordle);
+
+/**
+ * Returns a static NUL-terminated description of the status.
+ */
+const char *wordle_status_message(enum WordleStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* WORDLE_GENERATOR_H */
@@ -0,0 +1,214 @@
+//! C bindings for the `wordle-generator` crate.
+//!
+//! Generators are exposed as opaque handles, created by [`wordle_new`] and
+//! released by [`wordle_free`]. Every fallible function returns a
+//! [`WordleStatus`], and writes its result through an out pointer.
+
+use std::ffi::{c_char, CStr, CString};
+use wordle_generator::{DailyPicker, Seed, WordleBuilder, WordleError};
+
+/// An opaque generator over a list of words.
+pub struct Wordle {
+    picker: DailyPicker<'static, CString>,
+}
+
+/// The status code returned by the fallible functions.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WordleStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument is null.
+    NullPointer = 1,
+    /// A word is not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The seed is neither a hexadecimal nor a base64 string of 32 bytes.
+    InvalidSeed = 3,
+    /// The word list is empty.
+    EmptyWords = 4,
+    /// The window length is zero.
+    ZeroWindowLen = 5,
+    /// The configuration is invalid for another reason.
+    InvalidConfig = 6,
+}
+
+impl From<WordleError> for WordleStatus {
+    fn from(err: WordleError) -> Self {
+        match err {
+            WordleError::EmptyWords => WordleStatus::EmptyWords,
+            WordleError::ZeroWindowLen => WordleStatus::ZeroWindowLen,
+            WordleError::InvalidSeed(_) => WordleStatus::InvalidSeed,
+            _ => WordleStatus::InvalidConfig,
+        }
+    }
+}
+
+/// Creates a generator over `words_len` NUL-terminated UTF-8 words.
+///
+/// The seed is a NUL-terminated hexadecimal or base64 string. The words are
+/// copied, so they can be freed once this returns. On success, the handle is
+/// written to `out`, and must be released via [`wordle_free`].
+///
+/// # Safety
+///
+/// `words` must point to `words_len` valid NUL-terminated strings, and
+/// `seed` must be a valid NUL-terminated string. `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_new(
+    words: *const *const c_char,
+    words_len: usize,
+    window_len: u64,
+    seed: *const c_char,
+    out: *mut *mut Wordle,
+) -> WordleStatus {
+    if (words.is_null() && words_len > 0) || seed.is_null() || out.is_null() {
+        return WordleStatus::NullPointer;
+    }
+    let mut owned = Vec::with_capacity(words_len);
+    for i in 0..words_len {
+        let word = *words.add(i);
+        if word.is_null() {
+            return WordleStatus::NullPointer;
+        }
+        let word = CStr::from_ptr(word);
+        if word.to_str().is_err() {
+            return WordleStatus::InvalidUtf8;
+        }
+        owned.push(word.to_owned());
+    }
+    let seed: Seed = match CStr::from_ptr(seed).to_str().map(str::parse) {
+        Ok(Ok(seed)) => seed,
+        _ => return WordleStatus::InvalidSeed,
+    };
+    let result = WordleBuilder::from_vec(owned)
+        .seed(seed)
+        .window_len(window_len)
+        .build_picker();
+    match result {
+        Ok(picker) => {
+            *out = Box::into_raw(Box::new(Wordle { picker }));
+            WordleStatus::Ok
+        }
+        Err(err) => err.into(),
+    }
+}
+
+/// Writes the NUL-terminated word for the given day to `out`.
+///
+/// The word is owned by the generator, and stays valid until it is freed.
+///
+/// # Safety
+///
+/// `wordle` must be a handle returned by [`wordle_new`] that wasn't freed,
+/// and `out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_get(
+    wordle: *const Wordle,
+    day: u64,
+    out: *mut *const c_char,
+) -> WordleStatus {
+    if wordle.is_null() || out.is_null() {
+        return WordleStatus::NullPointer;
+    }
+    *out = (*wordle).picker.get(day).as_ptr();
+    WordleStatus::Ok
+}
+
+/// Releases a generator. Does nothing if `wordle` is null.
+///
+/// # Safety
+///
+/// `wordle` must be null or a handle returned by [`wordle_new`] that wasn't
+/// freed already.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_free(wordle: *mut Wordle) {
+    if !wordle.is_null() {
+        drop(Box::from_raw(wordle));
+    }
+}
+
+/// Returns a static NUL-terminated description of the status.
+#[no_mangle]
+pub extern "C" fn wordle_status_message(status: WordleStatus) -> *const c_char {
+    let message: &'static CStr = match status {
+        WordleStatus::Ok => c"ok",
+        WordleStatus::NullPointer => c"null pointer",
+        WordleStatus::InvalidUtf8 => c"word is not valid UTF-8",
+        WordleStatus::InvalidSeed => c"invalid seed",
+        WordleStatus::EmptyWords => c"word list cannot be empty",
+        WordleStatus::ZeroWindowLen => c"window length should be positive",
+        WordleStatus::InvalidConfig => c"invalid configuration",
+    };
+    message.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    unsafe fn new(
+        words: &[&CStr],
+        window_len: u64,
+        seed: &CStr,
+    ) -> Result<*mut Wordle, WordleStatus> {
+        let ptrs: Vec<_> = words.iter().map(|word| word.as_ptr()).collect();
+        let mut out = ptr::null_mut();
+        match wordle_new(
+            ptrs.as_ptr(),
+            ptrs.len(),
+            window_len,
+            seed.as_ptr(),
+            &mut out,
+        ) {
+            WordleStatus::Ok => Ok(out),
+            status => Err(status),
+        }
+    }
+
+    #[test]
+    fn get() {
+        let words = [c"apple", c"grape", c"lemon"];
+        let seed = CString::new(Seed::new([7; 32]).to_string()).unwrap();
+        unsafe {
+            let wordle = new(&words, 3, &seed).unwrap();
+            let mut seen = Vec::new();
+            for day in 0..3 {
+                let mut word = ptr::null();
+                assert_eq!(wordle_get(wordle, day, &mut word), WordleStatus::Ok);
+                seen.push(CStr::from_ptr(word).to_owned());
+            }
+            seen.sort();
+            assert_eq!(seen, words.map(CStr::to_owned));
+            wordle_free(wordle);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let seed = CString::new(Seed::new([7; 32]).to_string()).unwrap();
+        unsafe {
+            assert_eq!(new(&[], 3, &seed).err(), Some(WordleStatus::EmptyWords));
+            assert_eq!(
+                new(&[c"apple"], 0, &seed).err(),
+                Some(WordleStatus::ZeroWindowLen)
+            );
+            assert_eq!(
+                new(&[c"apple"], 3, c"nope").err(),
+                Some(WordleStatus::InvalidSeed)
+            );
+            let invalid = c"\xff";
+            assert_eq!(
+                new(&[invalid], 3, &seed).err(),
+                Some(WordleStatus::InvalidUtf8)
+            );
+
+            let mut word = ptr::null();
+            assert_eq!(
+                wordle_get(ptr::null(), 0, &mut word),
+                WordleStatus::NullPointer
+            );
+            wordle_free(ptr::null_mut());
+        }
+    }
+}
@@ -0,0 +1,142 @@
+//! Python bindings for the `wordle-generator` crate.
+//!
+//! The extension module is built with [maturin](https://www.maturin.rs),
+//! e.g. via `maturin develop` in this directory.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use wordle_generator::{Seed, Wordle, WordleBuilder, WordleError};
+
+fn value_error(err: WordleError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// A secret seed that determines the sequence of picked words.
+///
+/// Seeds are parsed from hexadecimal or base64 strings.
+#[pyclass(name = "Seed", module = "wordle_generator", frozen)]
+#[derive(Clone, Copy)]
+struct PySeed(Seed);
+
+#[pymethods]
+impl PySeed {
+    #[new]
+    fn new(value: &str) -> PyResult<Self> {
+        let seed = value
+            .parse()
+            .map_err(WordleError::from)
+            .map_err(value_error)?;
+        Ok(Self(seed))
+    }
+
+    /// Generates a random seed using the operating system's CSPRNG.
+    #[staticmethod]
+    fn generate() -> Self {
+        Self(Seed::generate())
+    }
+
+    /// Creates a seed from its 32 bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| PyValueError::new_err("seed should be 32 bytes long"))?;
+        Ok(Self(Seed::new(bytes)))
+    }
+
+    /// Returns a fingerprint that identifies the seed without revealing it.
+    fn fingerprint(&self) -> u64 {
+        self.0.fingerprint()
+    }
+
+    fn __bytes__(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> &'static str {
+        // Avoid leaking the seed in logs
+        "Seed(..)"
+    }
+}
+
+/// A cryptographically secure random wordle generator.
+#[pyclass(name = "Wordle", module = "wordle_generator", frozen)]
+struct PyWordle {
+    wordle: Wordle<'static, String>,
+}
+
+#[pymethods]
+impl PyWordle {
+    #[new]
+    #[pyo3(signature = (words, seed, window_len=None))]
+    fn new(words: Vec<String>, seed: PySeed, window_len: Option<u64>) -> PyResult<Self> {
+        let mut builder = WordleBuilder::from_vec(words).seed(seed.0);
+        if let Some(window_len) = window_len {
+            builder = builder.window_len(window_len);
+        }
+        let wordle = builder.build().map_err(value_error)?;
+        Ok(Self { wordle })
+    }
+
+    /// The number of consecutive days in which no word repeats.
+    #[getter]
+    fn window_len(&self) -> u64 {
+        self.wordle.window_len()
+    }
+
+    /// Returns the word for the given day.
+    fn get(&self, day: u64) -> &str {
+        self.wordle.get(day)
+    }
+
+    /// Returns the words for every day in `start..end`.
+    fn get_range(&self, start: u64, end: u64) -> Vec<&str> {
+        self.wordle.get_range(start..end)
+    }
+
+    /// Returns `n` distinct words for the given day.
+    fn get_many(&self, day: u64, n: usize) -> PyResult<Vec<&str>> {
+        self.wordle.try_get_many(day, n).map_err(value_error)
+    }
+
+    /// Returns the words of every day of the given window, in order.
+    fn window_words(&self, window: u64) -> Vec<&str> {
+        self.wordle.window_words(window)
+    }
+
+    /// Returns the index of the word picked on every day of the given window.
+    ///
+    /// This is the permutation of word indices derived by the Feistel network.
+    fn permutation(&self, window: u64) -> Vec<usize> {
+        let start = window.saturating_mul(self.wordle.window_len());
+        self.wordle
+            .window_bounds(start)
+            .map(|day| self.wordle.get_with_index(day).0)
+            .collect()
+    }
+
+    /// Returns the first day of the given window on which `word` is picked.
+    fn day_of(&self, word: &str, window: u64) -> Option<u64> {
+        self.wordle.day_of(word, window)
+    }
+
+    fn __len__(&self) -> usize {
+        self.wordle.word_count()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.wordle)
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "wordle_generator")]
+fn wordle_generator_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PySeed>()?;
+    m.add_class::<PyWordle>()?;
+    Ok(())
+}
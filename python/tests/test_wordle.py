@@ -0,0 +1,33 @@
+import pytest
+
+from wordle_generator import Seed, Wordle
+
+WORDS = ["apple", "grape", "lemon", "mango", "peach"]
+SEED = Seed("07" * 32)
+
+
+def test_seed():
+    assert str(SEED) == "07" * 32
+    assert bytes(SEED) == bytes([7] * 32)
+    assert repr(SEED) == "Seed(..)"
+    assert Seed.from_bytes(bytes(SEED)).fingerprint() == SEED.fingerprint()
+    with pytest.raises(ValueError):
+        Seed("nope")
+
+
+def test_window():
+    wordle = Wordle(WORDS, SEED)
+    assert wordle.window_len == len(WORDS) == len(wordle)
+    assert sorted(wordle.window_words(0)) == sorted(WORDS)
+    assert wordle.get_range(0, 5) == [wordle.get(day) for day in range(5)]
+    assert [WORDS[idx] for idx in wordle.permutation(1)] == wordle.window_words(1)
+    assert wordle.day_of(wordle.get(3), 0) == 3
+
+
+def test_errors():
+    with pytest.raises(ValueError):
+        Wordle([], SEED)
+    with pytest.raises(ValueError):
+        Wordle(WORDS, SEED, window_len=0)
+    with pytest.raises(ValueError):
+        Wordle(WORDS, SEED).get_many(0, 6)
@@ -0,0 +1,107 @@
+use crate::feedback::{encode_pattern, evaluate_letters};
+use crate::{Guess, GuessError, LetterFeedback, Seed};
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+/// An adversarial game, in which the answer is not chosen in advance.
+///
+/// After each guess, the remaining answers are split by the feedback they
+/// would give, and the adversary keeps the largest group, so the game lasts
+/// as long as possible. Ties between groups of the same size are broken by
+/// a key derived from the seed of the day, so every player of the same day
+/// faces the same adversary, while the choices can't be predicted without
+/// the seed.
+///
+/// The game is over once a single answer remains and is guessed. There is
+/// no limit on the number of guesses.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{Seed, Wordle};
+///
+/// let words = ["those", "shoes", "whose", "chose", "hoses"];
+/// let wordle = Wordle::from_seed(&words, 5, Seed::new([0; 32]));
+/// let mut game = wordle.absurdle(0);
+/// game.guess("those").unwrap();
+/// assert!(game.candidates().count() < 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Absurdle<'a> {
+    // The answers consistent with the feedback so far
+    candidates: Vec<&'a str>,
+    guesses: Vec<Guess>,
+    key: Seed,
+}
+
+impl<'a> Absurdle<'a> {
+    /// Creates a game over the given answers, whose ties are broken by `key`.
+    pub(crate) fn new(candidates: Vec<&'a str>, key: Seed) -> Self {
+        Self {
+            candidates,
+            guesses: Vec::new(),
+            key,
+        }
+    }
+
+    /// Returns the answers that are consistent with the feedback so far.
+    pub fn candidates(&self) -> impl ExactSizeIterator<Item = &'a str> + '_ {
+        self.candidates.iter().copied()
+    }
+
+    /// Returns the guesses made so far, in order.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Returns whether the answer was guessed.
+    pub fn is_over(&self) -> bool {
+        self.guesses.last().is_some_and(Guess::is_correct)
+    }
+
+    /// Makes a guess, and returns the feedback for each of its letters.
+    ///
+    /// Returns an error if the game is over, or the guess has a different
+    /// number of letters than the answers.
+    pub fn guess(&mut self, word: &str) -> Result<&[LetterFeedback], GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        let letters: Vec<char> = word.chars().collect();
+        let len = self.candidates[0].chars().count();
+        if letters.len() != len {
+            return Err(GuessError::WordLength {
+                expected: len,
+                found: letters.len(),
+            });
+        }
+        let mut buckets: BTreeMap<u64, (Vec<LetterFeedback>, Vec<&'a str>)> = BTreeMap::new();
+        let mut feedback = vec![LetterFeedback::Absent; len];
+        for &candidate in &self.candidates {
+            let answer: Vec<char> = candidate.chars().collect();
+            evaluate_letters(&letters, &answer, &mut feedback);
+            let bucket = buckets
+                .entry(encode_pattern(&feedback))
+                .or_insert_with(|| (feedback.clone(), Vec::new()));
+            bucket.1.push(candidate);
+        }
+        let (_, (feedback, candidates)) = buckets
+            .into_iter()
+            .max_by_key(|(pattern, (_, candidates))| (candidates.len(), self.tiebreaker(*pattern)))
+            .unwrap(); // there is at least one candidate
+        self.candidates = candidates;
+        self.guesses.push(Guess::new(word, feedback));
+        Ok(self.guesses.last().unwrap().feedback())
+    }
+
+    /// Returns the priority of a feedback pattern among groups of the same size.
+    fn tiebreaker(&self, pattern: u64) -> u64 {
+        let digest = Sha256::new()
+            .chain_update(self.key.as_bytes())
+            .chain_update(pattern.to_le_bytes())
+            .finalize();
+        u64::from_le_bytes(digest[..8].try_into().unwrap())
+    }
+}
@@ -0,0 +1,155 @@
+use crate::feistel::{
+    HalfSipHashRound, HmacSha256Round, RoundFunction, RoundPolicy, SipHashRound, WyHashRound,
+};
+
+/// A version of the algorithm that derives the schedule from the seed.
+///
+/// Every version produces the same schedule forever, so pinning a version
+/// guarantees that published schedules never change when upgrading this
+/// crate. Changes to the internals (e.g. the hash or round function) are
+/// introduced as new versions, which must be selected explicitly via
+/// [`WordleBuilder::algorithm`](crate::WordleBuilder::algorithm).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum Algorithm {
+    /// The original algorithm: round keys derived via SHA-512, and WyHash
+    /// as the round function.
+    ///
+    /// Integers are hashed in native byte order, so big-endian and
+    /// little-endian hosts produce different schedules from the same seed.
+    #[default]
+    V1,
+    /// The same algorithm as [`Algorithm::V1`], but integers are hashed in
+    /// little-endian byte order, so every host produces the same schedule.
+    ///
+    /// On little-endian hosts, this produces the same schedule as [`Algorithm::V1`].
+    V2,
+    /// The same algorithm as [`Algorithm::V2`], but the Feistel networks
+    /// permute the smallest power-of-two domain that holds every word, using
+    /// unbalanced networks for odd bit lengths. Earlier versions round odd bit
+    /// lengths up, which doubles the expected cycle walking for many list sizes.
+    V3,
+    /// The same algorithm as [`Algorithm::V3`], but with SipHash-2-4 as the
+    /// round function, a PRF with better-studied keyed security than WyHash.
+    /// SipHash is keyed by each round key and a key shared by every round,
+    /// both derived from the seed.
+    V4,
+    /// The same algorithm as [`Algorithm::V3`], but with HMAC-SHA-256 as the
+    /// round function, keyed by a key shared by every round that is derived
+    /// from the seed. Together with the SHA-512 key derivation, every
+    /// primitive is NIST-approved.
+    V5,
+    /// The same algorithm as [`Algorithm::V3`], but with HalfSipHash-2-4 as
+    /// the round function, keyed by each round key. HalfSipHash only uses
+    /// 32-bit arithmetic, so this is the fastest version on 32-bit targets,
+    /// and a keyed PRF unlike WyHash.
+    V6,
+}
+
+impl Algorithm {
+    /// Returns the byte representation of `value` that is hashed.
+    pub(crate) fn encode_u64(self, value: u64) -> [u8; 8] {
+        match self {
+            Algorithm::V1 => value.to_ne_bytes(),
+            Algorithm::V2 | Algorithm::V3 | Algorithm::V4 | Algorithm::V5 | Algorithm::V6 => {
+                value.to_le_bytes()
+            }
+        }
+    }
+
+    /// Returns whether integers are hashed in native byte order.
+    pub(crate) fn is_native_endian(self) -> bool {
+        self == Algorithm::V1
+    }
+
+    /// Returns whether the Feistel networks are balanced, rounding odd bit
+    /// lengths up.
+    pub(crate) fn is_balanced(self) -> bool {
+        matches!(self, Algorithm::V1 | Algorithm::V2)
+    }
+}
+
+/// A trade-off between speed and security, which selects an [`Algorithm`],
+/// a number of Feistel rounds and a [`RoundPolicy`] in one place.
+///
+/// Every level keeps the guarantees of the generator (e.g. no repeats within
+/// a window), and only changes how hard it is to predict upcoming words
+/// without the seed. Levels are set via
+/// [`WordleBuilder::security_level`](crate::WordleBuilder::security_level).
+///
+/// | Level                        | Algorithm         | Round function | Rounds | Round policy          |
+/// |------------------------------|-------------------|----------------|--------|-----------------------|
+/// | [`SecurityLevel::Fast`]      | [`Algorithm::V3`] | WyHash         | 8      | -                     |
+/// | [`SecurityLevel::Standard`]  | [`Algorithm::V4`] | SipHash-2-4    | 12     | [`RoundPolicy::V1`]   |
+/// | [`SecurityLevel::Paranoid`]  | [`Algorithm::V5`] | HMAC-SHA-256   | 24     | [`RoundPolicy::V1`]   |
+///
+/// The round policy raises the number of rounds of small domains, so the
+/// rounds of a level are a minimum.
+///
+/// The mapping of each level never changes, so a level produces the same
+/// schedule forever.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum SecurityLevel {
+    /// The fastest level, with a round function that is not a keyed PRF.
+    /// Suitable when the seed is public or predicting words is harmless.
+    Fast,
+    /// A keyed PRF as the round function, with a comfortable margin of rounds.
+    #[default]
+    Standard,
+    /// A NIST-approved round function with the number of rounds recommended
+    /// for the smallest domains, for schedules that must stay secret for
+    /// a long time.
+    Paranoid,
+}
+
+impl SecurityLevel {
+    /// Returns the algorithm selected by this level.
+    pub fn algorithm(self) -> Algorithm {
+        match self {
+            SecurityLevel::Fast => Algorithm::V3,
+            SecurityLevel::Standard => Algorithm::V4,
+            SecurityLevel::Paranoid => Algorithm::V5,
+        }
+    }
+
+    /// Returns the number of Feistel rounds selected by this level.
+    pub fn rounds(self) -> usize {
+        match self {
+            SecurityLevel::Fast => 8,
+            SecurityLevel::Standard => 12,
+            SecurityLevel::Paranoid => 24,
+        }
+    }
+
+    /// Returns the policy that chooses the minimum number of rounds of each
+    /// network, if any, selected by this level.
+    pub fn round_policy(self) -> Option<RoundPolicy> {
+        match self {
+            SecurityLevel::Fast => None,
+            SecurityLevel::Standard | SecurityLevel::Paranoid => Some(RoundPolicy::V1),
+        }
+    }
+}
+
+/// The round function selected by an [`Algorithm`].
+#[derive(Debug, Clone)]
+pub(crate) enum AlgorithmRound {
+    WyHash(WyHashRound),
+    SipHash(SipHashRound),
+    HmacSha256(HmacSha256Round),
+    HalfSipHash(HalfSipHashRound),
+}
+
+impl RoundFunction for AlgorithmRound {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        match self {
+            AlgorithmRound::WyHash(round) => round.round(key, input),
+            AlgorithmRound::SipHash(round) => round.round(key, input),
+            AlgorithmRound::HmacSha256(round) => round.round(key, input),
+            AlgorithmRound::HalfSipHash(round) => round.round(key, input),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A date of the proleptic Gregorian calendar, as a number of days since
+/// 1970-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date(i64);
+
+impl Date {
+    /// The Unix epoch, 1970-01-01.
+    pub const UNIX_EPOCH: Date = Date(0);
+
+    /// Returns the current date in UTC.
+    pub fn today() -> Self {
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        Date((secs / 86_400) as i64)
+    }
+
+    /// Creates a date from its year, month (1-12) and day (1-31).
+    ///
+    /// See <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+    fn from_ymd(year: i64, month: u32, day: u32) -> Self {
+        let year = if month <= 2 { year - 1 } else { year };
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = month as i64;
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        Date(era * 146_097 + day_of_era - 719_468)
+    }
+
+    /// Returns the year, month (1-12) and day (1-31) of the date.
+    ///
+    /// See <http://howardhinnant.github.io/date_algorithms.html#civil_from_days>.
+    fn to_ymd(self) -> (i64, u32, u32) {
+        let days = self.0 + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        (year, month, day)
+    }
+
+    /// Returns the number of days from `earlier` to this date, or `None` if
+    /// `earlier` is later than this date.
+    pub fn days_since(self, earlier: Date) -> Option<u64> {
+        self.0.checked_sub(earlier.0)?.try_into().ok()
+    }
+
+    /// Returns the date `days` days after this one.
+    pub fn add_days(self, days: u64) -> Option<Self> {
+        self.0.checked_add(days.try_into().ok()?).map(Date)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (year, month, day) = self.to_ymd();
+        write!(f, "{:04}-{:02}-{:02}", year, month, day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("invalid date `{}`, expected YYYY-MM-DD", s);
+        let mut parts = s.splitn(3, '-');
+        let mut next = || parts.next().ok_or_else(invalid);
+        let year: i64 = next()?.parse().map_err(|_| invalid())?;
+        let month: u32 = next()?.parse().map_err(|_| invalid())?;
+        let day: u32 = next()?.parse().map_err(|_| invalid())?;
+        let date = Date::from_ymd(year, month, day);
+        // Reject out-of-range months and days, e.g. 2023-02-30
+        if date.to_ymd() != (year, month, day) {
+            return Err(invalid());
+        }
+        Ok(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Date;
+
+    #[test]
+    fn parse_and_format() {
+        for s in ["1970-01-01", "2000-02-29", "2021-06-19", "1969-12-31"] {
+            assert_eq!(s.parse::<Date>().unwrap().to_string(), s);
+        }
+        let date: Date = "2021-06-19".parse().unwrap();
+        assert_eq!(date.days_since(Date::UNIX_EPOCH), Some(18_797));
+        assert_eq!(Date::UNIX_EPOCH.days_since(date), None);
+    }
+
+    #[test]
+    fn invalid() {
+        for s in ["2023-02-29", "2023-13-01", "2023-01", "today"] {
+            assert!(s.parse::<Date>().is_err());
+        }
+    }
+}
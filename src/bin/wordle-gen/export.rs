@@ -0,0 +1,110 @@
+use crate::date::Date;
+use clap::ValueEnum;
+use hmac::{Hmac, Mac};
+use serde_json::json;
+use sha2::Sha256;
+use std::io::{self, Write};
+
+/// The output format of an exported schedule.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+/// A puzzle of an exported schedule.
+pub struct Row {
+    pub date: Date,
+    pub day: u64,
+    pub puzzle_id: u64,
+    pub window: u64,
+    pub word: String,
+    pub list_fingerprint: String,
+}
+
+/// Returns the lowercase hexadecimal HMAC-SHA256 of `word` under `key`.
+pub fn hmac_word(key: &[u8], word: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(word.as_bytes());
+    let tag = mac.finalize().into_bytes();
+    tag.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Writes the rows in the given format. The `word_column` names the column
+/// of the words, which differs if they are HMACed.
+pub fn write(
+    out: &mut impl Write,
+    format: Format,
+    word_column: &str,
+    rows: &[Row],
+) -> io::Result<()> {
+    match format {
+        Format::Json => {
+            let rows: Vec<_> = rows
+                .iter()
+                .map(|row| {
+                    json!({
+                        "date": row.date.to_string(),
+                        "day": row.day,
+                        "puzzle_id": row.puzzle_id,
+                        "window": row.window,
+                        word_column: row.word,
+                        "list_fingerprint": row.list_fingerprint,
+                    })
+                })
+                .collect();
+            serde_json::to_writer_pretty(&mut *out, &rows)?;
+            writeln!(out)
+        }
+        Format::Csv => {
+            writeln!(
+                out,
+                "date,day,puzzle_id,window,{},list_fingerprint",
+                word_column
+            )?;
+            for row in rows {
+                writeln!(
+                    out,
+                    "{},{},{},{},{},{}",
+                    row.date,
+                    row.day,
+                    row.puzzle_id,
+                    row.window,
+                    csv_field(&row.word),
+                    row.list_fingerprint
+                )?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Quotes a CSV field if it contains a delimiter, quote or newline.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_quoting() {
+        assert_eq!(csv_field("apple"), "apple");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn hmac() {
+        // RFC 4231, test case 2
+        assert_eq!(
+            hmac_word(b"Jefe", "what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! A command-line tool to inspect the schedule of a generator.
+
+mod date;
+mod export;
+
+use crate::date::Date;
+use crate::export::{Format, Row};
+use clap::{Args, Parser, Subcommand};
+use std::error::Error;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use wordle_generator::{Seed, WordList, Wordle, WordleBuilder};
+
+#[derive(Parser)]
+#[command(name = "wordle-gen", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Prints the word of a day.
+    Get(GetArgs),
+    /// Exports the puzzles of a date range.
+    Export(ExportArgs),
+}
+
+/// The options that determine the schedule.
+#[derive(Args)]
+struct GeneratorArgs {
+    /// The seed, as a hexadecimal or base64 string.
+    #[arg(long)]
+    seed: Seed,
+    /// A file with one word per line, optionally followed by a tab and the
+    /// weight of the word.
+    #[arg(long)]
+    words: PathBuf,
+    /// The number of consecutive days in which no word repeats
+    /// [default: the number of words].
+    #[arg(long)]
+    window_len: Option<u64>,
+    /// The date of day 0, used to convert dates to days.
+    #[arg(long, default_value_t = Date::UNIX_EPOCH)]
+    epoch: Date,
+}
+
+impl GeneratorArgs {
+    fn read_words(&self) -> Result<WordList, Box<dyn Error>> {
+        let words = WordList::from_path(&self.words)
+            .map_err(|err| format!("cannot read {}: {}", self.words.display(), err))?;
+        Ok(words)
+    }
+
+    fn build<'a>(&self, words: &'a WordList) -> Result<Wordle<'a, String>, Box<dyn Error>> {
+        let len = self.window_len.unwrap_or(words.len() as u64);
+        let wordle = WordleBuilder::from_word_list(words)
+            .window_len(len)
+            .seed(self.seed)
+            .build()?;
+        Ok(wordle)
+    }
+
+    /// Returns the day of the given date.
+    fn day_of(&self, date: Date) -> Result<u64, Box<dyn Error>> {
+        let day = date.days_since(self.epoch);
+        Ok(day.ok_or_else(|| format!("{} is before the epoch {}", date, self.epoch))?)
+    }
+}
+
+#[derive(Args)]
+struct GetArgs {
+    #[command(flatten)]
+    generator: GeneratorArgs,
+    /// The day number.
+    #[arg(long, conflicts_with = "date")]
+    day: Option<u64>,
+    /// The date, as YYYY-MM-DD [default: today in UTC].
+    #[arg(long)]
+    date: Option<Date>,
+}
+
+fn get(args: GetArgs) -> Result<(), Box<dyn Error>> {
+    let generator = &args.generator;
+    let day = match args.day {
+        Some(day) => day,
+        None => generator.day_of(args.date.unwrap_or_else(Date::today))?,
+    };
+    let words = generator.read_words()?;
+    let wordle = generator.build(&words)?;
+    println!("{}", wordle.get(day));
+    Ok(())
+}
+
+#[derive(Args)]
+struct ExportArgs {
+    #[command(flatten)]
+    generator: GeneratorArgs,
+    /// The first date to export, as YYYY-MM-DD.
+    #[arg(long)]
+    from: Date,
+    /// The last date to export (inclusive), as YYYY-MM-DD.
+    #[arg(long)]
+    to: Date,
+    /// The output format.
+    #[arg(long, value_enum, default_value_t = Format::Csv)]
+    format: Format,
+    /// The puzzle ID of day 0.
+    #[arg(long, default_value_t = 1)]
+    first_id: u64,
+    /// Replaces each word by its hexadecimal HMAC-SHA256 under this key,
+    /// so the schedule can be shared without revealing the answers.
+    #[arg(long)]
+    hmac_key: Option<String>,
+}
+
+fn export(args: ExportArgs) -> Result<(), Box<dyn Error>> {
+    let generator = &args.generator;
+    let first = generator.day_of(args.from)?;
+    let last = generator.day_of(args.to)?;
+    if last < first {
+        return Err(format!("{} is before {}", args.to, args.from).into());
+    }
+    let words = generator.read_words()?;
+    let wordle = generator.build(&words)?;
+    // Lets consumers check that they use the same list before comparing
+    let list_fingerprint = wordle.list_fingerprint().to_string();
+    let rows: Vec<_> = wordle
+        .iter_from(first)
+        .take_while(|&(day, _)| day <= last)
+        .map(|(day, word)| Row {
+            date: generator.epoch.add_days(day).unwrap(),
+            day,
+            puzzle_id: args.first_id.saturating_add(day),
+            window: wordle.window_of(day),
+            word: match &args.hmac_key {
+                Some(key) => export::hmac_word(key.as_bytes(), word),
+                None => word.to_owned(),
+            },
+            list_fingerprint: list_fingerprint.clone(),
+        })
+        .collect();
+    let word_column = match args.hmac_key {
+        Some(_) => "word_hmac",
+        None => "word",
+    };
+    export::write(&mut io::stdout().lock(), args.format, word_column, &rows)?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Get(args) => get(args),
+        Command::Export(args) => export(args),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,757 @@
+use crate::dictionary::Dictionary;
+use crate::feistel::RoundPolicy;
+use crate::items::Items;
+use crate::weights::Weights;
+use crate::{
+    Algorithm, DailyPicker, FeistelPermutation, KeyedPermutation, Normalizer, SecurityLevel, Seed,
+    WordList, Wordle, WordleConfig, WordleError, DEFAULT_ROUNDS,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::iter;
+use core::marker::PhantomData;
+
+/// A builder to configure a [`Wordle`] generator, or a [`DailyPicker`]
+/// over arbitrary items.
+///
+/// Every option has a default:
+///
+/// - the seed is generated via `Seed::generate` (without the `std`
+///   feature, a seed must be set),
+/// - the window length is equal to the number of items, so every item is
+///   picked exactly once before any repeats (banning items doesn't change
+///   it, see [`WordleBuilder::banned`]),
+/// - the day offset is zero,
+/// - the algorithm is [`Algorithm::V1`],
+/// - the generator uses [`DEFAULT_ROUNDS`] Feistel rounds,
+/// - the days are permuted by a [`FeistelPermutation`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{Seed, Wordle};
+///
+/// let words = ["apple", "grape", "lemon"];
+/// let wordle = Wordle::builder(&words)
+///     .seed(Seed::new([7; 32]))
+///     .window_len(2)
+///     .build()
+///     .unwrap();
+/// assert!(words.contains(&wordle.get(0)));
+/// ```
+pub struct WordleBuilder<'a, T, const ROUNDS: usize = DEFAULT_ROUNDS, P = FeistelPermutation> {
+    items: Items<'a, T>,
+    seed: Option<Seed>,
+    window_len: Option<u64>,
+    day_offset: u64,
+    banned: Vec<usize>,
+    vetoes: Vec<u64>,
+    weights: Option<Vec<u32>>,
+    repeat_gap: u64,
+    rotations: Vec<(u64, Seed)>,
+    windowless: bool,
+    algorithm: Algorithm,
+    label: Option<Vec<u8>>,
+    capacity: Option<u64>,
+    appends: Vec<(u64, usize)>,
+    round_policy: Option<RoundPolicy>,
+    whitening: bool,
+    constant_time: bool,
+    // The number of rounds expected by an applied configuration
+    config_rounds: Option<usize>,
+    // The number of rounds set at runtime, which overrides `ROUNDS`
+    runtime_rounds: Option<usize>,
+    guesses: Option<Items<'a, T>>,
+    puzzle_offset: u64,
+    normalizer: Normalizer,
+    permutation: PhantomData<fn() -> P>,
+}
+
+impl<'a, T> WordleBuilder<'a, T> {
+    /// Creates a builder for a generator over `items`.
+    pub fn new(items: &'a [T]) -> Self {
+        Self::with_items(Items::Borrowed(items))
+    }
+
+    fn with_items(items: Items<'a, T>) -> Self {
+        Self {
+            items,
+            seed: None,
+            window_len: None,
+            day_offset: 0,
+            banned: Vec::new(),
+            vetoes: Vec::new(),
+            weights: None,
+            repeat_gap: 0,
+            rotations: Vec::new(),
+            windowless: false,
+            algorithm: Algorithm::default(),
+            label: None,
+            capacity: None,
+            appends: Vec::new(),
+            round_policy: None,
+            whitening: false,
+            constant_time: false,
+            config_rounds: None,
+            runtime_rounds: None,
+            guesses: None,
+            puzzle_offset: 0,
+            normalizer: Normalizer::new(),
+            permutation: PhantomData,
+        }
+    }
+}
+
+impl<'a> WordleBuilder<'a, String> {
+    /// Creates a builder for a generator over the words of `list`, which
+    /// picks heavier words more often if the list has weights.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Seed, WordList, WordleBuilder};
+    ///
+    /// let words = WordList::parse("apple\t5\ngrape\t1\nlemon\t0\n").unwrap();
+    /// let wordle = WordleBuilder::from_word_list(&words)
+    ///     .seed(Seed::new([0; 32]))
+    ///     .window_len(2)
+    ///     .build()
+    ///     .unwrap();
+    /// // Words with a weight of zero are never picked
+    /// assert!(wordle.get_range(0..10).iter().all(|&word| word != "lemon"));
+    /// ```
+    pub fn from_word_list(list: &'a WordList) -> Self {
+        let builder = Self::new(list.as_slice());
+        match list.weights() {
+            Some(weights) => builder.weights(weights.iter().copied()),
+            None => builder,
+        }
+    }
+}
+
+impl<T> WordleBuilder<'static, T> {
+    /// Creates a builder for a generator that owns `items`.
+    pub fn from_vec(items: Vec<T>) -> Self {
+        Self::with_items(Items::Owned(items))
+    }
+}
+
+impl<T, const ROUNDS: usize, P> WordleBuilder<'static, T, ROUNDS, P> {
+    /// Sets the words that can be guessed besides the answers, which the
+    /// generator owns.
+    ///
+    /// See [`WordleBuilder::allowed_guesses`] for details.
+    pub fn allowed_guesses_vec(mut self, guesses: Vec<T>) -> Self {
+        self.guesses = Some(Items::Owned(guesses));
+        self
+    }
+}
+
+impl<'a, T, const ROUNDS: usize, P: KeyedPermutation> WordleBuilder<'a, T, ROUNDS, P> {
+    /// Sets the list of items to pick from.
+    pub fn items(mut self, items: &'a [T]) -> Self {
+        self.items = Items::Borrowed(items);
+        self
+    }
+
+    /// Sets the seed used to derive the permutation of every window.
+    pub fn seed(mut self, seed: Seed) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sets the number of consecutive days in which no word repeats.
+    pub fn window_len(mut self, window_len: u64) -> Self {
+        self.window_len = Some(window_len);
+        self
+    }
+
+    /// Enables windowless mode, which permutes the days over the full 64-bit
+    /// space and maps each result into the list of items.
+    ///
+    /// The schedule has no window boundaries and never becomes periodic, but
+    /// items can repeat on any two days, as if each window had a single day.
+    /// This overrides the window length, and a repeat gap can't be set.
+    pub fn windowless(mut self) -> Self {
+        self.windowless = true;
+        self
+    }
+
+    /// Replaces the seed from the given day onwards.
+    ///
+    /// See [`DailyPicker::rotate_seed`] for details.
+    pub fn rotate_seed(mut self, from_day: u64, seed: Seed) -> Self {
+        self.rotations.retain(|&(day, _)| day < from_day);
+        self.rotations.push((from_day, seed));
+        self
+    }
+
+    /// Sets the offset added to every day before looking up its word.
+    ///
+    /// This shifts the schedule, so that the day `0` passed to [`Wordle::get`]
+    /// corresponds to the day `day_offset` of the underlying sequence.
+    pub fn day_offset(mut self, day_offset: u64) -> Self {
+        self.day_offset = day_offset;
+        self
+    }
+
+    /// Bans the items at the given indices, so they are never picked.
+    ///
+    /// Banned items are skipped by continuing the permutation walk, so only
+    /// the days on which a banned item would have been picked, and the days
+    /// whose position in their window is a banned index, change. The default
+    /// window length stays the number of items, so with it only the days of
+    /// banned items change, and those days repeat another item of their
+    /// window.
+    pub fn banned(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.banned.extend(indices);
+        self
+    }
+
+    /// Bans every occurrence of the given words in the current list, so they
+    /// are never picked. Words that are not in the list are ignored.
+    ///
+    /// See [`WordleBuilder::banned`] for details.
+    pub fn banned_words<'w>(mut self, words: impl IntoIterator<Item = &'w str>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        let words: BTreeSet<_> = words.into_iter().collect();
+        let indices = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, word)| words.contains(word.as_ref()))
+            .map(|(idx, _)| idx);
+        self.banned.extend(indices);
+        self
+    }
+
+    /// Vetoes the item picked for the given day, replacing it by another one.
+    ///
+    /// Vetoing the same day again replaces the replacement. See
+    /// [`DailyPicker::veto`] for details.
+    pub fn veto(mut self, day: u64) -> Self {
+        self.vetoes.push(day);
+        self
+    }
+
+    /// Sets the weight of each item, so heavier items are picked more often.
+    ///
+    /// Items are still never picked twice within a window, but heavier items
+    /// are more likely to be picked in each window. Items with a weight of
+    /// zero are never picked. Looking up a weighted item takes time
+    /// proportional to the position of its day within the window.
+    pub fn weights(mut self, weights: impl IntoIterator<Item = u32>) -> Self {
+        self.weights = Some(weights.into_iter().collect());
+        self
+    }
+
+    /// Ensures no item is picked twice within `repeat_gap` consecutive days,
+    /// even across window boundaries.
+    ///
+    /// The items of the first days of each window that were picked on the
+    /// last days of the previous window are swapped with items picked later
+    /// in the window. The gap should be at most a third of the window length.
+    pub fn repeat_gap(mut self, repeat_gap: u64) -> Self {
+        self.repeat_gap = repeat_gap;
+        self
+    }
+
+    /// Sets the version of the algorithm that derives the schedule.
+    ///
+    /// See [`Algorithm`] for details.
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets the policy that chooses the minimum number of rounds of every
+    /// Feistel network from its bit length, so small word lists get more
+    /// rounds. By default, every network uses the configured number of rounds.
+    ///
+    /// The configured number of rounds is a minimum, and the policy can be
+    /// combined with any [`Algorithm`]. Changing it can change the generated
+    /// sequence. See [`RoundPolicy`] for details.
+    pub fn round_policy(mut self, policy: RoundPolicy) -> Self {
+        self.round_policy = Some(policy);
+        self
+    }
+
+    /// Sets whether the input and output of every Feistel network are XORed
+    /// with whitening keys derived from the seed, which hardens the
+    /// permutation of small domains. Disabled by default.
+    ///
+    /// Whitening can be combined with any [`Algorithm`], e.g. with the keyed
+    /// round function of [`Algorithm::V4`]. Changing it changes the generated
+    /// sequence.
+    pub fn whitening(mut self, whitening: bool) -> Self {
+        self.whitening = whitening;
+        self
+    }
+
+    /// Sets whether the cycles of the permutations are walked in constant
+    /// time, via [`Permutation::permute_bounded_constant_time`]. Disabled by
+    /// default.
+    ///
+    /// This hides the number of cycle walking steps from an attacker that
+    /// measures response times, at the cost of slower picks. Picks slow down
+    /// further as the permutation outgrows the items, e.g. with a pinned
+    /// [`capacity`](WordleBuilder::capacity) much larger than the list. It
+    /// doesn't change the generated sequence, and isn't part of the
+    /// configuration.
+    /// Other parts of the picker (e.g. skipping banned words) still take a
+    /// variable time. See the [`feistel`](crate::feistel#timing) module for
+    /// the timing model.
+    ///
+    /// [`Permutation::permute_bounded_constant_time`]: crate::Permutation::permute_bounded_constant_time
+    pub fn constant_time(mut self, constant_time: bool) -> Self {
+        self.constant_time = constant_time;
+        self
+    }
+
+    /// Sets a label that is mixed into the derivation of every permutation,
+    /// so generators that share a seed but have different labels (e.g.
+    /// `b"daily-word"` and `b"bonus-word"`) pick unrelated sequences.
+    ///
+    /// Without a label, the permutations are derived from the seed alone, as
+    /// in earlier versions. Changing the label changes the generated sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Seed, Wordle};
+    ///
+    /// let words = ["apple", "grape", "lemon", "mango", "peach"];
+    /// let builder = || Wordle::builder(&words).seed(Seed::new([0; 32]));
+    /// let daily = builder().label(b"daily-word").build().unwrap();
+    /// let bonus = builder().label(b"bonus-word").build().unwrap();
+    /// assert_eq!(daily.label(), Some(&b"daily-word"[..]));
+    /// assert_ne!(daily.get_range(0..5), bonus.get_range(0..5));
+    /// ```
+    pub fn label(mut self, label: &[u8]) -> Self {
+        self.label = Some(label.to_vec());
+        self
+    }
+
+    /// Pins the size of the domain of the permutations of items to
+    /// `capacity`, so words can be appended to the list (up to the capacity)
+    /// without changing the words of earlier days.
+    ///
+    /// By default, the domain is the number of items (or the total weight of
+    /// a weighted list), so appending words changes every permutation. With
+    /// a pinned capacity, the permutations only depend on the seed, and slots
+    /// without a word are skipped by the permutation walk. Appended words are
+    /// only picked from the day passed to [`WordleBuilder::appended`], so
+    /// earlier days keep their words. The window length should also be set
+    /// explicitly, since it defaults to the number of words.
+    ///
+    /// Picking takes time proportional to the ratio of the capacity to the
+    /// number of words, so the capacity shouldn't be much larger than the
+    /// list. Changing the capacity changes the generated sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Seed, Wordle};
+    ///
+    /// let words = ["apple", "grape", "lemon", "mango", "peach", "melon"];
+    /// let before = Wordle::builder(&words[..4])
+    ///     .seed(Seed::new([0; 32]))
+    ///     .window_len(4)
+    ///     .capacity(8)
+    ///     .build()
+    ///     .unwrap();
+    /// // Two words are appended, which can be picked from day 8 onwards
+    /// let after = Wordle::builder(&words)
+    ///     .seed(Seed::new([0; 32]))
+    ///     .window_len(4)
+    ///     .capacity(8)
+    ///     .appended(8, 4)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(after.get_range(0..8), before.get_range(0..8));
+    /// ```
+    pub fn capacity(mut self, capacity: u64) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the day from which the items from index `first_index` onwards,
+    /// which were appended to the list, can be picked.
+    ///
+    /// The items are picked from the first window that starts on or after
+    /// `from_day`, so no window mixes them with the words of earlier
+    /// windows. This requires a pinned [capacity](WordleBuilder::capacity),
+    /// and can be called once per batch of appended items.
+    pub fn appended(mut self, from_day: u64, first_index: usize) -> Self {
+        self.appends.push((from_day, first_index));
+        self
+    }
+
+    /// Sets the algorithm, the number of Feistel rounds and the round policy
+    /// from a trade-off between speed and security.
+    ///
+    /// This overrides the `ROUNDS` parameter of the generator, like
+    /// [`WordleBuilder::runtime_rounds`]. Later calls to
+    /// [`WordleBuilder::algorithm`], [`WordleBuilder::runtime_rounds`] or
+    /// [`WordleBuilder::round_policy`] override the choices of the level. See
+    /// [`SecurityLevel`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Algorithm, SecurityLevel, Seed, Wordle};
+    ///
+    /// let words = ["apple", "grape", "lemon"];
+    /// let wordle = Wordle::builder(&words)
+    ///     .seed(Seed::new([0; 32]))
+    ///     .security_level(SecurityLevel::Paranoid)
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(wordle.config().algorithm, Algorithm::V5);
+    /// assert_eq!(wordle.config().rounds, 24);
+    /// ```
+    pub fn security_level(mut self, level: SecurityLevel) -> Self {
+        self.algorithm = level.algorithm();
+        self.runtime_rounds = Some(level.rounds());
+        self.round_policy = level.round_policy();
+        self
+    }
+
+    /// Applies a configuration obtained from another generator, replacing
+    /// every option of this builder.
+    ///
+    /// Building fails if the configuration uses a different number of rounds.
+    /// See [`WordleConfig`] for details.
+    pub fn config(mut self, config: WordleConfig) -> Self {
+        self.seed = Some(config.seed);
+        self.window_len = Some(config.window_len);
+        self.day_offset = config.day_offset;
+        self.algorithm = config.algorithm;
+        self.label = config.label;
+        self.capacity = config.capacity;
+        self.appends = config.appends;
+        self.round_policy = config.round_policy;
+        self.whitening = config.whitening;
+        self.config_rounds = Some(config.rounds);
+        self.windowless = config.windowless;
+        self.banned = config.banned;
+        self.weights = config.weights;
+        self.repeat_gap = config.repeat_gap;
+        self.rotations = config.rotations;
+        self.vetoes = config
+            .vetoes
+            .into_iter()
+            .flat_map(|(day, count)| iter::repeat_n(day, count as usize))
+            .collect();
+        self
+    }
+
+    /// Sets the words that can be guessed besides the answers, as in the
+    /// classic game, where answers are picked from a curated list but any
+    /// word of a larger dictionary is accepted.
+    ///
+    /// The guesses don't affect the picked words, and are ignored by
+    /// [`WordleBuilder::build_picker`]. See [`Wordle::is_valid_guess`].
+    pub fn allowed_guesses(mut self, guesses: &'a [T]) -> Self {
+        self.guesses = Some(Items::Borrowed(guesses));
+        self
+    }
+
+    /// Sets the puzzle number of day zero, so that day `n` is puzzle number
+    /// `puzzle_offset + n`. Defaults to zero.
+    ///
+    /// Puzzle numbers don't affect the picked words, and are ignored by
+    /// [`WordleBuilder::build_picker`]. See [`Wordle::puzzle_id`].
+    pub fn puzzle_offset(mut self, puzzle_offset: u64) -> Self {
+        self.puzzle_offset = puzzle_offset;
+        self
+    }
+
+    /// Sets the normalizer applied to guesses before they are checked by
+    /// [`Wordle::is_valid_guess`] and played in the games of
+    /// [`Wordle::new_game`]. Defaults to a normalizer that doesn't change words.
+    ///
+    /// The answers and the allowed guesses aren't normalized, since that would
+    /// change the picked words, so they should be normalized beforehand with
+    /// the same normalizer (e.g. via [`ParseOptions::normalizer`]).
+    ///
+    /// [`ParseOptions::normalizer`]: crate::ParseOptions::normalizer
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{CaseFolding, Normalizer, Seed, Wordle};
+    ///
+    /// let words = ["árbol", "campo", "fuego"];
+    /// let wordle = Wordle::builder(&words)
+    ///     .seed(Seed::new([0; 32]))
+    ///     .normalizer(Normalizer::new().case_folding(CaseFolding::Lowercase))
+    ///     .build()
+    ///     .unwrap();
+    /// assert!(wordle.is_valid_guess("ÁRBOL"));
+    /// ```
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Sets the number of Feistel rounds used to permute each window.
+    ///
+    /// Changing the number of rounds changes the generated sequence.
+    pub fn rounds<const R: usize>(self) -> WordleBuilder<'a, T, R, P> {
+        WordleBuilder {
+            items: self.items,
+            seed: self.seed,
+            window_len: self.window_len,
+            day_offset: self.day_offset,
+            banned: self.banned,
+            vetoes: self.vetoes,
+            weights: self.weights,
+            repeat_gap: self.repeat_gap,
+            rotations: self.rotations,
+            windowless: self.windowless,
+            algorithm: self.algorithm,
+            label: self.label,
+            capacity: self.capacity,
+            appends: self.appends,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            constant_time: self.constant_time,
+            config_rounds: self.config_rounds,
+            runtime_rounds: self.runtime_rounds,
+            guesses: self.guesses,
+            puzzle_offset: self.puzzle_offset,
+            normalizer: self.normalizer,
+            permutation: PhantomData,
+        }
+    }
+
+    /// Sets the permutation of the days of each window, which defaults to a
+    /// [`FeistelPermutation`]. For lists of up to
+    /// [`FISHER_YATES_MAX_LEN`](crate::FISHER_YATES_MAX_LEN) words,
+    /// [`FisherYatesPermutation`](crate::FisherYatesPermutation) picks
+    /// uniformly random orders.
+    ///
+    /// Changing the permutation changes the generated sequence. Configurations
+    /// and commitments don't record the permutation, so the same permutation
+    /// must be set when restoring a generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{KeyedPermutation, Permutation, PermutationKey, Seed, Wordle};
+    ///
+    /// /// Picks the words in order, which is convenient for tests.
+    /// #[derive(Clone)]
+    /// struct Identity(u128);
+    ///
+    /// impl Permutation for Identity {
+    ///     fn permute(&self, input: u64) -> u64 {
+    ///         input
+    ///     }
+    ///
+    ///     fn permute_inv(&self, output: u64) -> u64 {
+    ///         output
+    ///     }
+    ///
+    ///     fn domain_len(&self) -> u128 {
+    ///         self.0
+    ///     }
+    /// }
+    ///
+    /// impl KeyedPermutation for Identity {
+    ///     fn from_key(domain_len: u128, _key: &PermutationKey<'_>) -> Self {
+    ///         Identity(domain_len)
+    ///     }
+    /// }
+    ///
+    /// let words = ["apple", "grape", "lemon"];
+    /// let wordle = Wordle::builder(&words)
+    ///     .seed(Seed::new([0; 32]))
+    ///     .permutation::<Identity>()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(wordle.get_range(0..4), ["apple", "grape", "lemon", "apple"]);
+    /// ```
+    pub fn permutation<Q: KeyedPermutation>(self) -> WordleBuilder<'a, T, ROUNDS, Q> {
+        WordleBuilder {
+            items: self.items,
+            seed: self.seed,
+            window_len: self.window_len,
+            day_offset: self.day_offset,
+            banned: self.banned,
+            vetoes: self.vetoes,
+            weights: self.weights,
+            repeat_gap: self.repeat_gap,
+            rotations: self.rotations,
+            windowless: self.windowless,
+            algorithm: self.algorithm,
+            label: self.label,
+            capacity: self.capacity,
+            appends: self.appends,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            constant_time: self.constant_time,
+            config_rounds: self.config_rounds,
+            runtime_rounds: self.runtime_rounds,
+            guesses: self.guesses,
+            puzzle_offset: self.puzzle_offset,
+            normalizer: self.normalizer,
+            permutation: PhantomData,
+        }
+    }
+
+    /// Sets the number of Feistel rounds used to permute each window at
+    /// runtime, overriding the `ROUNDS` parameter of the generator.
+    ///
+    /// This allows choosing the number of rounds e.g. from a configuration
+    /// file, without a type for each number of rounds. Picking is as fast as
+    /// with [`WordleBuilder::rounds`], and the same number of rounds generates
+    /// the same sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Seed, Wordle};
+    ///
+    /// let words = ["apple", "grape", "lemon", "mango", "peach"];
+    /// let builder = || Wordle::builder(&words).seed(Seed::new([0; 32])).window_len(5);
+    /// let runtime = builder().runtime_rounds(12).build().unwrap();
+    /// let fixed = builder().rounds::<12>().build().unwrap();
+    /// assert_eq!(runtime.config().rounds, 12);
+    /// assert_eq!(runtime.get_range(0..5), fixed.get_range(0..5));
+    /// ```
+    pub fn runtime_rounds(mut self, rounds: usize) -> Self {
+        self.runtime_rounds = Some(rounds);
+        self
+    }
+
+    /// Builds the generator, or returns an error if the configuration is invalid.
+    pub fn build(mut self) -> Result<Wordle<'a, T, ROUNDS, P>, WordleError>
+    where
+        T: AsRef<str>,
+    {
+        let guesses = self.guesses.take();
+        let puzzle_offset = self.puzzle_offset;
+        let normalizer = self.normalizer;
+        let mut wordle = Wordle::from(self.build_picker()?);
+        wordle.guesses = guesses.map(Dictionary::new);
+        wordle.puzzle_offset = puzzle_offset;
+        wordle.normalizer = normalizer;
+        Ok(wordle)
+    }
+
+    /// Builds a picker over arbitrary items, or returns an error if the
+    /// configuration is invalid.
+    pub fn build_picker(self) -> Result<DailyPicker<'a, T, ROUNDS, P>, WordleError> {
+        if self.items.is_empty() {
+            return Err(WordleError::EmptyWords);
+        }
+        let rounds = self.runtime_rounds.unwrap_or(ROUNDS);
+        if rounds == 0 {
+            return Err(WordleError::ZeroRounds);
+        }
+        if let Some(found) = self.config_rounds.filter(|&found| found != rounds) {
+            return Err(WordleError::RoundsMismatch {
+                expected: rounds,
+                found,
+            });
+        }
+        let mut banned = self.banned;
+        let weights = match self.weights {
+            Some(weights) => {
+                if weights.len() != self.items.len() {
+                    return Err(WordleError::WeightsLenMismatch);
+                }
+                let zero_weights = weights.iter().enumerate().filter(|(_, &w)| w == 0);
+                banned.extend(zero_weights.map(|(idx, _)| idx));
+                let weights = Weights::new(&weights).ok_or(WordleError::WeightsOverflow)?;
+                if usize::try_from(weights.total()).is_err() {
+                    return Err(WordleError::WeightsOverflow);
+                }
+                Some(weights)
+            }
+            None => None,
+        };
+        banned.sort_unstable();
+        banned.dedup();
+        if let Some(&idx) = banned.last().filter(|&&idx| idx >= self.items.len()) {
+            return Err(WordleError::BannedOutOfBounds(idx));
+        }
+        if banned.len() == self.items.len() {
+            return Err(WordleError::AllBanned);
+        }
+        let domain_len = match &weights {
+            Some(weights) => weights.total(),
+            None => self.items.len() as u64,
+        };
+        if self.capacity.is_some_and(|capacity| capacity < domain_len) {
+            return Err(WordleError::CapacityExceeded);
+        }
+        if !self.appends.is_empty() && self.capacity.is_none() {
+            return Err(WordleError::MissingCapacity);
+        }
+        for &(_, first) in &self.appends {
+            if first == 0 || first > self.items.len() {
+                return Err(WordleError::AppendOutOfBounds(first));
+            }
+            // Some of the earlier items must be allowed
+            if banned.partition_point(|&idx| idx < first) == first {
+                return Err(WordleError::AllBanned);
+            }
+        }
+        let window_len = match self.windowless {
+            true => 1,
+            false => self.window_len.unwrap_or(self.items.len() as u64),
+        };
+        if window_len == 0 {
+            return Err(WordleError::ZeroWindowLen);
+        }
+        if self.repeat_gap > window_len / 3 {
+            return Err(WordleError::RepeatGapTooLong);
+        }
+        #[cfg(feature = "std")]
+        let seed = self.seed.unwrap_or_else(Seed::generate);
+        #[cfg(not(feature = "std"))]
+        let seed = self.seed.ok_or(WordleError::MissingSeed)?;
+        let mut vetoes = BTreeMap::new();
+        for day in self.vetoes {
+            *vetoes.entry(day.wrapping_add(self.day_offset)).or_insert(0) += 1;
+        }
+        let rotations = self
+            .rotations
+            .into_iter()
+            .map(|(day, seed)| (day.wrapping_add(self.day_offset), seed))
+            .collect();
+        let mut appends: Vec<_> = self
+            .appends
+            .into_iter()
+            .map(|(day, first)| (day.wrapping_add(self.day_offset), first))
+            .collect();
+        appends.sort_unstable();
+        Ok(DailyPicker {
+            items: self.items,
+            window_len,
+            seed,
+            day_offset: self.day_offset,
+            banned,
+            vetoes,
+            weights,
+            repeat_gap: self.repeat_gap,
+            rotations,
+            windowless: self.windowless,
+            algorithm: self.algorithm,
+            label: self.label,
+            rounds,
+            capacity: self.capacity,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            constant_time: self.constant_time,
+            appends,
+            permutation: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,150 @@
+use crate::feistel::RoundPolicy;
+use crate::{hex, Algorithm, ListFingerprint, WordleConfig};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+use sha2::{Digest, Sha256};
+
+/// The length of a commitment in bytes.
+const COMMITMENT_LEN: usize = 32;
+
+/// A hash commitment to the seed, configuration and list of words of a
+/// generator.
+///
+/// Publishing the commitment in advance fixes every future answer without
+/// revealing them. Once the seed is revealed, anyone can rebuild the
+/// generator and check published answers via
+/// [`Wordle::verify_answer`](crate::Wordle::verify_answer).
+///
+/// The commitment covers the [`ListFingerprint`] of the list, so it changes
+/// whenever the fingerprint does. Comparing fingerprints first tells a
+/// mismatched list apart from a mismatched seed or configuration.
+///
+/// Commitments are formatted as lowercase hexadecimal strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Commitment([u8; COMMITMENT_LEN]);
+
+impl Commitment {
+    /// Computes the commitment to a configuration and the fingerprint of a
+    /// list of words.
+    pub(crate) fn new(config: &WordleConfig, fingerprint: &ListFingerprint) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(b"wordle-generator commitment v2");
+        hasher.update(config.seed.as_bytes());
+        hasher.update(fingerprint.as_bytes());
+        update_u64(&mut hasher, config.window_len);
+        update_u64(&mut hasher, config.day_offset);
+        let algorithm = match config.algorithm {
+            Algorithm::V1 => 1,
+            Algorithm::V2 => 2,
+            Algorithm::V3 => 3,
+            Algorithm::V4 => 4,
+            Algorithm::V5 => 5,
+            Algorithm::V6 => 6,
+        };
+        update_u64(&mut hasher, algorithm);
+        update_u64(&mut hasher, config.rounds as u64);
+        update_u64(&mut hasher, config.windowless as u64);
+        // Every list is length-prefixed
+        update_u64(&mut hasher, config.banned.len() as u64);
+        for &idx in &config.banned {
+            update_u64(&mut hasher, idx as u64);
+        }
+        match &config.weights {
+            Some(weights) => {
+                update_u64(&mut hasher, 1 + weights.len() as u64);
+                for &weight in weights {
+                    update_u64(&mut hasher, weight as u64);
+                }
+            }
+            None => update_u64(&mut hasher, 0),
+        }
+        update_u64(&mut hasher, config.repeat_gap);
+        update_u64(&mut hasher, config.rotations.len() as u64);
+        for (day, seed) in &config.rotations {
+            update_u64(&mut hasher, *day);
+            hasher.update(seed.as_bytes());
+        }
+        update_u64(&mut hasher, config.vetoes.len() as u64);
+        for &(day, count) in &config.vetoes {
+            update_u64(&mut hasher, day);
+            update_u64(&mut hasher, count as u64);
+        }
+        // Unlabelled configurations keep their earlier commitments
+        if let Some(label) = &config.label {
+            hasher.update(b"label");
+            update_u64(&mut hasher, label.len() as u64);
+            hasher.update(label);
+        }
+        // As do configurations without a pinned capacity
+        if let Some(capacity) = config.capacity {
+            hasher.update(b"capacity");
+            update_u64(&mut hasher, capacity);
+            update_u64(&mut hasher, config.appends.len() as u64);
+            for &(day, first) in &config.appends {
+                update_u64(&mut hasher, day);
+                update_u64(&mut hasher, first as u64);
+            }
+        }
+        // As do unwhitened configurations
+        if config.whitening {
+            hasher.update(b"whitening");
+        }
+        // And configurations without a round policy
+        if let Some(policy) = config.round_policy {
+            hasher.update(b"round policy");
+            let policy = match policy {
+                RoundPolicy::V1 => 1,
+            };
+            update_u64(&mut hasher, policy);
+        }
+        Self(hasher.finalize().into())
+    }
+
+    /// Returns the bytes of the commitment.
+    pub fn as_bytes(&self) -> &[u8; COMMITMENT_LEN] {
+        &self.0
+    }
+}
+
+/// Hashes an integer in little-endian byte order.
+fn update_u64(hasher: &mut Sha256, value: u64) {
+    hasher.update(value.to_le_bytes());
+}
+
+impl From<[u8; COMMITMENT_LEN]> for Commitment {
+    fn from(bytes: [u8; COMMITMENT_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl fmt::Display for Commitment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::write(f, &self.0)
+    }
+}
+
+impl FromStr for Commitment {
+    type Err = ParseCommitmentError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode_array(s).ok_or(ParseCommitmentError(()))?;
+        Ok(Self(bytes))
+    }
+}
+
+/// An error which can be returned when parsing a [`Commitment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCommitmentError(());
+
+impl fmt::Display for ParseCommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "commitment should be {} hexadecimal digits",
+            2 * COMMITMENT_LEN
+        )
+    }
+}
+
+impl Error for ParseCommitmentError {}
@@ -0,0 +1,167 @@
+use crate::feistel::RoundPolicy;
+use crate::{Algorithm, Seed};
+use alloc::vec::Vec;
+
+/// The configuration of a generator, excluding its list of items.
+///
+/// A configuration is obtained via [`DailyPicker::config`](crate::DailyPicker::config),
+/// and applied to a builder via [`WordleBuilder::config`](crate::WordleBuilder::config).
+/// Together with the same list of items, it reproduces the exact schedule
+/// of the generator it was obtained from, including vetoes.
+///
+/// With the `serde` feature, configurations can be serialized and
+/// deserialized. The serialized form has a `version` field, so newer
+/// schemas can add fields while still reading older configurations.
+/// Configurations with an unknown version are rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(into = "schema::Versioned", try_from = "schema::Versioned")
+)]
+#[non_exhaustive]
+pub struct WordleConfig {
+    /// The initial seed.
+    pub seed: Seed,
+    /// The number of consecutive days in which no item repeats.
+    pub window_len: u64,
+    /// The offset added to every day before looking up its item.
+    pub day_offset: u64,
+    /// The version of the algorithm that derives the schedule.
+    pub algorithm: Algorithm,
+    /// The label mixed into the derivation of every permutation, if any.
+    pub label: Option<Vec<u8>>,
+    /// The number of Feistel rounds.
+    pub rounds: usize,
+    /// Whether the generator is in windowless mode.
+    pub windowless: bool,
+    /// The sorted indices of the banned items.
+    pub banned: Vec<usize>,
+    /// The weight of each item, if any.
+    pub weights: Option<Vec<u32>>,
+    /// The minimum number of days between repeats across windows.
+    pub repeat_gap: u64,
+    /// The days from which the seed is replaced, and their seeds.
+    pub rotations: Vec<(u64, Seed)>,
+    /// The vetoed days, and the number of times each was vetoed.
+    pub vetoes: Vec<(u64, u32)>,
+    /// The pinned size of the domain of the permutations, if any.
+    pub capacity: Option<u64>,
+    /// The days from which appended items can be picked, and the index of
+    /// the first item of each batch.
+    pub appends: Vec<(u64, usize)>,
+    /// The policy that chooses the minimum number of rounds, if any.
+    pub round_policy: Option<RoundPolicy>,
+    /// Whether the input and output of the permutations are whitened.
+    pub whitening: bool,
+}
+
+#[cfg(feature = "serde")]
+mod schema {
+    use super::WordleConfig;
+    use crate::feistel::RoundPolicy;
+    use crate::{Algorithm, Seed};
+    use alloc::vec::Vec;
+    use core::fmt;
+    use serde::{Deserialize, Serialize};
+
+    /// The latest version of the serialized schema.
+    const VERSION: u32 = 4;
+
+    /// The serialized form of a [`WordleConfig`].
+    ///
+    /// Fields added by later versions must have a default value, so that
+    /// older configurations can still be read.
+    #[derive(Serialize, Deserialize)]
+    pub struct Versioned {
+        version: u32,
+        seed: Seed,
+        window_len: u64,
+        day_offset: u64,
+        algorithm: Algorithm,
+        // Added in version 2
+        #[serde(default)]
+        label: Option<Vec<u8>>,
+        rounds: usize,
+        windowless: bool,
+        banned: Vec<usize>,
+        weights: Option<Vec<u32>>,
+        repeat_gap: u64,
+        rotations: Vec<(u64, Seed)>,
+        vetoes: Vec<(u64, u32)>,
+        // Added in version 3
+        #[serde(default)]
+        capacity: Option<u64>,
+        #[serde(default)]
+        appends: Vec<(u64, usize)>,
+        // Added in version 4
+        #[serde(default)]
+        round_policy: Option<RoundPolicy>,
+        #[serde(default)]
+        whitening: bool,
+    }
+
+    pub struct UnsupportedVersion(u32);
+
+    impl fmt::Display for UnsupportedVersion {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(
+                f,
+                "unsupported config version {} (latest is {})",
+                self.0, VERSION
+            )
+        }
+    }
+
+    impl From<WordleConfig> for Versioned {
+        fn from(config: WordleConfig) -> Self {
+            Self {
+                version: VERSION,
+                seed: config.seed,
+                window_len: config.window_len,
+                day_offset: config.day_offset,
+                algorithm: config.algorithm,
+                label: config.label,
+                rounds: config.rounds,
+                windowless: config.windowless,
+                banned: config.banned,
+                weights: config.weights,
+                repeat_gap: config.repeat_gap,
+                rotations: config.rotations,
+                vetoes: config.vetoes,
+                capacity: config.capacity,
+                appends: config.appends,
+                round_policy: config.round_policy,
+                whitening: config.whitening,
+            }
+        }
+    }
+
+    impl TryFrom<Versioned> for WordleConfig {
+        type Error = UnsupportedVersion;
+
+        fn try_from(versioned: Versioned) -> Result<Self, Self::Error> {
+            if versioned.version == 0 || versioned.version > VERSION {
+                return Err(UnsupportedVersion(versioned.version));
+            }
+            Ok(Self {
+                seed: versioned.seed,
+                window_len: versioned.window_len,
+                day_offset: versioned.day_offset,
+                algorithm: versioned.algorithm,
+                label: versioned.label,
+                rounds: versioned.rounds,
+                windowless: versioned.windowless,
+                banned: versioned.banned,
+                weights: versioned.weights,
+                repeat_gap: versioned.repeat_gap,
+                rotations: versioned.rotations,
+                vetoes: versioned.vetoes,
+                capacity: versioned.capacity,
+                appends: versioned.appends,
+                round_policy: versioned.round_policy,
+                whitening: versioned.whitening,
+            })
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::items::Items;
+use alloc::vec::Vec;
+
+/// A list of words that supports fast lookups.
+pub(crate) struct Dictionary<'a, T> {
+    words: Items<'a, T>,
+    // The indices of the words, sorted by word
+    order: Vec<usize>,
+}
+
+impl<'a, T: AsRef<str>> Dictionary<'a, T> {
+    /// Creates a dictionary over the given words.
+    pub fn new(words: Items<'a, T>) -> Self {
+        let mut order: Vec<usize> = (0..words.len()).collect();
+        order.sort_unstable_by(|&a, &b| words[a].as_ref().cmp(words[b].as_ref()));
+        Self { words, order }
+    }
+
+    /// Returns whether the dictionary contains `word`.
+    pub fn contains(&self, word: &str) -> bool {
+        self.order
+            .binary_search_by(|&idx| self.words[idx].as_ref().cmp(word))
+            .is_ok()
+    }
+
+    /// Returns a dictionary that borrows the words of this dictionary.
+    pub fn borrowed(&self) -> Dictionary<'_, T> {
+        Dictionary {
+            words: Items::Borrowed(&self.words),
+            order: self.order.clone(),
+        }
+    }
+}
+
+impl<'a, T> Dictionary<'a, T> {
+    /// Returns the number of words in the dictionary.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+}
+
+impl<'a, T: Clone> Dictionary<'a, T> {
+    /// Converts the dictionary into one that owns its words,
+    /// cloning the words if they are borrowed.
+    pub fn into_owned(self) -> Dictionary<'static, T> {
+        Dictionary {
+            words: self.words.into_owned(),
+            order: self.order,
+        }
+    }
+}
+
+impl<'a, T: Clone> Clone for Dictionary<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            words: self.words.clone(),
+            order: self.order.clone(),
+        }
+    }
+}
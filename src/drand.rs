@@ -0,0 +1,236 @@
+//! Per-window seeds derived from the [drand](https://drand.love) randomness beacon.
+//!
+//! The seed of each window is derived from the beacon round that is emitted
+//! when the window starts, so nobody (not even the operator) can predict the
+//! words of a window before then. Players can fetch the same round from any
+//! drand relay, and check the schedule.
+//!
+//! Beacons are fetched by the caller, e.g. from
+//! `https://api.drand.sh/{chain_hash}/public/{round}`, and parsed via
+//! [`DrandBeacon::from_json`]. This module checks that the randomness of a
+//! beacon matches its signature, but doesn't verify the BLS signature itself,
+//! which should be done with a drand client.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::drand::{DrandBeacon, DrandSchedule, DrandChain};
+//! use wordle_generator::{Seed, Wordle};
+//!
+//! let words = ["apple", "grape", "lemon"];
+//! let mut wordle = Wordle::from_seed(&words, 3, Seed::new([0; 32]));
+//! // Day 0 starts at 2024-01-01T00:00:00Z
+//! let schedule = DrandSchedule::new(DrandChain::DEFAULT, 1_704_067_200);
+//! assert_eq!(schedule.round_for_day(0), 3_621_206);
+//!
+//! let json = r#"{
+//!     "round": 3621206,
+//!     "randomness": "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+//!     "signature": "00"
+//! }"#;
+//! let beacon = DrandBeacon::from_json(json).unwrap();
+//! schedule.apply(&mut wordle, 0, &beacon, None).unwrap();
+//! ```
+
+use crate::{hex, KeyedPermutation, Seed, Wordle};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+/// The number of seconds in a day.
+const SECS_PER_DAY: u64 = 86_400;
+
+/// The parameters of a drand chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrandChain {
+    /// The hash that identifies the chain.
+    pub chain_hash: [u8; 32],
+    /// The Unix time of round 1, in seconds.
+    pub genesis_time: u64,
+    /// The number of seconds between rounds.
+    pub period: u64,
+}
+
+impl DrandChain {
+    /// The default chain of the League of Entropy, which emits a round every
+    /// 30 seconds.
+    pub const DEFAULT: DrandChain = DrandChain {
+        chain_hash: [
+            0x89, 0x90, 0xe7, 0xa9, 0xaa, 0xed, 0x2f, 0xfe, 0xd7, 0x3d, 0xbd, 0x70, 0x92, 0x12,
+            0x3d, 0x6f, 0x28, 0x99, 0x30, 0x54, 0x0d, 0x76, 0x51, 0x33, 0x62, 0x25, 0xdc, 0x17,
+            0x2e, 0x51, 0xb2, 0xce,
+        ],
+        genesis_time: 1_595_431_050,
+        period: 30,
+    };
+
+    /// Returns the latest round emitted at the given Unix time, or `0` if
+    /// the time is before the genesis.
+    pub fn round_at(&self, unix_time: u64) -> u64 {
+        match unix_time.checked_sub(self.genesis_time) {
+            Some(elapsed) => elapsed / self.period + 1,
+            None => 0,
+        }
+    }
+}
+
+/// A round of a drand chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DrandBeacon {
+    round: u64,
+    randomness: [u8; 32],
+    signature: Vec<u8>,
+}
+
+impl DrandBeacon {
+    /// Creates a beacon from its round and signature.
+    pub fn new(round: u64, signature: &[u8]) -> Self {
+        Self {
+            round,
+            randomness: Sha256::digest(signature).into(),
+            signature: signature.to_vec(),
+        }
+    }
+
+    /// Parses a beacon from the JSON returned by a drand relay.
+    ///
+    /// Returns an error if the JSON is invalid, or the randomness doesn't
+    /// match the signature.
+    pub fn from_json(json: &str) -> Result<Self, DrandError> {
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|_| DrandError::InvalidJson)?;
+        let round = value["round"].as_u64().ok_or(DrandError::InvalidJson)?;
+        let hex_field = |name: &str| {
+            value[name]
+                .as_str()
+                .and_then(hex::decode)
+                .ok_or(DrandError::InvalidJson)
+        };
+        let randomness = hex_field("randomness")?;
+        let signature = hex_field("signature")?;
+        let beacon = Self::new(round, &signature);
+        if beacon.randomness[..] != randomness[..] {
+            return Err(DrandError::RandomnessMismatch);
+        }
+        Ok(beacon)
+    }
+
+    /// Returns the round number.
+    pub fn round(&self) -> u64 {
+        self.round
+    }
+
+    /// Returns the randomness of the round, which is the SHA-256 hash of
+    /// its signature.
+    pub fn randomness(&self) -> &[u8; 32] {
+        &self.randomness
+    }
+
+    /// Returns the seed derived from the randomness of the round.
+    ///
+    /// If a secret seed is given, the derived seed also depends on it, so
+    /// the schedule stays private until the secret is revealed.
+    pub fn seed(&self, secret: Option<&Seed>) -> Seed {
+        let mut label = b"drand:".to_vec();
+        label.extend_from_slice(&self.round.to_le_bytes());
+        label.extend_from_slice(&self.randomness);
+        secret.unwrap_or(&Seed::new([0; 32])).derive_child(&label)
+    }
+}
+
+/// Maps the windows of a generator to drand rounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DrandSchedule {
+    chain: DrandChain,
+    epoch: u64,
+}
+
+impl DrandSchedule {
+    /// Creates a schedule whose day `0` starts at the given Unix time.
+    pub fn new(chain: DrandChain, epoch: u64) -> Self {
+        Self { chain, epoch }
+    }
+
+    /// Returns the round emitted when the given day starts.
+    pub fn round_for_day(&self, day: u64) -> u64 {
+        let start = self.epoch.saturating_add(day.saturating_mul(SECS_PER_DAY));
+        self.chain.round_at(start)
+    }
+
+    /// Returns the first day of the given window.
+    fn window_start<T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation>(
+        wordle: &Wordle<'_, T, ROUNDS, P>,
+        window: u64,
+    ) -> u64 {
+        let picker = wordle.picker();
+        let start = window.wrapping_mul(picker.window_len());
+        start.wrapping_sub(picker.day_offset())
+    }
+
+    /// Returns the round whose randomness seeds the given window.
+    pub fn round_for_window<T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation>(
+        &self,
+        wordle: &Wordle<'_, T, ROUNDS, P>,
+        window: u64,
+    ) -> u64 {
+        self.round_for_day(Self::window_start(wordle, window))
+    }
+
+    /// Replaces the seed of the generator from the start of the given window
+    /// by the seed derived from the beacon.
+    ///
+    /// Returns an error if the beacon is not the round of the window. Since
+    /// rotations from later days are discarded, windows must be applied in
+    /// order. See [`DrandBeacon::seed`] for details.
+    pub fn apply<T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation>(
+        &self,
+        wordle: &mut Wordle<'_, T, ROUNDS, P>,
+        window: u64,
+        beacon: &DrandBeacon,
+        secret: Option<&Seed>,
+    ) -> Result<(), DrandError> {
+        let expected = self.round_for_window(wordle, window);
+        if beacon.round != expected {
+            return Err(DrandError::WrongRound {
+                expected,
+                found: beacon.round,
+            });
+        }
+        wordle.rotate_seed(Self::window_start(wordle, window), beacon.seed(secret));
+        Ok(())
+    }
+}
+
+/// An error which can be returned when using drand beacons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DrandError {
+    /// The beacon JSON is invalid.
+    InvalidJson,
+    /// The randomness of the beacon is not the hash of its signature.
+    RandomnessMismatch,
+    /// The beacon is not the round of the window.
+    WrongRound {
+        /// The round of the window.
+        expected: u64,
+        /// The round of the beacon.
+        found: u64,
+    },
+}
+
+impl fmt::Display for DrandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrandError::InvalidJson => f.write_str("invalid drand beacon"),
+            DrandError::RandomnessMismatch => {
+                f.write_str("beacon randomness doesn't match its signature")
+            }
+            DrandError::WrongRound { expected, found } => {
+                write!(f, "expected drand round {}, but found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl Error for DrandError {}
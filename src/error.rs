@@ -0,0 +1,128 @@
+use crate::ParseSeedError;
+use core::error::Error;
+use core::fmt;
+
+/// The error type for the fallible operations of this crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum WordleError {
+    /// The word list is empty.
+    EmptyWords,
+    /// The window length is zero.
+    ZeroWindowLen,
+    /// The number of Feistel rounds is zero.
+    ZeroRounds,
+    /// A banned index is out of bounds of the list of words.
+    BannedOutOfBounds(usize),
+    /// Every word is banned.
+    AllBanned,
+    /// The number of weights differs from the number of words.
+    WeightsLenMismatch,
+    /// The sum of the weights overflows.
+    WeightsOverflow,
+    /// The repeat gap is longer than a third of the window length.
+    RepeatGapTooLong,
+    /// More distinct words were requested than can be picked.
+    NotEnoughWords {
+        /// The number of requested words.
+        requested: usize,
+        /// The number of words that can be picked.
+        available: usize,
+    },
+    /// A seed could not be parsed.
+    InvalidSeed(ParseSeedError),
+    /// No seed was set, and seeds can't be generated without the `std` feature.
+    MissingSeed,
+    /// A configuration uses a different number of rounds than the generator.
+    RoundsMismatch {
+        /// The number of rounds of the generator.
+        expected: usize,
+        /// The number of rounds of the configuration.
+        found: usize,
+    },
+    /// A generator over several lists has no lists.
+    NoLists,
+    /// A list index is out of bounds of the lists of a generator.
+    ListOutOfBounds(usize),
+    /// The list of words (or its total weight) exceeds the pinned capacity.
+    CapacityExceeded,
+    /// Appended words were set without pinning the capacity.
+    MissingCapacity,
+    /// The first index of appended words is zero or out of bounds of the
+    /// list of words.
+    AppendOutOfBounds(usize),
+    /// A word doesn't have the expected number of letters.
+    WordLength {
+        /// The expected number of letters.
+        expected: usize,
+        /// The number of letters of the word.
+        found: usize,
+    },
+}
+
+impl fmt::Display for WordleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WordleError::EmptyWords => f.write_str("word list cannot be empty"),
+            WordleError::ZeroWindowLen => f.write_str("window length should be positive"),
+            WordleError::ZeroRounds => f.write_str("number of rounds should be positive"),
+            WordleError::BannedOutOfBounds(idx) => {
+                write!(f, "banned index {} is out of bounds", idx)
+            }
+            WordleError::AllBanned => f.write_str("every word is banned"),
+            WordleError::WeightsLenMismatch => {
+                f.write_str("number of weights should equal the number of words")
+            }
+            WordleError::WeightsOverflow => f.write_str("sum of weights overflows"),
+            WordleError::RepeatGapTooLong => {
+                f.write_str("repeat gap should be at most a third of the window length")
+            }
+            WordleError::NotEnoughWords {
+                requested,
+                available,
+            } => write!(
+                f,
+                "requested {} distinct words, but only {} can be picked",
+                requested, available
+            ),
+            WordleError::InvalidSeed(err) => write!(f, "invalid seed: {}", err),
+            WordleError::MissingSeed => f.write_str("a seed must be set"),
+            WordleError::RoundsMismatch { expected, found } => write!(
+                f,
+                "configuration uses {} rounds, but the generator uses {}",
+                found, expected
+            ),
+            WordleError::NoLists => f.write_str("at least one word list is required"),
+            WordleError::ListOutOfBounds(idx) => {
+                write!(f, "list index {} is out of bounds", idx)
+            }
+            WordleError::CapacityExceeded => {
+                f.write_str("word list should not exceed the pinned capacity")
+            }
+            WordleError::MissingCapacity => f.write_str("appended words require a pinned capacity"),
+            WordleError::AppendOutOfBounds(idx) => {
+                write!(f, "first appended index {} is out of bounds", idx)
+            }
+            WordleError::WordLength { expected, found } => write!(
+                f,
+                "word should have {} letters, but has {}",
+                expected, found
+            ),
+        }
+    }
+}
+
+impl Error for WordleError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WordleError::InvalidSeed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseSeedError> for WordleError {
+    fn from(err: ParseSeedError) -> Self {
+        WordleError::InvalidSeed(err)
+    }
+}
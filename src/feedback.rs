@@ -0,0 +1,125 @@
+use crate::WordleError;
+use alloc::vec;
+
+/// The feedback for a letter of a guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LetterFeedback {
+    /// The letter is in the answer at the same position (green).
+    Correct,
+    /// The letter is in the answer at another position (yellow).
+    Present,
+    /// The letter is not in the answer, or every occurrence in the answer is
+    /// already accounted for by other letters of the guess (gray).
+    Absent,
+}
+
+/// Returns the feedback for each letter of `guess`, given the `answer`.
+///
+/// Letters are compared exactly (i.e. case-sensitively), as Unicode scalar values.
+/// Normalize both words beforehand to compare letters regardless of their case
+/// or encoding (see [`Normalizer`](crate::Normalizer)).
+/// A letter that occurs more times in the guess than in the answer is marked as
+/// [`Correct`](LetterFeedback::Correct) at matching positions first, then as
+/// [`Present`](LetterFeedback::Present) from left to right while unmatched
+/// occurrences remain in the answer, and as [`Absent`](LetterFeedback::Absent)
+/// otherwise.
+///
+/// # Panics
+///
+/// Panics if either word doesn't have `N` letters.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::evaluate;
+/// use wordle_generator::LetterFeedback::{Absent, Correct, Present};
+///
+/// // Only one of the two "e"s of the guess is in the answer
+/// assert_eq!(
+///     evaluate("geese", "those"),
+///     [Absent, Absent, Absent, Correct, Correct]
+/// );
+/// assert_eq!(
+///     evaluate("speed", "abide"),
+///     [Absent, Absent, Present, Absent, Present]
+/// );
+/// ```
+pub fn evaluate<const N: usize>(guess: &str, answer: &str) -> [LetterFeedback; N] {
+    match try_evaluate(guess, answer) {
+        Ok(feedback) => feedback,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+/// Returns the feedback for each letter of `guess`, given the `answer`, or
+/// returns an error if either word doesn't have `N` letters.
+///
+/// See [`evaluate`] for details.
+pub fn try_evaluate<const N: usize>(
+    guess: &str,
+    answer: &str,
+) -> Result<[LetterFeedback; N], WordleError> {
+    let guess = letters::<N>(guess)?;
+    let answer = letters::<N>(answer)?;
+    let mut feedback = [LetterFeedback::Absent; N];
+    evaluate_letters(&guess, &answer, &mut feedback);
+    Ok(feedback)
+}
+
+/// Writes the feedback for each letter of `guess` to `feedback`, given the
+/// `answer`. The slices must have the same length.
+pub(crate) fn evaluate_letters(guess: &[char], answer: &[char], feedback: &mut [LetterFeedback]) {
+    let len = feedback.len();
+    feedback.fill(LetterFeedback::Absent);
+    // Whether each letter of the answer is matched by a letter of the guess
+    let mut matched = vec![false; len];
+    for i in 0..len {
+        if guess[i] == answer[i] {
+            feedback[i] = LetterFeedback::Correct;
+            matched[i] = true;
+        }
+    }
+    for i in 0..len {
+        if feedback[i] == LetterFeedback::Correct {
+            continue;
+        }
+        let unmatched = (0..len).find(|&j| !matched[j] && answer[j] == guess[i]);
+        if let Some(j) = unmatched {
+            feedback[i] = LetterFeedback::Present;
+            matched[j] = true;
+        }
+    }
+}
+
+/// Encodes the feedback of a guess as an integer, which is unique for words
+/// of up to 40 letters.
+pub(crate) fn encode_pattern(feedback: &[LetterFeedback]) -> u64 {
+    feedback.iter().fold(0, |acc, f| {
+        let digit = match f {
+            LetterFeedback::Absent => 0,
+            LetterFeedback::Present => 1,
+            LetterFeedback::Correct => 2,
+        };
+        acc.wrapping_mul(3).wrapping_add(digit)
+    })
+}
+
+/// Returns the letters of `word`, or an error if it doesn't have `N` letters.
+fn letters<const N: usize>(word: &str) -> Result<[char; N], WordleError> {
+    let mut letters = ['\0'; N];
+    let mut len = 0;
+    for letter in word.chars() {
+        if len < N {
+            letters[len] = letter;
+        }
+        len += 1;
+    }
+    if len != N {
+        return Err(WordleError::WordLength {
+            expected: N,
+            found: len,
+        });
+    }
+    Ok(letters)
+}
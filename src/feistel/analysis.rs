@@ -0,0 +1,483 @@
+use crate::Permutation;
+use alloc::vec;
+use core::error::Error;
+use core::fmt;
+
+/// The distribution of the number of cycle walking steps of
+/// [`FeistelNetwork::permute_bounded`](crate::feistel::FeistelNetwork::permute_bounded),
+/// to plan for the tail latency of lookups.
+///
+/// Cycle walking over `0..domain_len` with a network of `2^bit_len` integers
+/// takes `2^bit_len / domain_len` steps on average over the domain, which is
+/// less than 2 for networks of the smallest bit length. The number of steps
+/// of a single lookup varies, and the tail is estimated as for a random
+/// permutation: each step leaves the domain with probability
+/// `1 - domain_len / 2^bit_len`.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::CycleWalkStats;
+///
+/// // 2309 words need a 12-bit network
+/// let stats = CycleWalkStats::new(2309, 12);
+/// assert!((stats.expected_steps - 1.77).abs() < 0.01);
+/// assert_eq!(stats.worst_case_steps, 1788);
+/// assert_eq!(stats.steps_at_quantile(0.999), 9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct CycleWalkStats {
+    /// The size of the walked domain.
+    pub domain_len: u64,
+    /// The number of bits of the network.
+    pub bit_len: u8,
+    /// The average number of steps over the inputs of the domain.
+    pub expected_steps: f64,
+    /// The largest possible number of steps, if every integer outside the
+    /// domain is on the same cycle.
+    pub worst_case_steps: u128,
+}
+
+impl CycleWalkStats {
+    /// Returns the statistics of cycle walking over `0..domain_len` with a
+    /// network of `2^bit_len` integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain_len` is zero or greater than `2^bit_len`, or if
+    /// `bit_len` is greater than `u64::BITS`.
+    pub fn new(domain_len: u64, bit_len: u8) -> Self {
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            bit_len as u32 <= u64::BITS,
+            "bit_len (is {}) should be <= {}",
+            bit_len,
+            u64::BITS
+        );
+        let network_len = 1u128 << bit_len;
+        assert!(
+            u128::from(domain_len) <= network_len,
+            "domain_len (is {}) should be <= 2^{}",
+            domain_len,
+            bit_len
+        );
+        Self {
+            domain_len,
+            bit_len,
+            expected_steps: network_len as f64 / domain_len as f64,
+            worst_case_steps: network_len - u128::from(domain_len) + 1,
+        }
+    }
+
+    /// Returns the estimated probability that a lookup takes more than
+    /// `steps` steps.
+    pub fn tail_probability(&self, steps: u64) -> f64 {
+        // (1 - domain_len / 2^bit_len)^steps, by squaring
+        let (mut base, mut exponent) = (self.leave_probability(), steps);
+        let mut probability = 1.0;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                probability *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        probability
+    }
+
+    /// Returns the estimated number of steps that a fraction `quantile` of
+    /// the lookups doesn't exceed, e.g. `0.999` for the 99.9th percentile.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `quantile` is not between 0 and 1.
+    pub fn steps_at_quantile(&self, quantile: f64) -> u64 {
+        assert!(
+            (0.0..=1.0).contains(&quantile),
+            "quantile (is {}) should be between 0 and 1",
+            quantile
+        );
+        let leave = self.leave_probability();
+        let mut steps = 1;
+        let mut tail = leave;
+        while tail > 1.0 - quantile && u128::from(steps) < self.worst_case_steps {
+            tail *= leave;
+            steps += 1;
+        }
+        steps
+    }
+
+    /// Returns the probability that a step leaves the domain.
+    fn leave_probability(&self) -> f64 {
+        1.0 - 1.0 / self.expected_steps
+    }
+}
+
+/// Counts the lookups and cycle walking steps of
+/// [`FeistelNetwork::permute_bounded_counted`](crate::feistel::FeistelNetwork::permute_bounded_counted),
+/// to compare the observed steps with [`CycleWalkStats`].
+///
+/// The counter isn't synchronized: share it between threads behind a mutex,
+/// or keep one per thread and [`CycleWalkCounter::merge`] them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CycleWalkCounter {
+    lookups: u64,
+    steps: u64,
+    max_steps: u64,
+}
+
+impl CycleWalkCounter {
+    /// Creates a counter without lookups.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a lookup that took the given number of steps.
+    pub fn record(&mut self, steps: u64) {
+        self.lookups += 1;
+        self.steps += steps;
+        self.max_steps = self.max_steps.max(steps);
+    }
+
+    /// Adds the lookups recorded by another counter.
+    pub fn merge(&mut self, other: &CycleWalkCounter) {
+        self.lookups += other.lookups;
+        self.steps += other.steps;
+        self.max_steps = self.max_steps.max(other.max_steps);
+    }
+
+    /// Returns the number of recorded lookups.
+    pub fn lookups(&self) -> u64 {
+        self.lookups
+    }
+
+    /// Returns the total number of steps of the recorded lookups.
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Returns the largest number of steps of a recorded lookup.
+    pub fn max_steps(&self) -> u64 {
+        self.max_steps
+    }
+
+    /// Returns the average number of steps of the recorded lookups, or zero
+    /// if there are none.
+    pub fn mean_steps(&self) -> f64 {
+        match self.lookups {
+            0 => 0.0,
+            lookups => self.steps as f64 / lookups as f64,
+        }
+    }
+}
+
+/// The largest domain that
+/// [`FeistelNetwork::verify_bijective`](crate::feistel::FeistelNetwork::verify_bijective)
+/// checks exhaustively.
+pub const EXHAUSTIVE_VERIFY_LEN: u64 = 1 << 24;
+
+/// The number of inputs that [`FeistelNetwork::verify_bijective`] checks for
+/// larger domains.
+const VERIFY_SAMPLES: u64 = 1 << 16;
+
+/// The error returned by
+/// [`FeistelNetwork::verify_bijective`](crate::feistel::FeistelNetwork::verify_bijective)
+/// if the bounded permutation is not a bijection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BijectivityError {
+    /// Two inputs are mapped to the same output.
+    Collision {
+        /// The colliding inputs, in increasing order.
+        inputs: [u64; 2],
+        /// The output of both inputs.
+        output: u64,
+    },
+    /// An input is mapped outside the domain.
+    OutOfDomain {
+        /// The input that was permuted.
+        input: u64,
+        /// The output of the input.
+        output: u64,
+    },
+    /// The inverse permutation doesn't map the output of an input back to
+    /// the input.
+    InverseMismatch {
+        /// The input that was permuted.
+        input: u64,
+        /// The output of the input.
+        output: u64,
+        /// The inverse of the output.
+        inverse: u64,
+    },
+}
+
+impl fmt::Display for BijectivityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BijectivityError::Collision { inputs, output } => write!(
+                f,
+                "inputs {} and {} are both mapped to {}",
+                inputs[0], inputs[1], output
+            ),
+            BijectivityError::OutOfDomain { input, output } => {
+                write!(
+                    f,
+                    "input {} is mapped outside the domain to {}",
+                    input, output
+                )
+            }
+            BijectivityError::InverseMismatch {
+                input,
+                output,
+                inverse,
+            } => write!(
+                f,
+                "input {} is mapped to {}, whose inverse is {}",
+                input, output, inverse
+            ),
+        }
+    }
+}
+
+impl Error for BijectivityError {}
+
+/// Checks that `permute` is a bijection of `0..domain_len`, exhaustively for
+/// small domains, and by comparing `permute_inv` on a sample otherwise.
+pub(super) fn verify_bijective(
+    domain_len: u64,
+    permute: impl Fn(u64) -> u64,
+    permute_inv: impl Fn(u64) -> u64,
+) -> Result<(), BijectivityError> {
+    assert!(domain_len > 0, "domain cannot be empty");
+    if domain_len > EXHAUSTIVE_VERIFY_LEN {
+        for sample in 0..VERIFY_SAMPLES {
+            let input =
+                (u128::from(sample) * 0x9E37_79B9_7F4A_7C15 % u128::from(domain_len)) as u64;
+            let output = permute(input);
+            if output >= domain_len {
+                return Err(BijectivityError::OutOfDomain { input, output });
+            }
+            let inverse = permute_inv(output);
+            if inverse != input {
+                return Err(BijectivityError::InverseMismatch {
+                    input,
+                    output,
+                    inverse,
+                });
+            }
+        }
+        return Ok(());
+    }
+
+    // One bit per output
+    let mut seen = vec![0u64; domain_len.div_ceil(64) as usize];
+    for input in 0..domain_len {
+        let output = permute(input);
+        if output >= domain_len {
+            return Err(BijectivityError::OutOfDomain { input, output });
+        }
+        let (word, bit) = ((output / 64) as usize, 1 << (output % 64));
+        if seen[word] & bit != 0 {
+            // Some earlier input has the same output
+            let first = (0..input).find(|&first| permute(first) == output).unwrap();
+            return Err(BijectivityError::Collision {
+                inputs: [first, input],
+                output,
+            });
+        }
+        seen[word] |= bit;
+    }
+    Ok(())
+}
+
+/// The result of [`analysis`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct AnalysisReport {
+    /// The number of analyzed inputs.
+    pub samples: u64,
+    /// The number of bits of the analyzed inputs and outputs.
+    pub bit_len: u8,
+    /// The mean fraction of output bits that flip when flipping a single
+    /// input bit, which is `0.5` for a random permutation.
+    pub avalanche: f64,
+    /// The largest deviation from `0.5` of the probability that flipping a
+    /// given input bit flips a given output bit. This is close to zero for a
+    /// random permutation (given enough samples), and `0.5` if some output
+    /// bit depends linearly on some input bit.
+    pub max_bias: f64,
+    /// The number of analyzed inputs that the permutation maps to themselves.
+    pub fixed_points: u64,
+    /// The expected number of fixed points among the analyzed inputs for a
+    /// random permutation.
+    pub expected_fixed_points: f64,
+}
+
+/// Measures the diffusion of a permutation, to compare round functions and
+/// numbers of rounds empirically.
+///
+/// Every input bit of `samples` inputs (or of every input, for smaller
+/// domains) is flipped, and the flipped output bits are counted. Bit flips
+/// that leave the domain are skipped. The inputs are spread over the domain
+/// deterministically, so the report of a permutation never changes.
+///
+/// Passing these checks doesn't make a permutation secure, but failing them
+/// shows that it has too few rounds.
+///
+/// # Panics
+///
+/// Panics if `samples` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::{analysis, DynFeistelNetwork};
+///
+/// let weak = DynFeistelNetwork::from_master_key(16, 2, b"a secret master key");
+/// let strong = DynFeistelNetwork::from_master_key(16, 8, b"a secret master key");
+/// assert!(analysis(&weak, 4096).max_bias > 0.4);
+/// assert!(analysis(&strong, 4096).max_bias < 0.1);
+/// ```
+pub fn analysis<P: Permutation + ?Sized>(permutation: &P, samples: u64) -> AnalysisReport {
+    assert!(samples > 0, "samples should be positive");
+    let domain_len = permutation.domain_len();
+    let bit_len = (u128::BITS - (domain_len - 1).leading_zeros()).max(1) as usize;
+    let samples = samples.min(domain_len.try_into().unwrap_or(u64::MAX));
+    let exhaustive = u128::from(samples) == domain_len;
+
+    // The times that each input bit flipped each output bit, by input bit
+    let mut flips = vec![0u64; bit_len * bit_len];
+    let mut pairs = vec![0u64; bit_len];
+    let mut fixed_points = 0;
+    for sample in 0..samples {
+        let input = match exhaustive {
+            true => sample,
+            false => (u128::from(sample) * 0x9E37_79B9_7F4A_7C15 % domain_len) as u64,
+        };
+        let output = permutation.permute(input);
+        fixed_points += u64::from(output == input);
+        for bit in 0..bit_len {
+            let flipped = input ^ (1 << bit);
+            if !permutation.contains(flipped) {
+                continue;
+            }
+            pairs[bit] += 1;
+            let diff = output ^ permutation.permute(flipped);
+            let counts = &mut flips[bit * bit_len..][..bit_len];
+            for (out_bit, count) in counts.iter_mut().enumerate() {
+                *count += (diff >> out_bit) & 1;
+            }
+        }
+    }
+
+    let total_pairs: u64 = pairs.iter().sum();
+    let total_flips: u64 = flips.iter().sum();
+    let mut max_bias: f64 = 0.0;
+    for (bit, &pairs) in pairs.iter().enumerate().filter(|(_, &pairs)| pairs > 0) {
+        for &count in &flips[bit * bit_len..][..bit_len] {
+            // |count / pairs - 1/2|, without `f64::abs`
+            let bias = (2 * count).abs_diff(pairs) as f64 / (2 * pairs) as f64;
+            max_bias = max_bias.max(bias);
+        }
+    }
+    AnalysisReport {
+        samples,
+        bit_len: bit_len as u8,
+        avalanche: match total_pairs {
+            0 => 0.0,
+            _ => total_flips as f64 / (total_pairs * bit_len as u64) as f64,
+        },
+        max_bias,
+        fixed_points,
+        expected_fixed_points: samples as f64 / domain_len as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BijectivityError, CycleWalkCounter, CycleWalkStats};
+    use crate::feistel::{DynFeistelNetwork, FeistelNetwork};
+
+    #[test]
+    fn analysis() {
+        let weak = FeistelNetwork::<1>::with_keys(12, [42]);
+        let report = super::analysis(&weak, 10_000);
+        assert_eq!(report.samples, 4096);
+        assert_eq!(report.bit_len, 12);
+        // Flipping an upper bit only flips the bit it moves to
+        assert_eq!(report.max_bias, 0.5);
+        assert!(report.avalanche < 0.4);
+
+        let network = FeistelNetwork::<8>::from_master_key(12, b"master key");
+        let report = super::analysis(&network, 10_000);
+        assert!((report.avalanche - 0.5).abs() < 0.01);
+        assert!(report.max_bias < 0.05);
+        assert!(report.fixed_points < 10);
+        assert_eq!(report.expected_fixed_points, 1.0);
+
+        // Bit flips can leave domains that aren't powers of two
+        let shuffle = crate::shuffle::SwapOrNot::with_keys(1000, &[7; 64]);
+        let report = super::analysis(&shuffle, 500);
+        assert_eq!(report.samples, 500);
+        assert_eq!(report.bit_len, 10);
+    }
+
+    #[test]
+    fn cycle_walk_stats() {
+        let stats = CycleWalkStats::new(2309, 12);
+        assert_eq!(stats.tail_probability(0), 1.0);
+        assert!((stats.tail_probability(1) - (1.0 - 2309.0 / 4096.0)).abs() < 1e-12);
+        assert_eq!(stats.steps_at_quantile(0.0), 1);
+        assert_eq!(CycleWalkStats::new(4096, 12).steps_at_quantile(1.0), 1);
+        assert_eq!(CycleWalkStats::new(1, 2).steps_at_quantile(1.0), 4);
+
+        // Every integer outside the domain is walked once over the domain
+        let network = FeistelNetwork::<8>::with_keys(12, [3, 1, 4, 1, 5, 9, 2, 6]);
+        let mut counter = CycleWalkCounter::new();
+        for value in 0..2309 {
+            let output = network.permute_bounded_counted(value, 2309, &mut counter);
+            assert_eq!(output, network.permute_bounded(value, 2309));
+        }
+        assert_eq!(counter.lookups(), 2309);
+        assert_eq!(counter.steps(), 4096);
+        assert_eq!(counter.mean_steps(), stats.expected_steps);
+        assert!(counter.max_steps() >= stats.steps_at_quantile(0.99));
+
+        let mut merged = CycleWalkCounter::new();
+        merged.merge(&counter);
+        merged.merge(&counter);
+        assert_eq!(merged.steps(), 8192);
+        assert_eq!(merged.max_steps(), counter.max_steps());
+    }
+
+    #[test]
+    fn verify_bijective() {
+        let network = FeistelNetwork::<8>::with_keys(13, [3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(network.verify_bijective(5757), Ok(()));
+        let dynamic = DynFeistelNetwork::with_keys(40, &[3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(dynamic.verify_bijective(1 << 39 | 42), Ok(()));
+
+        let halve = super::verify_bijective(10, |input| input / 2, |output| output * 2);
+        let collision = BijectivityError::Collision {
+            inputs: [0, 1],
+            output: 0,
+        };
+        assert_eq!(halve, Err(collision));
+        assert_eq!(collision.to_string(), "inputs 0 and 1 are both mapped to 0");
+        let shift = super::verify_bijective(10, |input| input + 1, |output| output - 1);
+        assert_eq!(
+            shift,
+            Err(BijectivityError::OutOfDomain {
+                input: 9,
+                output: 10
+            })
+        );
+        let broken = super::verify_bijective(1 << 30, |input| input, |_| 0);
+        assert!(matches!(
+            broken,
+            Err(BijectivityError::InverseMismatch { inverse: 0, .. })
+        ));
+    }
+}
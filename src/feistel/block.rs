@@ -0,0 +1,225 @@
+use super::{expand_master_key, set_keys_from_bytes, KeyMaterialError, RoundFunction, WyHashRound};
+use core::fmt;
+
+/// A Feistel network that permutes byte blocks of any fixed size `N >= 2`,
+/// such as identifiers or share tokens.
+///
+/// The block is split into halves of `N - N / 2` and `N / 2` bytes. Every
+/// round XORs one half with bytes derived from the other half, by chaining
+/// the round function over its 8-byte chunks and then expanding the result.
+/// Since the chained state has 64 bits, the network has at most 64-bit
+/// security regardless of the block size. Blocks of different sizes are
+/// permuted independently, so one network can serve several sizes.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::BlockFeistelNetwork;
+///
+/// let network = BlockFeistelNetwork::<8>::from_master_key(b"a secret master key");
+/// let token = *b"share-token-0042";
+/// let obfuscated = network.permute(token);
+/// assert_ne!(obfuscated, token);
+/// assert_eq!(network.permute_inv(obfuscated), token);
+/// ```
+#[derive(Clone)]
+pub struct BlockFeistelNetwork<const ROUNDS: usize, F = WyHashRound> {
+    keys: [u64; ROUNDS],
+    round_function: F,
+}
+
+impl<const ROUNDS: usize> BlockFeistelNetwork<ROUNDS> {
+    /// Creates a network to permute byte blocks.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`BlockFeistelNetwork::from_master_key`], or initialize the keys by
+    /// mutating `BlockFeistelNetwork::keys_mut`.
+    pub fn new() -> Self {
+        Self::with_keys([0; ROUNDS])
+    }
+
+    /// Creates a network to permute byte blocks with the given round keys.
+    pub fn with_keys(keys: [u64; ROUNDS]) -> Self {
+        Self::with_round_function(keys, WyHashRound::default())
+    }
+
+    /// Creates a network to permute byte blocks, whose round keys are derived
+    /// from a secret master key. See
+    /// [`FeistelNetwork::from_master_key`](crate::feistel::FeistelNetwork::from_master_key).
+    ///
+    /// The keys are domain-separated from the keys of integer networks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ROUNDS` is greater than
+    /// [`MAX_MASTER_KEY_ROUNDS`](crate::feistel::MAX_MASTER_KEY_ROUNDS).
+    pub fn from_master_key(master_key: &[u8]) -> Self {
+        let mut network = Self::new();
+        // Integer networks have at least one bit
+        expand_master_key(master_key, 0, &mut network.keys);
+        network
+    }
+}
+
+impl<const ROUNDS: usize> Default for BlockFeistelNetwork<ROUNDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> BlockFeistelNetwork<ROUNDS, F> {
+    /// Creates a network to permute byte blocks, using the given round
+    /// function.
+    pub fn with_round_function(keys: [u64; ROUNDS], round_function: F) -> Self {
+        Self {
+            keys,
+            round_function,
+        }
+    }
+
+    /// Returns the output of the permutation for `block`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than 2.
+    pub fn permute<const N: usize>(&self, mut block: [u8; N]) -> [u8; N] {
+        assert!(
+            N >= 2,
+            "blocks (are {} bytes) should have 2 bytes or more",
+            N
+        );
+        let mut head_len = N - N / 2;
+        for &key in &self.keys {
+            let (head, tail) = block.split_at_mut(head_len);
+            self.round(key, tail, head);
+            // The tail becomes the head of the next round
+            block.rotate_left(head_len);
+            head_len = N - head_len;
+        }
+        block
+    }
+
+    /// Returns the block that [`BlockFeistelNetwork::permute`] maps to
+    /// `block`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is less than 2.
+    pub fn permute_inv<const N: usize>(&self, mut block: [u8; N]) -> [u8; N] {
+        assert!(
+            N >= 2,
+            "blocks (are {} bytes) should have 2 bytes or more",
+            N
+        );
+        // The length of the head of the last round
+        let mut head_len = match ROUNDS % 2 {
+            0 => N / 2,
+            _ => N - N / 2,
+        };
+        for &key in self.keys.iter().rev() {
+            block.rotate_right(head_len);
+            let (head, tail) = block.split_at_mut(head_len);
+            self.round(key, tail, head);
+            head_len = N - head_len;
+        }
+        block
+    }
+
+    /// Returns the number of rounds of the network.
+    pub fn rounds(&self) -> usize {
+        ROUNDS
+    }
+
+    /// Returns the round function of the network.
+    pub fn round_function(&self) -> &F {
+        &self.round_function
+    }
+
+    /// Returns a mutable reference to the array of round keys used by the
+    /// network.
+    ///
+    /// The round keys must be secret, independent and uniformly random.
+    /// Prefer [`BlockFeistelNetwork::from_master_key`].
+    pub fn keys_mut(&mut self) -> &mut [u64; ROUNDS] {
+        &mut self.keys
+    }
+
+    /// Sets the round keys from secret key material of any length. See
+    /// [`FeistelNetwork::set_keys_from_bytes`](crate::feistel::FeistelNetwork::set_keys_from_bytes).
+    pub fn set_keys_from_bytes(&mut self, bytes: &[u8]) -> Result<(), KeyMaterialError> {
+        set_keys_from_bytes(&mut self.keys, 0, bytes)
+    }
+
+    /// XORs `output` with bytes derived from `input`, keyed by `key`.
+    fn round(&self, key: u64, input: &[u8], output: &mut [u8]) {
+        // Separate the rounds of different block sizes
+        let block_len = (input.len() + output.len()) as u64;
+        let mut state = self.round_function.round(key, block_len);
+        for chunk in input.chunks(8) {
+            let mut bytes = [0; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            state = self.round_function.round(state, u64::from_le_bytes(bytes));
+        }
+        for (i, chunk) in output.chunks_mut(8).enumerate() {
+            let bytes = self.round_function.round(state, i as u64).to_le_bytes();
+            for (byte, mask) in chunk.iter_mut().zip(bytes) {
+                *byte ^= mask;
+            }
+        }
+    }
+}
+
+impl<const ROUNDS: usize, F> fmt::Debug for BlockFeistelNetwork<ROUNDS, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockFeistelNetwork")
+            .field("rounds", &ROUNDS)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::Zeroize for BlockFeistelNetwork<ROUNDS, F> {
+    fn zeroize(&mut self) {
+        self.keys.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> Drop for BlockFeistelNetwork<ROUNDS, F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::ZeroizeOnDrop for BlockFeistelNetwork<ROUNDS, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockFeistelNetwork;
+
+    #[test]
+    fn block() {
+        let network = BlockFeistelNetwork::<8>::from_master_key(b"master key");
+        let mut outputs: Vec<_> = (0..=u16::MAX)
+            .map(|value| u16::from_le_bytes(network.permute(value.to_le_bytes())))
+            .collect();
+        assert!((0..=u16::MAX).all(|value| {
+            let output = outputs[value as usize].to_le_bytes();
+            network.permute_inv(output) == value.to_le_bytes()
+        }));
+        outputs.sort();
+        assert!(outputs.into_iter().eq(0..=u16::MAX));
+
+        assert_eq!(
+            network.permute(*b"share-token-0042"),
+            [175, 32, 241, 125, 178, 50, 44, 59, 70, 147, 216, 81, 74, 66, 1, 41]
+        );
+        let odd = BlockFeistelNetwork::<7>::with_keys([3, 1, 4, 1, 5, 9, 2]);
+        let block = *b"thirteen byte";
+        assert_eq!(odd.permute_inv(odd.permute(block)), block);
+        // Blocks of different sizes are permuted independently
+        let prefix: [u8; 12] = block[..12].try_into().unwrap();
+        assert_ne!(odd.permute(prefix)[..12], odd.permute(block)[..12]);
+    }
+}
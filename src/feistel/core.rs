@@ -0,0 +1,895 @@
+use super::{
+    analysis::verify_bijective, set_keys_from_bytes, BijectivityError, CycleWalkCounter,
+    KeyMaterialError, OutOfDomainError, RoundFunction, WyHashRound,
+};
+use core::hint::black_box;
+
+/// The security level of
+/// [`FeistelNetwork::permute_bounded_constant_time`](crate::feistel::FeistelNetwork::permute_bounded_constant_time),
+/// in bits: its walk takes longer than
+/// [`FeistelNetwork::constant_time_steps`](crate::feistel::FeistelNetwork::constant_time_steps)
+/// with probability at most `2^-CONSTANT_TIME_BITS`.
+pub const CONSTANT_TIME_BITS: u32 = 32;
+
+/// The number of values that [`Core::permute_many`] permutes in lockstep.
+const LANES: usize = 4;
+
+/// The state of a network apart from its round keys, which permutes integers
+/// of up to 128 bits. Every round uses one key.
+#[derive(Clone)]
+pub(super) struct Core<F> {
+    upper_bits: u8,
+    lower_bits: u8,
+    pub(super) round_function: F,
+    // The keys XORed with the input before the first round, and with the
+    // output after the last round
+    pub(super) whitening: [u128; 2],
+}
+
+impl<F: RoundFunction> Core<F> {
+    pub(super) const fn new(bit_len: u8, max: u32, round_function: F) -> Self {
+        assert!(bit_len > 0, "bit_len should be positive");
+        // Formatting the bit length isn't supported in constant expressions
+        assert!(
+            bit_len as u32 <= max,
+            "bit_len should be at most the width of the integers"
+        );
+
+        Self {
+            upper_bits: bit_len - bit_len / 2,
+            lower_bits: bit_len / 2,
+            round_function,
+            whitening: [0; 2],
+        }
+    }
+
+    fn permute(&self, keys: &[u64], input: u128) -> u128 {
+        debug_assert!(
+            self.contains(input),
+            "input (is {}) should be < 2^{}",
+            input,
+            self.bit_len()
+        );
+        if self.bit_len() <= 32 {
+            return self.permute_narrow(keys, input as u32).into();
+        }
+        let input = input ^ self.whitening[0];
+        let (mut upper_bits, mut lower_bits) = (self.upper_bits, self.lower_bits);
+        let mut upper = input >> lower_bits;
+        let mut lower = input & mask(lower_bits);
+        for &key in keys {
+            let new_lower = upper ^ (self.round(key, lower) & mask(upper_bits));
+            upper = lower;
+            lower = new_lower;
+            (upper_bits, lower_bits) = (lower_bits, upper_bits);
+        }
+        (lower << upper_bits | upper) ^ self.whitening[1]
+    }
+
+    /// Permutes like [`Core::permute`] with 32-bit arithmetic, for networks
+    /// of at most 32 bits. This avoids 128-bit shifts and masks, which take
+    /// several instructions on 32-bit targets.
+    fn permute_narrow(&self, keys: &[u64], input: u32) -> u32 {
+        let input = input ^ self.whitening[0] as u32;
+        let (mut upper_bits, mut lower_bits) = (self.upper_bits, self.lower_bits);
+        let mut upper = input >> lower_bits;
+        let mut lower = input & mask_narrow(lower_bits);
+        for &key in keys {
+            let new_lower = upper ^ (self.round_narrow(key, lower) & mask_narrow(upper_bits));
+            upper = lower;
+            lower = new_lower;
+            (upper_bits, lower_bits) = (lower_bits, upper_bits);
+        }
+        (lower << upper_bits | upper) ^ self.whitening[1] as u32
+    }
+
+    /// Permutes `values` in place, [`LANES`] at a time.
+    ///
+    /// The rounds of the lanes are independent, so interleaving them hides
+    /// the latency of the round function.
+    fn permute_many(&self, keys: &[u64], values: &mut [u64]) {
+        let mut chunks = values.chunks_exact_mut(LANES);
+        for chunk in &mut chunks {
+            let mut upper = [0; LANES];
+            let mut lower = [0; LANES];
+            for (lane, &value) in chunk.iter().enumerate() {
+                debug_assert!(
+                    self.contains(value.into()),
+                    "input (is {}) should be < 2^{}",
+                    value,
+                    self.bit_len()
+                );
+                let input = u128::from(value) ^ self.whitening[0];
+                upper[lane] = input >> self.lower_bits;
+                lower[lane] = input & mask(self.lower_bits);
+            }
+            let (mut upper_bits, mut lower_bits) = (self.upper_bits, self.lower_bits);
+            for &key in keys {
+                for lane in 0..LANES {
+                    let new_lower = upper[lane] ^ (self.round(key, lower[lane]) & mask(upper_bits));
+                    upper[lane] = lower[lane];
+                    lower[lane] = new_lower;
+                }
+                (upper_bits, lower_bits) = (lower_bits, upper_bits);
+            }
+            for (lane, value) in chunk.iter_mut().enumerate() {
+                *value = ((lower[lane] << upper_bits | upper[lane]) ^ self.whitening[1]) as u64;
+            }
+        }
+        for value in chunks.into_remainder() {
+            *value = self.permute(keys, (*value).into()) as u64;
+        }
+    }
+
+    fn permute_inv(&self, keys: &[u64], output: u128) -> u128 {
+        debug_assert!(
+            self.contains(output),
+            "output (is {}) should be < 2^{}",
+            output,
+            self.bit_len()
+        );
+        if self.bit_len() <= 32 {
+            return self.permute_inv_narrow(keys, output as u32).into();
+        }
+        let output = output ^ self.whitening[1];
+        // The lengths of the halves after the last round
+        let (mut upper_bits, mut lower_bits) = match keys.len() % 2 {
+            0 => (self.upper_bits, self.lower_bits),
+            _ => (self.lower_bits, self.upper_bits),
+        };
+        let mut upper = output & mask(upper_bits);
+        let mut lower = output >> upper_bits;
+        for &key in keys.iter().rev() {
+            let prev_upper = lower ^ (self.round(key, upper) & mask(lower_bits));
+            lower = upper;
+            upper = prev_upper;
+            (upper_bits, lower_bits) = (lower_bits, upper_bits);
+        }
+        (upper << lower_bits | lower) ^ self.whitening[0]
+    }
+
+    /// Inverts like [`Core::permute_inv`] with 32-bit arithmetic, for
+    /// networks of at most 32 bits.
+    fn permute_inv_narrow(&self, keys: &[u64], output: u32) -> u32 {
+        let output = output ^ self.whitening[1] as u32;
+        let (mut upper_bits, mut lower_bits) = match keys.len() % 2 {
+            0 => (self.upper_bits, self.lower_bits),
+            _ => (self.lower_bits, self.upper_bits),
+        };
+        let mut upper = output & mask_narrow(upper_bits);
+        let mut lower = output >> upper_bits;
+        for &key in keys.iter().rev() {
+            let prev_upper = lower ^ (self.round_narrow(key, upper) & mask_narrow(lower_bits));
+            lower = upper;
+            upper = prev_upper;
+            (upper_bits, lower_bits) = (lower_bits, upper_bits);
+        }
+        (upper << lower_bits | lower) ^ self.whitening[0] as u32
+    }
+
+    /// Replaces every round key by the round function of `tweak`.
+    fn tweak_keys(&self, keys: &mut [u64], tweak: u64) {
+        for key in keys {
+            *key = self.round_function.round(*key, tweak);
+        }
+    }
+
+    /// Applies the round function to a half, which has at most 64 bits.
+    fn round(&self, key: u64, half: u128) -> u128 {
+        self.round_function.round(key, half as u64).into()
+    }
+
+    /// Applies the round function to a half of a network of at most 32 bits.
+    fn round_narrow(&self, key: u64, half: u32) -> u32 {
+        self.round_function.round(key, half.into()) as u32
+    }
+
+    fn try_permute(&self, keys: &[u64], input: u64) -> Result<u64, OutOfDomainError> {
+        if !self.contains(input.into()) {
+            return Err(OutOfDomainError {
+                input,
+                bit_len: self.bit_len(),
+            });
+        }
+        Ok(self.permute(keys, input.into()) as u64)
+    }
+
+    fn permute_bounded(&self, keys: &[u64], input: u128, domain_len: u128) -> u128 {
+        self.permute_bounded_steps(keys, input, domain_len).0
+    }
+
+    /// Cycle walks like [`Core::permute_bounded`], and also returns the number
+    /// of times the network was applied.
+    fn permute_bounded_steps(&self, keys: &[u64], input: u128, domain_len: u128) -> (u128, u64) {
+        self.check_bounded(input, domain_len);
+        cycle_walk(input, domain_len, |value| self.permute(keys, value))
+    }
+
+    fn permute_bounded_constant_time(&self, keys: &[u64], input: u128, domain_len: u128) -> u128 {
+        self.check_bounded(input, domain_len);
+        let mut value = input;
+        // All ones once an output within the domain was found
+        let mut found = 0u128;
+        let mut output = 0;
+        for _ in 0..self.constant_time_steps(domain_len) {
+            value = self.permute(keys, value);
+            // The comparison compiles to a flag, not a branch
+            let in_domain = black_box(0u128.wrapping_sub((value < domain_len).into()));
+            output |= value & in_domain & !found;
+            found |= in_domain;
+        }
+        if found != 0 {
+            return output;
+        }
+        // With probability at most 2^-CONSTANT_TIME_BITS
+        while value >= domain_len {
+            value = self.permute(keys, value);
+        }
+        value
+    }
+
+    /// Returns the number of steps of [`Core::permute_bounded_constant_time`].
+    fn constant_time_steps(&self, domain_len: u128) -> u64 {
+        assert!(domain_len > 0, "domain cannot be empty");
+        // A step leaves the domain with probability 1 - p, and (1 - p)^k <=
+        // e^(-pk), so k = ln(2) * CONSTANT_TIME_BITS / p steps suffice. The
+        // ratio 1 / p is rounded up, and 111 / 160 > ln(2)
+        let ratio = mask(self.bit_len()) / domain_len + 1;
+        let steps = ratio
+            .saturating_mul(111 * CONSTANT_TIME_BITS as u128)
+            .div_ceil(160);
+        steps.try_into().unwrap_or(u64::MAX)
+    }
+
+    fn permute_bounded_inv(&self, keys: &[u64], output: u128, domain_len: u128) -> u128 {
+        self.check_bounded(output, domain_len);
+        cycle_walk(output, domain_len, |value| self.permute_inv(keys, value)).0
+    }
+
+    fn check_bounded(&self, value: u128, domain_len: u128) {
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            self.contains(domain_len - 1),
+            "domain_len (is {}) should be <= 2^{}",
+            domain_len,
+            self.bit_len()
+        );
+        assert!(
+            value < domain_len,
+            "value (is {}) should be < {}",
+            value,
+            domain_len
+        );
+    }
+}
+
+impl Core<WyHashRound> {
+    /// Permutes like [`Core::permute`], but in constant expressions.
+    pub(super) const fn permute_const(&self, keys: &[u64], input: u128) -> u128 {
+        debug_assert!(self.contains(input), "input should be < 2^bit_len");
+        let input = input ^ self.whitening[0];
+        let (mut upper_bits, mut lower_bits) = (self.upper_bits, self.lower_bits);
+        let mut upper = input >> lower_bits;
+        let mut lower = input & mask(lower_bits);
+        let mut round = 0;
+        while round < keys.len() {
+            let output = self.round_function.round_const(keys[round], lower as u64);
+            let new_lower = upper ^ (output as u128 & mask(upper_bits));
+            upper = lower;
+            lower = new_lower;
+            (upper_bits, lower_bits) = (lower_bits, upper_bits);
+            round += 1;
+        }
+        (lower << upper_bits | upper) ^ self.whitening[1]
+    }
+}
+
+impl<F> Core<F> {
+    pub(super) const fn contains(&self, input: u128) -> bool {
+        match input.checked_shr(self.bit_len() as u32) {
+            Some(upper) => upper == 0,
+            None => true,
+        }
+    }
+
+    pub(super) const fn bit_len(&self) -> u8 {
+        self.upper_bits + self.lower_bits
+    }
+
+    fn set_whitening(&mut self, pre: u128, post: u128) {
+        let domain_mask = u128::MAX >> (u128::BITS as u8 - self.bit_len());
+        self.whitening = [pre & domain_mask, post & domain_mask];
+    }
+}
+
+/// A network made of round keys and a [`Core`], which permutes integers of
+/// type `Word`. The methods shared by the networks are implemented once here,
+/// and [`network_methods`] generates the inherent methods of each network
+/// that delegate to them.
+pub(super) trait Network {
+    type Word: Copy + Into<u128>;
+    type Function: RoundFunction;
+
+    fn core(&self) -> &Core<Self::Function>;
+
+    fn core_mut(&mut self) -> &mut Core<Self::Function>;
+
+    fn round_keys(&self) -> &[u64];
+
+    fn round_keys_mut(&mut self) -> &mut [u64];
+
+    /// Narrows an output of the core, which belongs to the domain.
+    fn word(value: u128) -> Self::Word;
+
+    fn permute(&self, input: Self::Word) -> Self::Word {
+        Self::word(self.core().permute(self.round_keys(), input.into()))
+    }
+
+    fn permute_inv(&self, output: Self::Word) -> Self::Word {
+        Self::word(self.core().permute_inv(self.round_keys(), output.into()))
+    }
+
+    fn permute_many(&self, values: &mut [u64])
+    where
+        Self: Network<Word = u64>,
+    {
+        self.core().permute_many(self.round_keys(), values);
+    }
+
+    fn try_permute(&self, input: u64) -> Result<u64, OutOfDomainError>
+    where
+        Self: Network<Word = u64>,
+    {
+        self.core().try_permute(self.round_keys(), input)
+    }
+
+    fn permute_bounded(&self, input: Self::Word, domain_len: Self::Word) -> Self::Word {
+        let keys = self.round_keys();
+        let output = self
+            .core()
+            .permute_bounded(keys, input.into(), domain_len.into());
+        Self::word(output)
+    }
+
+    fn permute_bounded_counted(
+        &self,
+        input: Self::Word,
+        domain_len: Self::Word,
+        counter: &mut CycleWalkCounter,
+    ) -> Self::Word {
+        let keys = self.round_keys();
+        let (output, steps) =
+            self.core()
+                .permute_bounded_steps(keys, input.into(), domain_len.into());
+        counter.record(steps);
+        Self::word(output)
+    }
+
+    fn permute_bounded_constant_time(
+        &self,
+        input: Self::Word,
+        domain_len: Self::Word,
+    ) -> Self::Word {
+        let keys = self.round_keys();
+        let output =
+            self.core()
+                .permute_bounded_constant_time(keys, input.into(), domain_len.into());
+        Self::word(output)
+    }
+
+    fn constant_time_steps(&self, domain_len: Self::Word) -> u64 {
+        self.core().constant_time_steps(domain_len.into())
+    }
+
+    fn permute_bounded_inv(&self, output: Self::Word, domain_len: Self::Word) -> Self::Word {
+        let keys = self.round_keys();
+        let input = self
+            .core()
+            .permute_bounded_inv(keys, output.into(), domain_len.into());
+        Self::word(input)
+    }
+
+    fn verify_bijective(&self, domain_len: u64) -> Result<(), BijectivityError>
+    where
+        Self: Network<Word = u64>,
+    {
+        verify_bijective(
+            domain_len,
+            |input| self.permute_bounded(input, domain_len),
+            |output| self.permute_bounded_inv(output, domain_len),
+        )
+    }
+
+    fn contains(&self, input: Self::Word) -> bool {
+        self.core().contains(input.into())
+    }
+
+    fn bit_len(&self) -> u8 {
+        self.core().bit_len()
+    }
+
+    fn round_function(&self) -> &Self::Function {
+        &self.core().round_function
+    }
+
+    fn set_whitening(&mut self, pre: Self::Word, post: Self::Word) {
+        self.core_mut().set_whitening(pre.into(), post.into());
+    }
+
+    fn tweak(&self, tweak: u64) -> Self
+    where
+        Self: Clone,
+    {
+        let mut network = self.clone();
+        self.core().tweak_keys(network.round_keys_mut(), tweak);
+        network
+    }
+
+    fn set_keys_from_bytes(&mut self, bytes: &[u8]) -> Result<(), KeyMaterialError> {
+        let bit_len = self.core().bit_len();
+        set_keys_from_bytes(self.round_keys_mut(), bit_len, bytes)
+    }
+}
+
+/// Generates the inherent methods that the networks of `u64` or `u128`
+/// integers share, which delegate to [`Network`]. The first argument is the
+/// type of the permuted integers, followed by the name of the network and an
+/// expression that creates a network of 13 bits, for the examples.
+macro_rules! network_methods {
+    (u64, $name:literal, $new:literal) => {
+        network_methods!(@common u64, $name, $new);
+
+        /// Replaces each value of `values` by its output of the permutation.
+        ///
+        /// This is equivalent to calling [`Self::permute`] on each value, but
+        /// faster for many values: the values are permuted in groups whose
+        /// rounds are independent, so the CPU can overlap them.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if a value is outside the domain.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        /// let mut values: Vec<u64> = (0..1 << 13).collect();
+        /// network.permute_many(&mut values);
+        /// assert_eq!(values[347], network.permute(347));
+        /// ```
+        pub fn permute_many(&self, values: &mut [u64]) {
+            $crate::feistel::core::Network::permute_many(self, values);
+        }
+
+        /// Returns the output of the permutation for `input`, or an error if
+        /// `input` is outside the domain.
+        pub fn try_permute(&self, input: u64) -> Result<u64, $crate::feistel::OutOfDomainError> {
+            $crate::feistel::core::Network::try_permute(self, input)
+        }
+
+        /// Returns the output of the permutation of `0..domain_len` for `input`,
+        /// like [`Self::permute_bounded`], and records the number of cycle
+        /// walking steps in `counter`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+        /// zero or greater than the domain of the network.
+        pub fn permute_bounded_counted(
+            &self,
+            input: u64,
+            domain_len: u64,
+            counter: &mut $crate::feistel::CycleWalkCounter,
+        ) -> u64 {
+            $crate::feistel::core::Network::permute_bounded_counted(self, input, domain_len, counter)
+        }
+
+        /// Returns the output of the permutation of `0..domain_len` for `input`,
+        /// like [`Self::permute_bounded`], in time independent of the input and
+        /// the output.
+        ///
+        /// The network is always applied [`Self::constant_time_steps`] times, and the first output within the domain is selected without
+        /// branching. This is slower, but doesn't leak the number of cycle
+        /// walking steps. The number of steps only depends on `domain_len` and
+        /// the bit length, and is chosen so that the walk takes longer with
+        /// probability at most `2^-CONSTANT_TIME_BITS` for any domain (see
+        /// [`CONSTANT_TIME_BITS`](crate::feistel::CONSTANT_TIME_BITS)). The
+        /// round function should be constant-time as well.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+        /// zero or greater than the domain of the network.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        /// let output = network.permute_bounded_constant_time(42, 5757);
+        /// assert_eq!(output, network.permute_bounded(42, 5757));
+        /// ```
+        pub fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+            $crate::feistel::core::Network::permute_bounded_constant_time(self, input, domain_len)
+        }
+
+        /// Returns the number of times that
+        /// [`Self::permute_bounded_constant_time`] applies the network to
+        /// permute `0..domain_len`.
+        ///
+        /// Each step lands within the domain with probability `p = domain_len /
+        /// 2^bit_len`, so `ln(2) * CONSTANT_TIME_BITS / p` steps make a longer
+        /// walk as likely as guessing a
+        /// [`CONSTANT_TIME_BITS`](crate::feistel::CONSTANT_TIME_BITS)-bit key.
+        /// This is 45 steps for domains of half the network, and grows
+        /// inversely with `p`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `domain_len` is zero.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        /// assert_eq!(network.constant_time_steps(1 << 13), 23);
+        /// assert_eq!(network.constant_time_steps(5757), 45);
+        /// ```
+        pub fn constant_time_steps(&self, domain_len: u64) -> u64 {
+            $crate::feistel::core::Network::constant_time_steps(self, domain_len)
+        }
+
+        /// Checks that [`Self::permute_bounded`] is a bijection of `0..domain_len`,
+        /// e.g. to test a custom [`RoundFunction`](crate::feistel::RoundFunction).
+        ///
+        /// Domains of up to
+        /// [`EXHAUSTIVE_VERIFY_LEN`](crate::feistel::EXHAUSTIVE_VERIFY_LEN)
+        /// integers are checked exhaustively, which finds the first input whose
+        /// output collides with the output of an earlier input. Larger domains
+        /// are checked on a sample of inputs spread over the domain, whose
+        /// outputs must be mapped back to them by [`Self::permute_bounded_inv`].
+        ///
+        /// # Panics
+        ///
+        /// Panics if `domain_len` is zero or greater than the domain of the
+        /// network.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        /// assert_eq!(network.verify_bijective(5757), Ok(()));
+        /// ```
+        pub fn verify_bijective(
+            &self,
+            domain_len: u64,
+        ) -> Result<(), $crate::feistel::BijectivityError> {
+            $crate::feistel::core::Network::verify_bijective(self, domain_len)
+        }
+    };
+    (u128, $name:literal, $new:literal) => {
+        network_methods!(@common u128, $name, $new);
+    };
+    (@common $word:ty, $name:literal, $new:literal) => {
+        /// Returns the output of the permutation for `input`.
+        ///
+        /// The input must belong to the domain (see [`Self::contains`]),
+        /// otherwise the output is unspecified.
+        ///
+        /// # Panics
+        ///
+        /// Panics in debug builds if `input` is outside the domain.
+        pub fn permute(&self, input: $word) -> $word {
+            $crate::feistel::core::Network::permute(self, input)
+        }
+
+        /// Returns the input that [`Self::permute`] maps to `output`.
+        ///
+        /// The network is inverted by applying the rounds in reverse order,
+        /// which is as fast as permuting. Cycle walking is inverted by walking
+        /// with the inverse permutation instead, which finds the position of a
+        /// value within the permuted domain (e.g. the day on which a word is
+        /// picked).
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        #[doc = concat!(
+            "let walk = |mut value: ", stringify!($word), ", permute: &dyn Fn(",
+            stringify!($word), ") -> ", stringify!($word), "| loop {"
+        )]
+        ///     value = permute(value);
+        ///     if value < 5757 {
+        ///         return value;
+        ///     }
+        /// };
+        /// for value in 0..5757 {
+        ///     let output = walk(value, &|v| network.permute(v));
+        ///     assert_eq!(walk(output, &|v| network.permute_inv(v)), value);
+        /// }
+        /// ```
+        pub fn permute_inv(&self, output: $word) -> $word {
+            $crate::feistel::core::Network::permute_inv(self, output)
+        }
+
+        /// Returns the output of the permutation of `0..domain_len` for `input`,
+        /// which is found by cycle walking: the network is re-applied until the
+        /// output falls within `0..domain_len`.
+        ///
+        /// The average number of steps over the domain is `2^bit_len /
+        /// domain_len`. See [`CycleWalkStats`](crate::feistel::CycleWalkStats)
+        /// for the distribution.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+        /// zero or greater than the domain of the network.
+        pub fn permute_bounded(&self, input: $word, domain_len: $word) -> $word {
+            $crate::feistel::core::Network::permute_bounded(self, input, domain_len)
+        }
+
+        /// Returns the input that [`Self::permute_bounded`] maps to `output`.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `output` is not less than `domain_len`, or `domain_len` is
+        /// zero or greater than the domain of the network.
+        pub fn permute_bounded_inv(&self, output: $word, domain_len: $word) -> $word {
+            $crate::feistel::core::Network::permute_bounded_inv(self, output, domain_len)
+        }
+
+        /// Returns whether `input` belongs to the permuted domain.
+        pub fn contains(&self, input: $word) -> bool {
+            $crate::feistel::core::Network::contains(self, input)
+        }
+
+        /// Returns the number of bits of the permuted integers.
+        pub fn bit_len(&self) -> u8 {
+            $crate::feistel::core::Network::bit_len(self)
+        }
+
+        /// Returns the round function of the network.
+        pub fn round_function(&self) -> &F {
+            $crate::feistel::core::Network::round_function(self)
+        }
+
+        /// Sets the whitening keys, which are XORed with the input before the
+        /// first round, and with the output after the last round. The keys are
+        /// truncated to the domain.
+        ///
+        /// Whitening hides the inputs and outputs of the rounds, which makes
+        /// attacks on the round function of small domains harder. Both keys are
+        /// initially zero, which disables whitening.
+        pub fn set_whitening(&mut self, pre: $word, post: $word) {
+            $crate::feistel::core::Network::set_whitening(self, pre, post);
+        }
+
+        /// Returns the network for the given tweak, a public input (such as the
+        /// window of a day) that selects one of many independent-looking
+        /// permutations of the same key.
+        ///
+        /// Every round key is replaced by the output of the round function for
+        /// the tweak, keyed by the round key. This mixes the tweak into every
+        /// round for one call of the round function per round, which is much
+        /// cheaper than deriving new round keys from a secret for every window.
+        /// Tweaked networks differ from the untweaked network for every tweak.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let network = ", $new, ";")]
+        /// let window = 54;
+        /// let tweaked = network.tweak(window);
+        /// let output = tweaked.permute_bounded(42, 5757);
+        /// assert_eq!(network.tweak(window).permute_bounded_inv(output, 5757), 42);
+        /// assert_ne!(network.tweak(window + 1).permute_bounded(42, 5757), output);
+        /// ```
+        pub fn tweak(&self, tweak: u64) -> Self
+        where
+            F: Clone,
+        {
+            $crate::feistel::core::Network::tweak(self, tweak)
+        }
+
+        /// Sets the round keys from secret key material of any length, e.g. the
+        /// output of a key derivation function.
+        ///
+        /// Key material of exactly 8 bytes per round is split into the keys, as
+        /// little-endian integers. Other key material is expanded into the keys
+        /// like [`Self::from_master_key`] does.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the key material has fewer than
+        /// [`MIN_KEY_MATERIAL_LEN`](crate::feistel::MIN_KEY_MATERIAL_LEN)
+        /// bytes, or too many rounds are expanded.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        #[doc = concat!("use wordle_generator::feistel::", $name, ";")]
+        ///
+        #[doc = concat!("let mut network = ", $new, ";")]
+        /// network.set_keys_from_bytes(&[0x42; 32]).unwrap();
+        /// assert!(network.set_keys_from_bytes(b"short").is_err());
+        /// ```
+        pub fn set_keys_from_bytes(
+            &mut self,
+            bytes: &[u8],
+        ) -> Result<(), $crate::feistel::KeyMaterialError> {
+            $crate::feistel::core::Network::set_keys_from_bytes(self, bytes)
+        }
+    };
+}
+
+pub(super) use network_methods;
+
+/// Applies `step` to `input` until the value falls within `0..domain_len`,
+/// and returns the value and the number of steps.
+pub(super) fn cycle_walk<T: Copy + PartialOrd>(
+    input: T,
+    domain_len: T,
+    step: impl Fn(T) -> T,
+) -> (T, u64) {
+    let mut value = step(input);
+    let mut steps = 1;
+    while value >= domain_len {
+        value = step(value);
+        steps += 1;
+    }
+    (value, steps)
+}
+
+/// Returns a mask of the lowest `bits` bits, for `bits < 128`.
+pub(super) const fn mask(bits: u8) -> u128 {
+    (1u128 << bits) - 1
+}
+
+/// Returns a mask of the lowest `bits` bits, for `bits < 32`.
+const fn mask_narrow(bits: u8) -> u32 {
+    (1u32 << bits) - 1
+}
+
+#[cfg(feature = "serde")]
+mod schema {
+    use super::Core;
+    use crate::feistel::{DynFeistelNetwork, FeistelNetwork, FeistelNetwork128, RoundFunction};
+    use crate::seed::wipe_flat;
+    use alloc::format;
+    use alloc::vec::Vec;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use smallvec::SmallVec;
+
+    /// The serialized form of a Feistel network.
+    ///
+    /// The keys are borrowed when serializing, and owned when deserializing.
+    #[derive(Serialize, Deserialize)]
+    struct Schema<K, F> {
+        bit_len: u8,
+        keys: K,
+        whitening: [u128; 2],
+        round_function: F,
+    }
+
+    impl<F> Core<F> {
+        fn serialize<S: Serializer>(&self, keys: &[u64], serializer: S) -> Result<S::Ok, S::Error>
+        where
+            F: Serialize,
+        {
+            Schema {
+                bit_len: self.bit_len(),
+                keys,
+                whitening: self.whitening,
+                round_function: &self.round_function,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: RoundFunction + Deserialize<'de>> Core<F> {
+        /// Deserializes the round keys and the core of a network that permutes
+        /// integers of up to `max` bits.
+        fn deserialize<D: Deserializer<'de>>(
+            deserializer: D,
+            max: u32,
+        ) -> Result<(Vec<u64>, Self), D::Error> {
+            let schema = Schema::<Vec<u64>, F>::deserialize(deserializer)?;
+            if schema.bit_len == 0 || schema.bit_len as u32 > max {
+                return Err(D::Error::custom(format!(
+                    "bit_len (is {}) should be in 1..={}",
+                    schema.bit_len, max
+                )));
+            }
+            let mut core = Core::new(schema.bit_len, max, schema.round_function);
+            if !schema.whitening.iter().all(|&key| core.contains(key)) {
+                return Err(D::Error::custom(format!(
+                    "whitening keys should be < 2^{}",
+                    schema.bit_len
+                )));
+            }
+            core.whitening = schema.whitening;
+            Ok((schema.keys, core))
+        }
+    }
+
+    fn fixed_keys<E: Error, const ROUNDS: usize>(mut keys: Vec<u64>) -> Result<[u64; ROUNDS], E> {
+        let fixed = match keys.len() == ROUNDS {
+            true => Ok(core::array::from_fn(|i| keys[i])),
+            false => Err(E::invalid_length(
+                keys.len(),
+                &format!("{} round keys", ROUNDS).as_str(),
+            )),
+        };
+        wipe_keys(&mut keys);
+        fixed
+    }
+
+    /// Wipes the deserialized round keys once they are copied into a network.
+    fn wipe_keys(keys: &mut [u64]) {
+        // SAFETY: the keys are integers, and aren't used again
+        keys.iter_mut().for_each(|key| unsafe { wipe_flat(key) });
+    }
+
+    impl<const ROUNDS: usize, F: Serialize> Serialize for FeistelNetwork<ROUNDS, F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.core.serialize(&self.keys, serializer)
+        }
+    }
+
+    impl<'de, const ROUNDS: usize, F: RoundFunction + Deserialize<'de>> Deserialize<'de>
+        for FeistelNetwork<ROUNDS, F>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (keys, core) = Core::deserialize(deserializer, u64::BITS)?;
+            let keys = fixed_keys::<D::Error, ROUNDS>(keys)?;
+            Ok(Self { keys, core })
+        }
+    }
+
+    impl<F: Serialize> Serialize for DynFeistelNetwork<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.core.serialize(&self.keys, serializer)
+        }
+    }
+
+    impl<'de, F: RoundFunction + Deserialize<'de>> Deserialize<'de> for DynFeistelNetwork<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (mut keys, core) = Core::deserialize(deserializer, u64::BITS)?;
+            let network = Self {
+                keys: SmallVec::from_slice(&keys),
+                core,
+            };
+            wipe_keys(&mut keys);
+            Ok(network)
+        }
+    }
+
+    impl<const ROUNDS: usize, F: Serialize> Serialize for FeistelNetwork128<ROUNDS, F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            self.core.serialize(&self.keys, serializer)
+        }
+    }
+
+    impl<'de, const ROUNDS: usize, F: RoundFunction + Deserialize<'de>> Deserialize<'de>
+        for FeistelNetwork128<ROUNDS, F>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let (keys, core) = Core::deserialize(deserializer, u128::BITS)?;
+            let keys = fixed_keys::<D::Error, ROUNDS>(keys)?;
+            Ok(Self { keys, core })
+        }
+    }
+}
@@ -0,0 +1,645 @@
+use super::core::{network_methods, Core, Network};
+#[cfg(feature = "std")]
+use super::fill_keys;
+use super::{
+    expand_master_key, FeistelNetwork, KeyMaterialError, RoundFunction, WyHashRound,
+    MAX_MASTER_KEY_ROUNDS,
+};
+use crate::{Permutation, DEFAULT_ROUNDS};
+use core::error::Error;
+use core::fmt;
+#[cfg(feature = "std")]
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use smallvec::SmallVec;
+
+/// A Feistel network whose number of rounds is chosen at runtime.
+///
+/// This behaves like a [`FeistelNetwork`] with the same number of rounds, and
+/// permutes integers the same way. Up to [`DEFAULT_ROUNDS`] round keys are
+/// stored inline, and more are allocated on the heap.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::{DynFeistelNetwork, FeistelNetwork};
+///
+/// let keys = [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8];
+/// let network = DynFeistelNetwork::with_keys(10, &keys);
+/// assert_eq!(network.rounds(), 12);
+/// let fixed = FeistelNetwork::with_keys(10, keys);
+/// assert_eq!(network.permute(347), fixed.permute(347));
+/// ```
+#[derive(Clone)]
+pub struct DynFeistelNetwork<F = WyHashRound> {
+    pub(super) keys: SmallVec<[u64; DEFAULT_ROUNDS]>,
+    pub(super) core: Core<F>,
+}
+
+impl DynFeistelNetwork {
+    /// Returns a builder that validates the parameters of a network, instead
+    /// of panicking.
+    pub fn builder<'k>() -> FeistelNetworkBuilder<'k> {
+        FeistelNetworkBuilder::new()
+    }
+
+    /// Creates a Feistel network to permute a domain of size at least
+    /// `domain_len`, with the number of rounds chosen by the given policy for
+    /// its bit length.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`DynFeistelNetwork::from_master_key`], or initialize the keys by mutating
+    /// `DynFeistelNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::feistel::{DynFeistelNetwork, RoundPolicy};
+    ///
+    /// let network = DynFeistelNetwork::for_domain(5757, RoundPolicy::V1);
+    /// assert_eq!(network.bit_len(), 13);
+    /// assert_eq!(network.rounds(), 16);
+    /// ```
+    pub fn for_domain(domain_len: usize, policy: RoundPolicy) -> Self {
+        let bit_len = FeistelNetwork::<0>::bit_len_for(domain_len);
+        Self::new(bit_len, policy.rounds(bit_len))
+    }
+
+    /// Creates a Feistel network with the given number of rounds to permute
+    /// a domain of size `2^bit_len`.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`DynFeistelNetwork::from_master_key`], or initialize the keys by mutating
+    /// `DynFeistelNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn new(bit_len: u8, rounds: usize) -> Self {
+        Self::zeroed(bit_len, rounds, WyHashRound::default())
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// with one round per key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn with_keys(bit_len: u8, keys: &[u64]) -> Self {
+        Self::with_round_function(bit_len, keys, WyHashRound::default())
+    }
+
+    /// Creates a Feistel network with the given number of rounds to permute
+    /// a domain of size `2^bit_len`, whose round keys are derived from a
+    /// secret master key. See [`FeistelNetwork::from_master_key`].
+    ///
+    /// This has the same round keys as a [`FeistelNetwork`] with the same
+    /// number of rounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`, or if
+    /// `rounds` is greater than [`MAX_MASTER_KEY_ROUNDS`].
+    pub fn from_master_key(bit_len: u8, rounds: usize, master_key: &[u8]) -> Self {
+        let mut network = Self::new(bit_len, rounds);
+        expand_master_key(master_key, bit_len, &mut network.keys);
+        network
+    }
+
+    /// Creates a Feistel network with the given number of rounds to permute
+    /// a domain of size `2^bit_len`, whose round keys are generated using the
+    /// operating system's CSPRNG. See [`FeistelNetwork::random`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    #[cfg(feature = "std")]
+    pub fn random(bit_len: u8, rounds: usize) -> Self {
+        Self::from_rng(bit_len, rounds, &mut OsRng)
+    }
+
+    /// Creates a Feistel network with the given number of rounds to permute
+    /// a domain of size `2^bit_len`, whose round keys are generated by the
+    /// given cryptographically secure random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    #[cfg(feature = "std")]
+    pub fn from_rng<R: RngCore + CryptoRng + ?Sized>(
+        bit_len: u8,
+        rounds: usize,
+        rng: &mut R,
+    ) -> Self {
+        let mut network = Self::new(bit_len, rounds);
+        fill_keys(rng, &mut network.keys);
+        network
+    }
+}
+
+impl<F: RoundFunction> DynFeistelNetwork<F> {
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// with one round per key, using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn with_round_function(bit_len: u8, keys: &[u64], round_function: F) -> Self {
+        Self {
+            keys: SmallVec::from_slice(keys),
+            core: Core::new(bit_len, u64::BITS, round_function),
+        }
+    }
+
+    /// Creates a Feistel network whose round keys are set to zero.
+    pub(crate) fn zeroed(bit_len: u8, rounds: usize, round_function: F) -> Self {
+        Self {
+            keys: SmallVec::from_elem(0, rounds),
+            core: Core::new(bit_len, u64::BITS, round_function),
+        }
+    }
+
+    /// Returns the number of rounds.
+    pub fn rounds(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns a mutable reference to the round keys used by the Feistel
+    /// network, one per round.
+    ///
+    /// The round keys must be secret, independent and uniformly random.
+    /// Prefer [`DynFeistelNetwork::from_master_key`].
+    pub fn keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    /// Returns the round keys, e.g. to write test vectors.
+    pub(crate) fn keys(&self) -> &[u64] {
+        &self.keys
+    }
+
+    /// Returns the whitening keys, which are zero unless set.
+    pub(crate) fn whitening(&self) -> [u64; 2] {
+        self.core.whitening.map(|key| key as u64)
+    }
+
+    network_methods!(
+        u64,
+        "DynFeistelNetwork",
+        "DynFeistelNetwork::from_master_key(13, 8, b\"a secret master key\")"
+    );
+}
+
+impl<F: RoundFunction> Network for DynFeistelNetwork<F> {
+    type Word = u64;
+    type Function = F;
+
+    fn core(&self) -> &Core<F> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<F> {
+        &mut self.core
+    }
+
+    fn round_keys(&self) -> &[u64] {
+        &self.keys
+    }
+
+    fn round_keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    fn word(value: u128) -> u64 {
+        value as u64
+    }
+}
+
+impl<F: RoundFunction> Permutation for DynFeistelNetwork<F> {
+    fn permute(&self, input: u64) -> u64 {
+        DynFeistelNetwork::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        DynFeistelNetwork::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        1 << self.bit_len()
+    }
+
+    fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+        DynFeistelNetwork::permute_bounded_constant_time(self, input, domain_len)
+    }
+}
+
+impl<F> fmt::Debug for DynFeistelNetwork<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynFeistelNetwork")
+            .field("bit_len", &self.core.bit_len())
+            .field("rounds", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A policy that chooses the number of rounds of a Feistel network from the
+/// number of bits it permutes.
+///
+/// Luby and Rackoff showed that 4 rounds suffice for large blocks, and 8
+/// rounds leave a wide margin. Small blocks (e.g. 12 bits for a few thousand
+/// words) need more rounds to resist attacks that enumerate the whole domain,
+/// so policies use more rounds for smaller blocks.
+///
+/// Every policy chooses the same number of rounds forever, so pinning a policy
+/// keeps permutations stable. Changes are introduced as new policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum RoundPolicy {
+    /// The original policy:
+    ///
+    /// | Bit length | Rounds |
+    /// |------------|--------|
+    /// | 1 to 7     | 24     |
+    /// | 8 to 15    | 16     |
+    /// | 16 to 31   | 12     |
+    /// | 32 to 128  | 8      |
+    #[default]
+    V1,
+}
+
+impl RoundPolicy {
+    /// Returns the number of rounds for a network that permutes `bit_len`-bit
+    /// integers.
+    pub fn rounds(self, bit_len: u8) -> usize {
+        match self {
+            RoundPolicy::V1 => match bit_len {
+                0..=7 => 24,
+                8..=15 => 16,
+                16..=31 => 12,
+                _ => 8,
+            },
+        }
+    }
+}
+
+/// A builder of a [`DynFeistelNetwork`], which validates the parameters and
+/// returns an error instead of panicking.
+///
+/// The size of the domain and a source of round keys must be set. The number
+/// of rounds defaults to the number of given keys, or to the number chosen by
+/// [`RoundPolicy::V1`] for the bit length.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::{DynFeistelNetwork, FeistelBuildError, SipHashRound};
+///
+/// let network = DynFeistelNetwork::builder()
+///     .domain_len(5757)
+///     .round_function(SipHashRound::new(42))
+///     .master_key(b"a secret master key")
+///     .whitening(0x1234, 0x5678)
+///     .build()
+///     .unwrap();
+/// assert_eq!(network.bit_len(), 13);
+/// assert_eq!(network.rounds(), 16);
+///
+/// let err = DynFeistelNetwork::builder().bit_len(13).build().unwrap_err();
+/// assert_eq!(err, FeistelBuildError::MissingKeys);
+/// ```
+#[derive(Clone)]
+pub struct FeistelNetworkBuilder<'k, F = WyHashRound> {
+    size: Option<Size>,
+    rounds: Option<usize>,
+    policy: RoundPolicy,
+    round_function: F,
+    keys: Option<KeySource<'k>>,
+    whitening: Option<(u64, u64)>,
+}
+
+#[derive(Clone, Copy)]
+enum Size {
+    BitLen(u8),
+    DomainLen(u64),
+}
+
+#[derive(Clone, Copy)]
+enum KeySource<'k> {
+    Keys(&'k [u64]),
+    MasterKey(&'k [u8]),
+    Bytes(&'k [u8]),
+}
+
+impl<'k> FeistelNetworkBuilder<'k> {
+    /// Creates a builder with the default round function.
+    pub fn new() -> Self {
+        Self {
+            size: None,
+            rounds: None,
+            policy: RoundPolicy::default(),
+            round_function: WyHashRound::default(),
+            keys: None,
+            whitening: None,
+        }
+    }
+}
+
+impl Default for FeistelNetworkBuilder<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'k, F: RoundFunction> FeistelNetworkBuilder<'k, F> {
+    /// Sets the number of bits of the permuted integers.
+    pub fn bit_len(mut self, bit_len: u8) -> Self {
+        self.size = Some(Size::BitLen(bit_len));
+        self
+    }
+
+    /// Sets the bit length to that of the smallest network that permutes a
+    /// domain of size at least `domain_len`.
+    pub fn domain_len(mut self, domain_len: u64) -> Self {
+        self.size = Some(Size::DomainLen(domain_len));
+        self
+    }
+
+    /// Sets the number of rounds.
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = Some(rounds);
+        self
+    }
+
+    /// Sets the policy that chooses the number of rounds, unless they are set
+    /// explicitly or by the number of keys.
+    pub fn round_policy(mut self, policy: RoundPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Sets the round function.
+    pub fn round_function<G: RoundFunction>(
+        self,
+        round_function: G,
+    ) -> FeistelNetworkBuilder<'k, G> {
+        FeistelNetworkBuilder {
+            size: self.size,
+            rounds: self.rounds,
+            policy: self.policy,
+            round_function,
+            keys: self.keys,
+            whitening: self.whitening,
+        }
+    }
+
+    /// Sets the round keys, one per round.
+    pub fn keys(mut self, keys: &'k [u64]) -> Self {
+        self.keys = Some(KeySource::Keys(keys));
+        self
+    }
+
+    /// Derives the round keys from a secret master key.
+    /// See [`FeistelNetwork::from_master_key`].
+    pub fn master_key(mut self, master_key: &'k [u8]) -> Self {
+        self.keys = Some(KeySource::MasterKey(master_key));
+        self
+    }
+
+    /// Sets the round keys from secret key material.
+    /// See [`FeistelNetwork::set_keys_from_bytes`].
+    pub fn key_bytes(mut self, bytes: &'k [u8]) -> Self {
+        self.keys = Some(KeySource::Bytes(bytes));
+        self
+    }
+
+    /// Sets the whitening keys. See [`FeistelNetwork::set_whitening`].
+    pub fn whitening(mut self, pre: u64, post: u64) -> Self {
+        self.whitening = Some((pre, post));
+        self
+    }
+
+    /// Builds the network, or returns an error if the parameters are invalid.
+    pub fn build(self) -> Result<DynFeistelNetwork<F>, FeistelBuildError> {
+        let bit_len = match self.size {
+            None => return Err(FeistelBuildError::MissingSize),
+            Some(Size::DomainLen(0)) => return Err(FeistelBuildError::EmptyDomain),
+            Some(Size::DomainLen(domain_len)) => {
+                // log2(domain_len) + 1, which is at most 64
+                (u64::BITS - domain_len.leading_zeros()) as u8
+            }
+            Some(Size::BitLen(bit_len)) if bit_len == 0 || bit_len as u32 > u64::BITS => {
+                return Err(FeistelBuildError::InvalidBitLen(bit_len))
+            }
+            Some(Size::BitLen(bit_len)) => bit_len,
+        };
+        let keys = self.keys.ok_or(FeistelBuildError::MissingKeys)?;
+        let rounds = match (self.rounds, keys) {
+            (Some(0), _) => return Err(FeistelBuildError::ZeroRounds),
+            (Some(rounds), KeySource::Keys(keys)) if keys.len() != rounds => {
+                return Err(FeistelBuildError::KeyCountMismatch {
+                    expected: rounds,
+                    found: keys.len(),
+                })
+            }
+            (Some(rounds), _) => rounds,
+            (None, KeySource::Keys([])) => return Err(FeistelBuildError::ZeroRounds),
+            (None, KeySource::Keys(keys)) => keys.len(),
+            (None, _) => self.policy.rounds(bit_len),
+        };
+
+        let mut network = DynFeistelNetwork::zeroed(bit_len, rounds, self.round_function);
+        match keys {
+            KeySource::Keys(keys) => network.keys.copy_from_slice(keys),
+            KeySource::MasterKey(master_key) => {
+                if rounds > MAX_MASTER_KEY_ROUNDS {
+                    let err = KeyMaterialError::TooManyRounds(rounds);
+                    return Err(FeistelBuildError::KeyMaterial(err));
+                }
+                expand_master_key(master_key, bit_len, &mut network.keys);
+            }
+            KeySource::Bytes(bytes) => network
+                .set_keys_from_bytes(bytes)
+                .map_err(FeistelBuildError::KeyMaterial)?,
+        }
+        if let Some((pre, post)) = self.whitening {
+            network.set_whitening(pre, post);
+        }
+        Ok(network)
+    }
+}
+
+impl<F> fmt::Debug for FeistelNetworkBuilder<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the keys in logs
+        f.debug_struct("FeistelNetworkBuilder")
+            .field("rounds", &self.rounds)
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The error returned by [`FeistelNetworkBuilder::build`] for invalid
+/// parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FeistelBuildError {
+    /// Neither the bit length nor the domain size was set.
+    MissingSize,
+    /// The bit length is zero or greater than `u64::BITS`.
+    InvalidBitLen(u8),
+    /// The domain size is zero.
+    EmptyDomain,
+    /// The number of rounds is zero.
+    ZeroRounds,
+    /// No source of round keys was set.
+    MissingKeys,
+    /// The number of round keys differs from the number of rounds.
+    KeyCountMismatch {
+        /// The number of rounds.
+        expected: usize,
+        /// The number of round keys.
+        found: usize,
+    },
+    /// The key material can't initialize the round keys.
+    KeyMaterial(KeyMaterialError),
+}
+
+impl fmt::Display for FeistelBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeistelBuildError::MissingSize => {
+                f.write_str("a bit length or domain size must be set")
+            }
+            FeistelBuildError::InvalidBitLen(bit_len) => {
+                write!(f, "bit length {} should be between 1 and 64", bit_len)
+            }
+            FeistelBuildError::EmptyDomain => f.write_str("domain cannot be empty"),
+            FeistelBuildError::ZeroRounds => f.write_str("number of rounds should be positive"),
+            FeistelBuildError::MissingKeys => f.write_str("a source of round keys must be set"),
+            FeistelBuildError::KeyCountMismatch { expected, found } => {
+                write!(f, "expected {} round keys, but found {}", expected, found)
+            }
+            FeistelBuildError::KeyMaterial(err) => write!(f, "invalid key material: {}", err),
+        }
+    }
+}
+
+impl Error for FeistelBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            FeistelBuildError::KeyMaterial(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> zeroize::Zeroize for DynFeistelNetwork<F> {
+    fn zeroize(&mut self) {
+        self.keys.as_mut_slice().zeroize();
+        self.core.whitening.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> Drop for DynFeistelNetwork<F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> zeroize::ZeroizeOnDrop for DynFeistelNetwork<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DynFeistelNetwork, FeistelBuildError, FeistelNetworkBuilder, RoundPolicy};
+    use crate::feistel::{FeistelNetwork, KeyMaterialError};
+
+    #[test]
+    fn runtime_rounds() {
+        let keys = [0x6B, 0x12, 0x99, 0x4E, 0x01];
+        let network = DynFeistelNetwork::with_keys(11, &keys);
+        let fixed = FeistelNetwork::with_keys(11, keys);
+        assert_eq!(network.rounds(), 5);
+        for value in 0..1 << 11 {
+            let output = network.permute(value);
+            assert_eq!(output, fixed.permute(value));
+            assert_eq!(network.permute_inv(output), value);
+        }
+
+        // More rounds than are stored inline
+        let keys: Vec<u64> = (0..20).collect();
+        let mut network = DynFeistelNetwork::new(16, 20);
+        network.keys_mut().copy_from_slice(&keys);
+        let fixed = FeistelNetwork::<20>::with_keys(16, keys.try_into().unwrap());
+        assert_eq!(network.permute(12345), fixed.permute(12345));
+    }
+
+    #[test]
+    fn builder() {
+        let keys = [3, 1, 4, 1, 5, 9, 2, 6];
+        let network = DynFeistelNetwork::builder()
+            .bit_len(13)
+            .keys(&keys)
+            .build()
+            .unwrap();
+        assert_eq!(
+            network.permute(42),
+            FeistelNetwork::with_keys(13, keys).permute(42)
+        );
+
+        let network = DynFeistelNetwork::builder()
+            .domain_len(5757)
+            .round_policy(RoundPolicy::V1)
+            .master_key(b"master key")
+            .build()
+            .unwrap();
+        let master = DynFeistelNetwork::from_master_key(13, 16, b"master key");
+        assert_eq!(network.permute(42), master.permute(42));
+
+        let build = |builder: FeistelNetworkBuilder<'_>| builder.build().unwrap_err();
+        let builder = DynFeistelNetwork::builder;
+        assert_eq!(build(builder()), FeistelBuildError::MissingSize);
+        assert_eq!(
+            build(builder().bit_len(65)),
+            FeistelBuildError::InvalidBitLen(65)
+        );
+        assert_eq!(
+            build(builder().domain_len(0)),
+            FeistelBuildError::EmptyDomain
+        );
+        assert_eq!(build(builder().bit_len(8)), FeistelBuildError::MissingKeys);
+        assert_eq!(
+            build(builder().bit_len(8).keys(&[])),
+            FeistelBuildError::ZeroRounds
+        );
+        assert_eq!(
+            build(builder().bit_len(8).rounds(4).keys(&keys)),
+            FeistelBuildError::KeyCountMismatch {
+                expected: 4,
+                found: 8
+            }
+        );
+        assert_eq!(
+            build(builder().bit_len(8).key_bytes(b"short")),
+            FeistelBuildError::KeyMaterial(KeyMaterialError::TooShort(5))
+        );
+    }
+
+    #[test]
+    fn round_policy() {
+        let policy = RoundPolicy::V1;
+        assert_eq!(policy.rounds(1), 24);
+        assert_eq!(policy.rounds(12), 16);
+        assert_eq!(policy.rounds(16), 12);
+        assert_eq!(policy.rounds(64), 8);
+        assert_eq!(policy.rounds(128), 8);
+        let network = DynFeistelNetwork::for_domain(347, policy);
+        assert_eq!((network.bit_len(), network.rounds()), (9, 16));
+    }
+}
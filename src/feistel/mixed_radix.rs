@@ -0,0 +1,361 @@
+use super::core::cycle_walk;
+use super::{RoundFunction, WyHashRound};
+use crate::{Permutation, DEFAULT_ROUNDS};
+use alloc::vec;
+use core::fmt;
+use smallvec::SmallVec;
+
+/// A numeral-system Feistel network that permutes `0..radices[0] * radices[1]`,
+/// so the domain can be any product of two integers instead of a power of two.
+///
+/// Each integer `x` is split into the digits `x / radices[1]` and
+/// `x % radices[1]`. Every round adds the round function of one digit to the
+/// other modulo its radix, and the digits swap places, so consecutive rounds
+/// alternate between the radices.
+///
+/// [`MixedRadixNetwork::for_domain`] factors the domain size into radices as
+/// close to its square root as possible, so domains with such factors (e.g.
+/// `5757 = 57 * 101`) are permuted exactly, without cycle walking. Other
+/// domains are permuted by cycle walking over a slightly larger domain, which
+/// rarely takes more than one step.
+///
+/// Unbalanced radices weaken the network, since the round function of the
+/// smaller digit has fewer distinct outputs. Use at least 8 rounds.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::MixedRadixNetwork;
+///
+/// let mut network = MixedRadixNetwork::for_domain(5757, 12);
+/// assert_eq!(network.radices(), [57, 101]);
+/// assert_eq!(network.domain_len(), 5757);
+/// for (i, key) in network.keys_mut().iter_mut().enumerate() {
+///     *key = 0x9E37_79B9_7F4A_7C15_u64.wrapping_mul(i as u64 + 1);
+/// }
+/// let output = network.permute(347);
+/// assert!(output < 5757);
+/// assert_eq!(network.permute_inv(output), 347);
+/// ```
+#[derive(Clone)]
+pub struct MixedRadixNetwork<F = WyHashRound> {
+    radices: [u64; 2],
+    keys: SmallVec<[u64; DEFAULT_ROUNDS]>,
+    round_function: F,
+}
+
+impl MixedRadixNetwork {
+    /// Creates a network with the given number of rounds to permute a domain
+    /// of size at least `domain_len`, and exactly `domain_len` if it has a
+    /// factor close to its square root.
+    ///
+    /// The round keys are initially set to zero. The network should be
+    /// initialized by mutating `MixedRadixNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain_len` is zero, or within `2^32` of `u64::MAX`.
+    pub fn for_domain(domain_len: u64, rounds: usize) -> Self {
+        Self::new(Self::radices_for(domain_len), rounds)
+    }
+
+    /// Creates a network with the given number of rounds to permute the
+    /// domain `0..radices[0] * radices[1]`.
+    ///
+    /// The round keys are initially set to zero. The network should be
+    /// initialized by mutating `MixedRadixNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a radix is zero, or the size of the domain overflows a `u64`.
+    pub fn new(radices: [u64; 2], rounds: usize) -> Self {
+        Self::with_round_function(radices, &vec![0; rounds], WyHashRound::default())
+    }
+
+    /// Creates a network to permute the domain `0..radices[0] * radices[1]`,
+    /// with one round per key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a radix is zero, or the size of the domain overflows a `u64`.
+    pub fn with_keys(radices: [u64; 2], keys: &[u64]) -> Self {
+        Self::with_round_function(radices, keys, WyHashRound::default())
+    }
+
+    /// Returns the radices of the smallest domain of size at least
+    /// `domain_len` whose radices are close to each other, as chosen by
+    /// [`MixedRadixNetwork::for_domain`].
+    ///
+    /// The radices are divisors of `domain_len` if one of them is at least
+    /// half of its square root (and is found among the `2^16` integers below
+    /// the square root). Otherwise, both are close to the square root, and
+    /// the domain exceeds `domain_len` by less than the square root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `domain_len` is zero, or within `2^32` of `u64::MAX`.
+    pub fn radices_for(domain_len: u64) -> [u64; 2] {
+        assert!(domain_len > 0, "domain cannot be empty");
+        let root = domain_len.isqrt();
+        let lowest = (root / 2).max(root.saturating_sub(1 << 16)).max(1);
+        match (lowest..=root)
+            .rev()
+            .find(|&radix| domain_len.is_multiple_of(radix))
+        {
+            Some(radix) => [radix, domain_len / radix],
+            None => {
+                let radices = [root, domain_len.div_ceil(root)];
+                assert!(
+                    radices[0].checked_mul(radices[1]).is_some(),
+                    "domain_len (is {}) is too large",
+                    domain_len
+                );
+                radices
+            }
+        }
+    }
+}
+
+impl<F: RoundFunction> MixedRadixNetwork<F> {
+    /// Creates a network to permute the domain `0..radices[0] * radices[1]`,
+    /// with one round per key, using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a radix is zero, or the size of the domain overflows a `u64`.
+    pub fn with_round_function(radices: [u64; 2], keys: &[u64], round_function: F) -> Self {
+        assert!(
+            radices.iter().all(|&radix| radix > 0),
+            "radices should be positive"
+        );
+        assert!(
+            radices[0].checked_mul(radices[1]).is_some(),
+            "domain size of radices {:?} overflows",
+            radices
+        );
+        Self {
+            radices,
+            keys: keys.into(),
+            round_function,
+        }
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `input` is outside the domain.
+    pub fn permute(&self, input: u64) -> u64 {
+        debug_assert!(
+            self.contains(input),
+            "input (is {}) should be < {}",
+            input,
+            self.domain_len()
+        );
+        let [mut upper_radix, mut lower_radix] = self.radices;
+        let mut upper = input / lower_radix;
+        let mut lower = input % lower_radix;
+        for &key in &self.keys {
+            let round = self.round(key, lower, upper_radix);
+            // The sum can overflow if the other radix is 1
+            let new_lower =
+                ((u128::from(upper) + u128::from(round)) % u128::from(upper_radix)) as u64;
+            upper = lower;
+            lower = new_lower;
+            (upper_radix, lower_radix) = (lower_radix, upper_radix);
+        }
+        upper * lower_radix + lower
+    }
+
+    /// Returns the input that [`MixedRadixNetwork::permute`] maps to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `output` is outside the domain.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        debug_assert!(
+            self.contains(output),
+            "output (is {}) should be < {}",
+            output,
+            self.domain_len()
+        );
+        // The radices after the last round
+        let [mut upper_radix, mut lower_radix] = match self.keys.len() % 2 {
+            0 => self.radices,
+            _ => [self.radices[1], self.radices[0]],
+        };
+        let mut upper = output / lower_radix;
+        let mut lower = output % lower_radix;
+        for &key in self.keys.iter().rev() {
+            let round = self.round(key, upper, lower_radix);
+            let prev_upper = match lower.checked_sub(round) {
+                Some(difference) => difference,
+                None => lower_radix - (round - lower),
+            };
+            lower = upper;
+            upper = prev_upper;
+            (upper_radix, lower_radix) = (lower_radix, upper_radix);
+        }
+        upper * lower_radix + lower
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// found by cycle walking. This takes a single step if `domain_len` is the
+    /// size of the domain of the network.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the network.
+    pub fn permute_bounded(&self, input: u64, domain_len: u64) -> u64 {
+        self.check_bounded(input, domain_len);
+        cycle_walk(input, domain_len, |value| self.permute(value)).0
+    }
+
+    /// Returns the input that [`MixedRadixNetwork::permute_bounded`] maps to
+    /// `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the network.
+    pub fn permute_bounded_inv(&self, output: u64, domain_len: u64) -> u64 {
+        self.check_bounded(output, domain_len);
+        cycle_walk(output, domain_len, |value| self.permute_inv(value)).0
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        input < self.domain_len()
+    }
+
+    /// Returns the size of the permuted domain.
+    pub fn domain_len(&self) -> u64 {
+        self.radices[0] * self.radices[1]
+    }
+
+    /// Returns the radices of the digits of the permuted integers.
+    pub fn radices(&self) -> [u64; 2] {
+        self.radices
+    }
+
+    /// Returns the number of rounds of the network.
+    pub fn rounds(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the round function of the network.
+    pub fn round_function(&self) -> &F {
+        &self.round_function
+    }
+
+    /// Returns a mutable reference to the round keys used by the network,
+    /// one per round.
+    ///
+    /// The round keys must be secret, independent and uniformly random.
+    pub fn keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    /// Applies the round function to a digit, reduced modulo `radix`.
+    fn round(&self, key: u64, digit: u64, radix: u64) -> u64 {
+        self.round_function.round(key, digit) % radix
+    }
+
+    fn check_bounded(&self, value: u64, domain_len: u64) {
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            domain_len <= self.domain_len(),
+            "domain_len (is {}) should be <= {}",
+            domain_len,
+            self.domain_len()
+        );
+        assert!(
+            value < domain_len,
+            "value (is {}) should be < {}",
+            value,
+            domain_len
+        );
+    }
+}
+
+impl<F: RoundFunction> Permutation for MixedRadixNetwork<F> {
+    fn permute(&self, input: u64) -> u64 {
+        self.permute(input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        self.permute_inv(output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.domain_len().into()
+    }
+}
+
+impl<F> fmt::Debug for MixedRadixNetwork<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MixedRadixNetwork")
+            .field("radices", &self.radices)
+            .field("rounds", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> zeroize::Zeroize for MixedRadixNetwork<F> {
+    fn zeroize(&mut self) {
+        self.keys.as_mut_slice().zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> Drop for MixedRadixNetwork<F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> zeroize::ZeroizeOnDrop for MixedRadixNetwork<F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::MixedRadixNetwork;
+
+    #[test]
+    fn mixed_radix() {
+        assert_eq!(MixedRadixNetwork::radices_for(1), [1, 1]);
+        assert_eq!(MixedRadixNetwork::radices_for(5757), [57, 101]);
+        assert_eq!(MixedRadixNetwork::radices_for(10_000), [100, 100]);
+        // 2309 is prime
+        assert_eq!(MixedRadixNetwork::radices_for(2309), [48, 49]);
+
+        for (radices, rounds) in [([57, 101], 8), ([101, 57], 9), ([1, 7], 3), ([48, 49], 12)] {
+            let keys: Vec<u64> = (1..=rounds)
+                .map(|i| 0x9E37_79B9_7F4A_7C15_u64.wrapping_mul(i))
+                .collect();
+            let network = MixedRadixNetwork::with_keys(radices, &keys);
+            let domain_len = network.domain_len();
+            let mut outputs: Vec<_> = (0..domain_len)
+                .map(|value| network.permute(value))
+                .collect();
+            assert!(outputs
+                .iter()
+                .all(|&output| network.permute_inv(output) < domain_len));
+            assert!(
+                (0..domain_len).all(|value| network.permute_inv(outputs[value as usize]) == value)
+            );
+            outputs.sort();
+            assert!(outputs.into_iter().eq(0..domain_len));
+        }
+
+        let network = MixedRadixNetwork::with_keys([48, 49], &[3, 1, 4, 1, 5, 9, 2, 6]);
+        let mut outputs: Vec<_> = (0..2309)
+            .map(|value| network.permute_bounded(value, 2309))
+            .collect();
+        assert_eq!(network.permute_bounded_inv(outputs[42], 2309), 42);
+        outputs.sort();
+        assert!(outputs.into_iter().eq(0..2309));
+    }
+}
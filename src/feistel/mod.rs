@@ -0,0 +1,1375 @@
+//! Keyed permutations of small integer domains, via Feistel networks.
+//!
+//! A [`FeistelNetwork`] permutes the integers `0..2^bit_len`. Odd bit lengths
+//! split the input into halves of different lengths (e.g. 13 bits as 7 and 6),
+//! so the permuted domain is at most twice the size of the domain, and
+//! [`FeistelNetwork128`] permutes integers of up to 128 bits.
+//! [`DynFeistelNetwork`] chooses the number of rounds at runtime. Combined with
+//! cycle walking (re-applying the permutation until the output falls within
+//! the domain), it permutes any domain `0..n`, which is a form of
+//! format-preserving encryption. The word picker uses this to map each day of
+//! a window to a distinct word.
+//!
+//! [`MixedRadixNetwork`] permutes domains whose size is the product of two
+//! integers, without cycle walking. [`BlockFeistelNetwork`] permutes byte
+//! blocks of any fixed size, e.g. to obfuscate 16-byte identifiers.
+//!
+//! The [`shuffle`](crate::shuffle) module provides alternative ciphers with
+//! better security bounds for tiny domains.
+//!
+//! With the `serde` feature, networks whose round function is serializable
+//! ([`WyHashRound`], [`SipHashRound`] and `Blake3Round`) can be serialized and
+//! deserialized, e.g. to ship a configured permutation to another process
+//! without re-deriving it. The serialized form contains the secret keys.
+//!
+//! # Security
+//!
+//! - The default round function is [WyHash](https://docs.rs/wyhash) keyed by
+//!   the round key, which is fast but not a cryptographically analyzed PRF.
+//!   With it, the permutation should be treated as obfuscation against casual
+//!   inspection, not as a block cipher. [`SipHashRound`] and
+//!   [`HmacSha256Round`] are keyed PRFs with better-studied security, as are
+//!   `Blake3Round` and `AesRound` (behind the `blake3` and `aes` features).
+//!   [`HalfSipHashRound`] is a faster PRF on 32-bit targets, with a smaller key.
+//!   Other round functions can be supplied by implementing [`RoundFunction`].
+//! - Feistel networks over small domains are vulnerable to distinguishing and
+//!   key-recovery attacks given many input/output pairs. Use at least 8
+//!   rounds, and don't reveal more outputs than necessary.
+//! - The round keys must be secret and independent. The `from_master_key`
+//!   constructors derive them from a secret master key via HKDF-SHA512, and
+//!   the `random` constructors generate them using the operating system's
+//!   CSPRNG.
+//!   With the `zeroize` feature, networks wipe their keys when dropped, and
+//!   implement `Zeroize` to wipe them earlier (e.g. when rotating keys).
+//! - Cycle walking leaks the number of steps via timing. See below.
+//!
+//! # Timing
+//!
+//! An attacker that measures the response time of a server (e.g. remotely,
+//! averaging over many requests) may learn about the secret inputs and
+//! outputs of a permutation if the time depends on them:
+//!
+//! - [`WyHashRound`] multiplies 64-bit integers into a 128-bit product, which
+//!   takes a variable time on some 32-bit and low-power CPUs. [`SipHashRound`]
+//!   and [`HalfSipHashRound`] only add, rotate and XOR, and [`HmacSha256Round`] and `Blake3Round` are
+//!   constant-time as well. `AesRound` is constant-time, both with AES
+//!   instructions and with the bitsliced software fallback.
+//! - The number of cycle walking steps of [`FeistelNetwork::permute_bounded`]
+//!   depends on the input. [`FeistelNetwork::permute_bounded_constant_time`]
+//!   always performs [`FeistelNetwork::constant_time_steps`] steps instead,
+//!   which grow with the ratio of the network to the walked domain: about
+//!   `22 * 2^bit_len / domain_len`. Balanced networks, pinned capacities and
+//!   weighted domains may be several times larger than the walked domain,
+//!   which makes constant-time walks proportionally slower.
+//!
+//! Combine both to permute in constant time. With
+//! [`WordleBuilder::constant_time`](crate::WordleBuilder::constant_time), the
+//! word picker walks the cycles of its permutations via
+//! [`Permutation::permute_bounded_constant_time`]. The rest of the picker
+//! is not constant-time (e.g. skipping banned words takes longer), so servers
+//! should still look up the answer of a day once, not per request.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::feistel::FeistelNetwork;
+//!
+//! // Permute 0..347 via cycle walking over a 9-bit network
+//! let network = FeistelNetwork::<8>::from_master_key(
+//!     FeistelNetwork::<8>::bit_len_for(347),
+//!     b"a secret master key",
+//! );
+//! let mut outputs: Vec<_> = (0..347)
+//!     .map(|value| network.permute_bounded(value, 347))
+//!     .collect();
+//! outputs.sort();
+//! assert!(outputs.into_iter().eq(0..347));
+//! ```
+
+mod analysis;
+mod block;
+mod core;
+mod r#dyn;
+mod mixed_radix;
+mod u128;
+
+pub use self::analysis::{
+    analysis, AnalysisReport, BijectivityError, CycleWalkCounter, CycleWalkStats,
+    EXHAUSTIVE_VERIFY_LEN,
+};
+pub use self::block::BlockFeistelNetwork;
+pub use self::core::CONSTANT_TIME_BITS;
+pub use self::mixed_radix::MixedRadixNetwork;
+pub use self::r#dyn::{DynFeistelNetwork, FeistelBuildError, FeistelNetworkBuilder, RoundPolicy};
+pub use self::u128::FeistelNetwork128;
+
+use self::core::{network_methods, Core, Network};
+use crate::seed::wipe_flat;
+use crate::Permutation;
+use ::core::error::Error;
+use ::core::fmt;
+use ::core::hash::Hasher;
+use bytemuck::cast_slice_mut;
+use hkdf::Hkdf;
+use hmac::{Hmac, Mac};
+#[cfg(feature = "std")]
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use sha2::{Sha256, Sha512};
+use siphasher::sip::SipHasher24;
+use wyhash::WyHash;
+
+/// A Feistel network of length `n` provides a random permutation
+/// of the set {0, 1, ..., 2^n - 1}, determined by the round keys.
+///
+/// If `n` is odd, the network is unbalanced: the upper half of the input has
+/// one more bit than the lower half, and the lengths of the halves alternate
+/// between rounds.
+///
+/// The network applies `ROUNDS` rounds, each keyed by one 64-bit round key,
+/// using the round function `F`, which defaults to [`WyHashRound`].
+/// See the [module documentation](self) for security caveats.
+#[derive(Clone)]
+pub struct FeistelNetwork<const ROUNDS: usize, F = WyHashRound> {
+    keys: [u64; ROUNDS],
+    core: Core<F>,
+}
+
+/// The pseudorandom function applied to one half of the input in each round
+/// of a [`FeistelNetwork`].
+///
+/// Implementations must be deterministic: the same key and input always give
+/// the same output. The output is truncated to the length of the other half,
+/// so every bit of it should depend on every bit of the key and the input.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::{FeistelNetwork, RoundFunction};
+///
+/// #[derive(Clone, Copy)]
+/// struct Multiply;
+///
+/// impl RoundFunction for Multiply {
+///     fn round(&self, key: u64, input: u64) -> u64 {
+///         (input ^ key).wrapping_mul(0x9E37_79B9_7F4A_7C15).rotate_left(29)
+///     }
+/// }
+///
+/// let network = FeistelNetwork::with_round_function(8, [1, 2, 3, 4, 5, 6, 7, 8], Multiply);
+/// let mut outputs: Vec<_> = (0..256).map(|value| network.permute(value)).collect();
+/// outputs.sort();
+/// assert!(outputs.into_iter().eq(0..256));
+/// ```
+pub trait RoundFunction {
+    /// Returns the output of the round keyed by `key` for the given input.
+    fn round(&self, key: u64, input: u64) -> u64;
+}
+
+/// The default round function, which hashes the input with
+/// [WyHash](https://docs.rs/wyhash) seeded by the round key.
+///
+/// WyHash is fast, but not a cryptographically analyzed PRF.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WyHashRound {
+    // Whether the input is hashed in native byte order
+    native_endian: bool,
+}
+
+impl WyHashRound {
+    /// The default round function, which hashes the input in little-endian
+    /// byte order.
+    const LITTLE_ENDIAN: Self = Self {
+        native_endian: false,
+    };
+
+    /// Creates a round function that hashes its input in native byte order,
+    /// instead of little-endian, if `native_endian` is set. This makes the
+    /// permutation depend on the host.
+    pub(crate) fn with_native_endian(native_endian: bool) -> Self {
+        Self { native_endian }
+    }
+
+    /// Returns the same output as [`RoundFunction::round`], in constant
+    /// expressions. This ports WyHash for a single 8-byte write.
+    const fn round_const(&self, key: u64, input: u64) -> u64 {
+        const P0: u64 = 0xa076_1d64_78bd_642f;
+        const P1: u64 = 0xe703_7ed1_a0b4_28db;
+        const P5: u64 = 0xeb44_acca_b455_d165;
+        const fn mum(a: u64, b: u64) -> u64 {
+            let product = a as u128 * b as u128;
+            ((product >> 64) ^ product) as u64
+        }
+
+        let bytes = match self.native_endian {
+            true => input.to_ne_bytes(),
+            false => input.to_le_bytes(),
+        };
+        // WyHash reads the two 4-byte words of the input swapped
+        let first = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64;
+        let second = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as u64;
+        let hash = mum(key ^ P0, (first << 32 | second) ^ P1);
+        mum(hash, 8 ^ P5)
+    }
+}
+
+impl RoundFunction for WyHashRound {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        let mut hasher = WyHash::with_seed(key);
+        if self.native_endian {
+            hasher.write_u64(input);
+        } else {
+            hasher.write(&input.to_le_bytes());
+        }
+        hasher.finish()
+    }
+}
+
+/// A round function that hashes the input with SipHash-2-4.
+///
+/// SipHash takes a 128-bit key: the lower half is the round key, and the
+/// upper half is a key shared by every round, which should be secret and
+/// independent of the round keys.
+///
+/// SipHash is a PRF designed for short inputs, and is several times slower
+/// than [`WyHashRound`].
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SipHashRound {
+    key: u64,
+}
+
+impl SipHashRound {
+    /// Creates a round function with the given shared key.
+    pub fn new(key: u64) -> Self {
+        Self { key }
+    }
+}
+
+impl RoundFunction for SipHashRound {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        let mut hasher = SipHasher24::new_with_keys(key, self.key);
+        hasher.write(&input.to_le_bytes());
+        hasher.finish()
+    }
+}
+
+impl fmt::Debug for SipHashRound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SipHashRound").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SipHashRound {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.key.zeroize();
+    }
+}
+
+/// A round function that hashes the input with HalfSipHash-2-4, keyed by
+/// the round key.
+///
+/// HalfSipHash is the 32-bit variant of SipHash: it only adds, rotates and
+/// XORs 32-bit words, so it is several times faster than [`WyHashRound`] and
+/// [`SipHashRound`] on 32-bit targets (e.g. microcontrollers without a 64-bit
+/// multiplier). Inputs of up to 32 bits are hashed as a single word.
+///
+/// The output has 32 bits, which covers the halves of networks of up to 64
+/// bits. Wider halves of a [`FeistelNetwork128`] only get 32 random bits, so
+/// this round function should not be used for networks of more than 64 bits.
+/// With a 64-bit key, HalfSipHash is a weaker PRF than SipHash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalfSipHashRound;
+
+impl RoundFunction for HalfSipHashRound {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        // The length of the message is hashed, so both encodings are distinct
+        let output = match u32::try_from(input) {
+            Ok(input) => half_siphash(key, &[input]),
+            Err(_) => half_siphash(key, &[input as u32, (input >> 32) as u32]),
+        };
+        output.into()
+    }
+}
+
+/// Returns the 32-bit HalfSipHash-2-4 of a message of little-endian words.
+fn half_siphash(key: u64, words: &[u32]) -> u32 {
+    fn sip_round(v: &mut [u32; 4]) {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(5) ^ v[0];
+        v[0] = v[0].rotate_left(16);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(8) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(7) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[2];
+        v[2] = v[2].rotate_left(16);
+    }
+
+    let (k0, k1) = (key as u32, (key >> 32) as u32);
+    let mut v = [k0, k1, 0x6c79_6765 ^ k0, 0x7465_6462 ^ k1];
+    // The last word holds the length of the message in bytes
+    let last = (words.len() as u32 * 4) << 24;
+    for &word in words.iter().chain([&last]) {
+        v[3] ^= word;
+        sip_round(&mut v);
+        sip_round(&mut v);
+        v[0] ^= word;
+    }
+    v[2] ^= 0xff;
+    for _ in 0..4 {
+        sip_round(&mut v);
+    }
+    v[1] ^ v[3]
+}
+
+/// A round function that authenticates the round key and the input with
+/// HMAC-SHA-256.
+///
+/// The HMAC key is shared by every round, and should be secret and
+/// independent of the round keys. HMAC and SHA-256 are NIST-approved, for
+/// deployments that only accept approved primitives.
+#[derive(Clone)]
+pub struct HmacSha256Round {
+    mac: Hmac<Sha256>,
+}
+
+impl HmacSha256Round {
+    /// Creates a round function with the given shared key.
+    pub fn new(key: [u8; 32]) -> Self {
+        Self {
+            mac: Hmac::new_from_slice(&key).expect("HMAC accepts any key length"),
+        }
+    }
+}
+
+impl RoundFunction for HmacSha256Round {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        let mut mac = self.mac.clone();
+        mac.update(&key.to_le_bytes());
+        mac.update(&input.to_le_bytes());
+        let mut tag = mac.finalize().into_bytes();
+        let output = u64::from_le_bytes(tag[..8].try_into().unwrap());
+        // SAFETY: the tag is a byte array, and isn't used again
+        unsafe { wipe_flat(&mut tag) };
+        output
+    }
+}
+
+impl fmt::Debug for HmacSha256Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HmacSha256Round").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for HmacSha256Round {
+    fn drop(&mut self) {
+        // The state of the MAC is derived from the key
+        // SAFETY: the MAC state only holds integer arrays, and is dropped next
+        unsafe { wipe_flat(&mut self.mac) };
+    }
+}
+
+/// A round function that hashes the round key and the input with the keyed
+/// mode of [BLAKE3](https://docs.rs/blake3).
+///
+/// The BLAKE3 key is shared by every round, and should be secret and
+/// independent of the round keys.
+#[cfg(feature = "blake3")]
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Blake3Round {
+    key: [u8; 32],
+}
+
+#[cfg(feature = "blake3")]
+impl Blake3Round {
+    /// Creates a round function with the given shared key.
+    pub fn new(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl RoundFunction for Blake3Round {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        let mut message = [0; 16];
+        message[..8].copy_from_slice(&key.to_le_bytes());
+        message[8..].copy_from_slice(&input.to_le_bytes());
+        let hash = blake3::keyed_hash(&self.key, &message);
+        u64::from_le_bytes(hash.as_bytes()[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "blake3")]
+impl fmt::Debug for Blake3Round {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Blake3Round").finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(feature = "blake3", feature = "zeroize"))]
+impl Drop for Blake3Round {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.key.zeroize();
+    }
+}
+
+/// A round function that encrypts the round key and the input with AES-128.
+///
+/// The AES key is shared by every round, and should be secret and independent
+/// of the round keys. AES instructions are used if the CPU supports them
+/// (detected at runtime on x86 and x86-64), which makes this round function
+/// well suited for generating schedules in bulk.
+#[cfg(feature = "aes")]
+#[derive(Clone)]
+pub struct AesRound {
+    cipher: aes::Aes128,
+}
+
+#[cfg(feature = "aes")]
+impl AesRound {
+    /// Creates a round function with the given shared key.
+    pub fn new(key: [u8; 16]) -> Self {
+        use aes::cipher::KeyInit;
+
+        Self {
+            cipher: aes::Aes128::new(&key.into()),
+        }
+    }
+}
+
+#[cfg(feature = "aes")]
+impl RoundFunction for AesRound {
+    fn round(&self, key: u64, input: u64) -> u64 {
+        use aes::cipher::BlockEncrypt;
+
+        let mut block = aes::Block::default();
+        block[..8].copy_from_slice(&key.to_le_bytes());
+        block[8..].copy_from_slice(&input.to_le_bytes());
+        self.cipher.encrypt_block(&mut block);
+        u64::from_le_bytes(block[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(feature = "aes")]
+impl fmt::Debug for AesRound {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AesRound").finish_non_exhaustive()
+    }
+}
+
+impl<const ROUNDS: usize> FeistelNetwork<ROUNDS> {
+    /// Creates a Feistel network to permute a domain of size at least `domain_len`.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`FeistelNetwork::from_master_key`], or initialize the keys by mutating
+    /// `FeistelNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn for_domain(domain_len: usize) -> Self {
+        Self::new(Self::bit_len_for(domain_len))
+    }
+
+    /// Returns the bit length of the smallest network that permutes a
+    /// domain of size at least `domain_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub const fn bit_len_for(domain_len: usize) -> u8 {
+        assert!(domain_len > 0, "domain cannot be empty");
+        // log2(domain_len) + 1, which is at most 64
+        (usize::BITS - domain_len.leading_zeros()) as u8
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`FeistelNetwork::from_master_key`], or initialize the keys by mutating
+    /// `FeistelNetwork::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub const fn new(bit_len: u8) -> Self {
+        Self::with_keys(bit_len, [0; ROUNDS])
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`.
+    ///
+    /// This is a `const fn`, so a network can be stored in a `static` and used
+    /// in constant expressions. See [`FeistelNetwork::permute_const`]. (With the
+    /// `zeroize` feature, networks wipe their keys when dropped, so they can't
+    /// be stored in a `const`.)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub const fn with_keys(bit_len: u8, keys: [u64; ROUNDS]) -> Self {
+        Self::with_round_function(bit_len, keys, WyHashRound::LITTLE_ENDIAN)
+    }
+
+    /// Returns the output of the permutation for `input`, like
+    /// [`FeistelNetwork::permute`], in constant expressions.
+    ///
+    /// This is slower than [`FeistelNetwork::permute`], so it should only be
+    /// used where a `const fn` is required, e.g. to bake a schedule into the
+    /// binary of an embedded device.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug builds if `input` is outside the domain.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::feistel::FeistelNetwork;
+    ///
+    /// static NETWORK: FeistelNetwork<8> = FeistelNetwork::with_keys(13, [3, 1, 4, 1, 5, 9, 2, 6]);
+    ///
+    /// // The word indices of a window of 365 days, among 5757 words
+    /// static SCHEDULE: [u16; 365] = {
+    ///     let mut schedule = [0; 365];
+    ///     let mut day = 0;
+    ///     while day < schedule.len() {
+    ///         schedule[day] = NETWORK.permute_bounded_const(day as u64, 5757) as u16;
+    ///         day += 1;
+    ///     }
+    ///     schedule
+    /// };
+    ///
+    /// assert_eq!(SCHEDULE[42] as u64, NETWORK.permute_bounded(42, 5757));
+    /// ```
+    pub const fn permute_const(&self, input: u64) -> u64 {
+        self.core.permute_const(&self.keys, input as u128) as u64
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// like [`FeistelNetwork::permute_bounded`], in constant expressions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the network.
+    pub const fn permute_bounded_const(&self, input: u64, domain_len: u64) -> u64 {
+        let (input, domain_len) = (input as u128, domain_len as u128);
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            self.core.contains(domain_len - 1),
+            "domain_len should be <= 2^bit_len"
+        );
+        assert!(input < domain_len, "value should be < domain_len");
+        let mut value = self.core.permute_const(&self.keys, input);
+        while value >= domain_len {
+            value = self.core.permute_const(&self.keys, value);
+        }
+        value as u64
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// whose round keys are derived from a secret master key.
+    ///
+    /// The round keys are expanded via HKDF-SHA512, domain-separated by the
+    /// bit length and the number of rounds, so networks of different shapes
+    /// have unrelated keys. The master key should have at least 128 bits of
+    /// entropy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`, or if
+    /// `ROUNDS` is greater than [`MAX_MASTER_KEY_ROUNDS`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::feistel::FeistelNetwork;
+    ///
+    /// let network = FeistelNetwork::<8>::from_master_key(10, b"a secret master key");
+    /// let other = FeistelNetwork::<8>::from_master_key(10, b"another master key");
+    /// assert_ne!(network.permute(347), other.permute(347));
+    /// ```
+    pub fn from_master_key(bit_len: u8, master_key: &[u8]) -> Self {
+        let mut network = Self::new(bit_len);
+        expand_master_key(master_key, bit_len, &mut network.keys);
+        network
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// whose round keys are generated using the operating system's CSPRNG.
+    ///
+    /// The keys can't be recovered, so the permutation can't be reproduced
+    /// later (e.g. by another process). Use [`FeistelNetwork::from_master_key`]
+    /// with a stored master key to reproduce it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::feistel::FeistelNetwork;
+    ///
+    /// let network = FeistelNetwork::<8>::random(13);
+    /// let output = network.permute_bounded(42, 5757);
+    /// assert_eq!(network.permute_bounded_inv(output, 5757), 42);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn random(bit_len: u8) -> Self {
+        Self::from_rng(bit_len, &mut OsRng)
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// whose round keys are generated by the given cryptographically secure
+    /// random number generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    #[cfg(feature = "std")]
+    pub fn from_rng<R: RngCore + CryptoRng + ?Sized>(bit_len: u8, rng: &mut R) -> Self {
+        let mut network = Self::new(bit_len);
+        fill_keys(rng, &mut network.keys);
+        network
+    }
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> FeistelNetwork<ROUNDS, F> {
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub const fn with_round_function(bit_len: u8, keys: [u64; ROUNDS], round_function: F) -> Self {
+        Self {
+            keys,
+            core: Core::new(bit_len, u64::BITS, round_function),
+        }
+    }
+
+    /// Returns a mutable reference to the array of round keys used by
+    /// the Feistel network.
+    ///
+    /// The round keys must be secret, independent and uniformly random, which
+    /// is easy to get wrong. Prefer [`FeistelNetwork::from_master_key`].
+    pub fn keys_mut(&mut self) -> &mut [u64; ROUNDS] {
+        &mut self.keys
+    }
+
+    network_methods!(
+        u64,
+        "FeistelNetwork",
+        "FeistelNetwork::<8>::from_master_key(13, b\"a secret master key\")"
+    );
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> Network for FeistelNetwork<ROUNDS, F> {
+    type Word = u64;
+    type Function = F;
+
+    fn core(&self) -> &Core<F> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<F> {
+        &mut self.core
+    }
+
+    fn round_keys(&self) -> &[u64] {
+        &self.keys
+    }
+
+    fn round_keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    fn word(value: u128) -> u64 {
+        value as u64
+    }
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> Permutation for FeistelNetwork<ROUNDS, F> {
+    fn permute(&self, input: u64) -> u64 {
+        FeistelNetwork::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        FeistelNetwork::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        1 << self.bit_len()
+    }
+
+    fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+        FeistelNetwork::permute_bounded_constant_time(self, input, domain_len)
+    }
+}
+
+impl<const ROUNDS: usize, F> fmt::Debug for FeistelNetwork<ROUNDS, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the round keys in logs
+        f.debug_struct("FeistelNetwork")
+            .field("bit_len", &self.core.bit_len())
+            .field("rounds", &ROUNDS)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The error returned by [`FeistelNetwork::try_permute`] for inputs outside
+/// the permuted domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfDomainError {
+    /// The input that was permuted.
+    pub input: u64,
+    /// The number of bits of the permuted integers.
+    pub bit_len: u8,
+}
+
+impl fmt::Display for OutOfDomainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "input {} is outside the domain of {}-bit integers",
+            self.input, self.bit_len
+        )
+    }
+}
+
+impl Error for OutOfDomainError {}
+
+/// The minimum length of the key material accepted by
+/// [`FeistelNetwork::set_keys_from_bytes`], in bytes.
+pub const MIN_KEY_MATERIAL_LEN: usize = 16;
+
+/// The error returned by [`FeistelNetwork::set_keys_from_bytes`] for key
+/// material that can't initialize the round keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyMaterialError {
+    /// The key material is shorter than [`MIN_KEY_MATERIAL_LEN`].
+    TooShort(usize),
+    /// The network has more than [`MAX_MASTER_KEY_ROUNDS`] rounds, so the key
+    /// material can't be expanded.
+    TooManyRounds(usize),
+}
+
+impl fmt::Display for KeyMaterialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyMaterialError::TooShort(len) => write!(
+                f,
+                "key material of {} bytes is too short (at least {} are required)",
+                len, MIN_KEY_MATERIAL_LEN
+            ),
+            KeyMaterialError::TooManyRounds(rounds) => write!(
+                f,
+                "cannot expand key material into {} rounds (at most {} are supported)",
+                rounds, MAX_MASTER_KEY_ROUNDS
+            ),
+        }
+    }
+}
+
+impl Error for KeyMaterialError {}
+
+/// Sets `keys` from key material, split if it has the exact length and
+/// expanded otherwise.
+fn set_keys_from_bytes(
+    keys: &mut [u64],
+    bit_len: u8,
+    bytes: &[u8],
+) -> Result<(), KeyMaterialError> {
+    if bytes.len() < MIN_KEY_MATERIAL_LEN {
+        return Err(KeyMaterialError::TooShort(bytes.len()));
+    }
+    if bytes.len() == keys.len() * 8 {
+        for (key, chunk) in keys.iter_mut().zip(bytes.chunks_exact(8)) {
+            *key = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        return Ok(());
+    }
+    if keys.len() > MAX_MASTER_KEY_ROUNDS {
+        return Err(KeyMaterialError::TooManyRounds(keys.len()));
+    }
+    expand_master_key(bytes, bit_len, keys);
+    Ok(())
+}
+
+/// The maximum number of rounds whose keys can be derived from a master key,
+/// which is limited by the output length of HKDF-SHA512.
+pub const MAX_MASTER_KEY_ROUNDS: usize = 255 * 64 / 8;
+
+/// Fills `keys` with round keys expanded from `master_key` via HKDF-SHA512.
+fn expand_master_key(master_key: &[u8], bit_len: u8, keys: &mut [u64]) {
+    assert!(
+        keys.len() <= MAX_MASTER_KEY_ROUNDS,
+        "rounds (is {}) should be <= {}",
+        keys.len(),
+        MAX_MASTER_KEY_ROUNDS
+    );
+    let mut hkdf = Hkdf::<Sha512>::new(None, master_key);
+    let rounds = (keys.len() as u64).to_le_bytes();
+    let info = [
+        b"wordle-generator feistel round keys:",
+        &[bit_len][..],
+        &rounds,
+    ];
+    // The number of rounds was checked above
+    hkdf.expand_multi_info(&info, cast_slice_mut(keys)).unwrap();
+    // SAFETY: the HKDF state only holds integer arrays, and isn't used again
+    unsafe { wipe_flat(&mut hkdf) };
+    for key in keys {
+        *key = u64::from_le(*key);
+    }
+}
+
+/// Fills `keys` with round keys generated by `rng`.
+#[cfg(feature = "std")]
+fn fill_keys<R: RngCore + CryptoRng + ?Sized>(rng: &mut R, keys: &mut [u64]) {
+    for key in keys {
+        *key = rng.next_u64();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::Zeroize for FeistelNetwork<ROUNDS, F> {
+    fn zeroize(&mut self) {
+        self.keys.zeroize();
+        self.core.whitening.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> Drop for FeistelNetwork<ROUNDS, F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::ZeroizeOnDrop for FeistelNetwork<ROUNDS, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CycleWalkCounter, DynFeistelNetwork, FeistelNetwork, FeistelNetwork128, KeyMaterialError,
+        OutOfDomainError, RoundFunction, SipHashRound, WyHashRound, CONSTANT_TIME_BITS,
+        MAX_MASTER_KEY_ROUNDS,
+    };
+
+    #[test]
+    fn small_domain() {
+        let keys = [0x12, 8];
+        let network = FeistelNetwork::with_keys(4, keys);
+        assert_eq!(network.permute(1), network.permute(1));
+        assert_ne!(network.permute(2), network.permute(3));
+    }
+
+    #[test]
+    fn full_domain() {
+        let mut network = FeistelNetwork::<1>::new(10);
+        network.keys_mut()[0] = 0x34; // initialize
+
+        let mut seen = [false; 1 << 10];
+        for value in 0..1 << 10 {
+            let result = network.permute(value) as usize;
+            seen[result] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+
+    #[test]
+    fn bijective() {
+        let keys = [0xAB; 8];
+        let network = FeistelNetwork::with_keys(12, keys);
+
+        let mut seen = [false; 1 << 12];
+        for value in 0..1 << 12 {
+            let result = network.permute(value) as usize;
+            assert!(!seen[result]);
+            seen[result] = true;
+        }
+        assert!(seen.iter().all(|&x| x));
+    }
+
+    #[test]
+    fn idempotent() {
+        let keys = [0xCD; 8];
+        let network = FeistelNetwork::with_keys(8, keys);
+        for value in 0..1 << 8 {
+            let expected = network.permute(value);
+            for _ in 0..4 {
+                assert_eq!(network.permute(value), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let keys = [0xEF; 8];
+        let network = FeistelNetwork::with_keys(10, keys);
+        for value in 0..1 << 10 {
+            assert_eq!(network.permute_inv(network.permute(value)), value);
+        }
+    }
+
+    #[test]
+    fn try_permute() {
+        let network = FeistelNetwork::<8>::with_keys(6, [0x42; 8]);
+        assert_eq!(network.try_permute(63), Ok(network.permute(63)));
+        assert_eq!(
+            network.try_permute(64),
+            Err(OutOfDomainError {
+                input: 64,
+                bit_len: 6
+            })
+        );
+        assert_eq!(
+            network.try_permute(64).unwrap_err().to_string(),
+            "input 64 is outside the domain of 6-bit integers"
+        );
+        let network = FeistelNetwork::<8>::new(64);
+        assert!(network.try_permute(u64::MAX).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn permute_out_of_domain() {
+        FeistelNetwork::<8>::new(6).permute(64);
+    }
+
+    #[test]
+    fn bounded() {
+        for domain_len in [1, 2, 347, 512, 1000] {
+            let network = FeistelNetwork::<8>::with_keys(10, [0x99; 8]);
+            let mut seen = vec![false; domain_len as usize];
+            for value in 0..domain_len {
+                let result = network.permute_bounded(value, domain_len);
+                assert!(!seen[result as usize]);
+                seen[result as usize] = true;
+                assert_eq!(network.permute_bounded_inv(result, domain_len), value);
+            }
+        }
+        let network = FeistelNetwork::<8>::new(64);
+        let value = network.permute_bounded(u64::MAX - 1, u64::MAX);
+        assert!(value < u64::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounded_domain_too_large() {
+        FeistelNetwork::<8>::new(10).permute_bounded(0, 1025);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bounded_input_out_of_domain() {
+        FeistelNetwork::<8>::new(10).permute_bounded(347, 347);
+    }
+
+    #[test]
+    fn master_key() {
+        let network = FeistelNetwork::<8>::from_master_key(16, b"master key");
+        assert_eq!(network.permute(12345), 35307);
+        let dynamic = DynFeistelNetwork::from_master_key(16, 8, b"master key");
+        assert_eq!(dynamic.permute(12345), network.permute(12345));
+        // Networks of different shapes have unrelated keys
+        let wider = FeistelNetwork::<8>::from_master_key(17, b"master key");
+        assert_ne!(wider.keys[..], network.keys[..]);
+        let longer = FeistelNetwork::<9>::from_master_key(16, b"master key");
+        assert_ne!(longer.keys[..8], network.keys[..]);
+        let other = FeistelNetwork::<8>::from_master_key(16, b"other key");
+        assert_ne!(other.keys, network.keys);
+    }
+
+    #[test]
+    #[should_panic]
+    fn master_key_rounds() {
+        DynFeistelNetwork::from_master_key(16, MAX_MASTER_KEY_ROUNDS + 1, b"master key");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn random() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let network = FeistelNetwork::<8>::from_rng(16, &mut StdRng::seed_from_u64(7));
+        let dynamic = DynFeistelNetwork::from_rng(16, 8, &mut StdRng::seed_from_u64(7));
+        assert_eq!(dynamic.keys[..], network.keys);
+        assert_eq!(dynamic.permute(12345), network.permute(12345));
+        let network = FeistelNetwork::<8>::random(16);
+        assert_ne!(FeistelNetwork::<8>::random(16).keys, network.keys);
+        assert_eq!(network.permute_inv(network.permute(12345)), 12345);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        let keys = [3, 1, 4, 1, 5, 9, 2, 6];
+        let mut network = FeistelNetwork::with_round_function(13, keys, SipHashRound::new(42));
+        network.set_whitening(0x1234, 0x0567);
+        let json = serde_json::to_value(&network).unwrap();
+        assert_eq!(json["bit_len"], 13);
+        let restored: FeistelNetwork<8, SipHashRound> =
+            serde_json::from_value(json.clone()).unwrap();
+        assert!((0..1 << 13).all(|value| restored.permute(value) == network.permute(value)));
+
+        // The networks share a format
+        let dynamic: DynFeistelNetwork<SipHashRound> =
+            serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(dynamic.rounds(), 8);
+        assert_eq!(dynamic.permute(347), network.permute(347));
+        let err = serde_json::from_value::<FeistelNetwork<6, SipHashRound>>(json.clone());
+        assert!(err.unwrap_err().to_string().contains("6 round keys"));
+
+        let mut invalid = json;
+        invalid["bit_len"] = 65.into();
+        let err = serde_json::from_value::<FeistelNetwork<8, SipHashRound>>(invalid.clone());
+        assert!(err.unwrap_err().to_string().contains("bit_len (is 65)"));
+        let wide: FeistelNetwork128<8, SipHashRound> = serde_json::from_value(invalid).unwrap();
+        assert_eq!(wide.bit_len(), 65);
+    }
+
+    #[test]
+    fn permute_many() {
+        let mut network = FeistelNetwork::<8>::with_keys(13, [3, 1, 4, 1, 5, 9, 2, 6]);
+        network.set_whitening(0x1234, 0x0567);
+        // Not a multiple of the number of lanes
+        let mut values: Vec<u64> = (0..1003).map(|value| value * 7).collect();
+        let expected: Vec<_> = values.iter().map(|&value| network.permute(value)).collect();
+        network.permute_many(&mut values);
+        assert_eq!(values, expected);
+
+        let dynamic = DynFeistelNetwork::with_keys(64, &[2, 7, 1, 8, 2, 8, 1, 8, 2]);
+        let mut values = [u64::MAX, 0, 1, 42, 19_000];
+        let expected = values.map(|value| dynamic.permute(value));
+        dynamic.permute_many(&mut values);
+        assert_eq!(values, expected);
+    }
+
+    #[test]
+    fn permute_bounded_constant_time() {
+        let network =
+            FeistelNetwork::with_round_function(9, [3, 1, 4, 1, 5, 9, 2, 6], SipHashRound::new(42));
+        for value in 0..347 {
+            let output = network.permute_bounded_constant_time(value, 347);
+            assert_eq!(output, network.permute_bounded(value, 347));
+        }
+        // Tiny domains take more steps, so the walk still fits within them
+        let dynamic = DynFeistelNetwork::with_keys(9, &[3, 1, 4, 1, 5, 9, 2, 6]);
+        assert_eq!(dynamic.constant_time_steps(3), 3797);
+        for value in 0..3 {
+            let output = dynamic.permute_bounded_constant_time(value, 3);
+            assert_eq!(output, dynamic.permute_bounded(value, 3));
+        }
+    }
+
+    #[test]
+    fn constant_time_steps() {
+        // Balanced networks may be 4 times larger than the domain, e.g. 5757
+        // words round up to 14 bits
+        let network = FeistelNetwork::<8>::from_master_key(14, b"master key");
+        for domain_len in [1 << 14, 1 << 13, 5757, 4097, 100] {
+            let steps = network.constant_time_steps(domain_len);
+            let miss = 1.0 - domain_len as f64 / (1 << 14) as f64;
+            assert!(miss.powf(steps as f64) <= 2f64.powi(-(CONSTANT_TIME_BITS as i32)));
+
+            let mut counter = CycleWalkCounter::new();
+            for value in 0..domain_len {
+                let output = network.permute_bounded_counted(value, domain_len, &mut counter);
+                assert_eq!(
+                    network.permute_bounded_constant_time(value, domain_len),
+                    output
+                );
+            }
+            assert!(counter.max_steps() <= steps);
+        }
+        assert_eq!(network.constant_time_steps(1 << 13), 45);
+        assert_eq!(network.constant_time_steps(5757), 67);
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let mut network = FeistelNetwork::<8>::from_master_key(12, b"master key");
+        network.set_whitening(0x123, 0x456);
+        network.zeroize();
+        assert_eq!(network.core.whitening, [0; 2]);
+        let zeroed = FeistelNetwork::<8>::new(12);
+        assert!((0..1 << 12).all(|value| network.permute(value) == zeroed.permute(value)));
+
+        let mut dynamic = DynFeistelNetwork::from_master_key(12, 10, b"master key");
+        dynamic.set_whitening(0x123, 0x456);
+        dynamic.zeroize();
+        assert_eq!(dynamic.core.whitening, [0; 2]);
+        assert_eq!(
+            dynamic.permute(42),
+            DynFeistelNetwork::new(12, 10).permute(42)
+        );
+
+        let mut wide = FeistelNetwork128::<8>::from_master_key(100, b"master key");
+        wide.set_whitening(0x123, 0x456);
+        wide.zeroize();
+        assert_eq!(wide.core.whitening, [0; 2]);
+        assert_eq!(wide.keys, [0; 8]);
+    }
+
+    #[test]
+    fn permute_const() {
+        static NETWORK: FeistelNetwork<8> = FeistelNetwork::with_keys(13, [3, 1, 4, 1, 5, 9, 2, 6]);
+        const OUTPUT: u64 = NETWORK.permute_const(42);
+        assert_eq!(OUTPUT, NETWORK.permute(42));
+
+        let mut network = FeistelNetwork::<8>::from_master_key(64, b"master key");
+        network.set_whitening(0x1234, 0x5678);
+        for value in (0..1000).chain([u64::MAX, 0x0123_4567_89AB_CDEF]) {
+            assert_eq!(network.permute_const(value), network.permute(value));
+        }
+        let native = WyHashRound::with_native_endian(true);
+        for value in 0..1000 {
+            let key = value * 0x9E37;
+            assert_eq!(native.round_const(key, value), native.round(key, value));
+        }
+    }
+
+    #[test]
+    fn set_keys_from_bytes() {
+        let mut network = FeistelNetwork::<2>::new(13);
+        let bytes: Vec<u8> = (1..=16).collect();
+        network.set_keys_from_bytes(&bytes).unwrap();
+        assert_eq!(network.keys, [0x0807_0605_0403_0201, 0x100F_0E0D_0C0B_0A09]);
+
+        let mut expanded = FeistelNetwork::<8>::new(13);
+        expanded
+            .set_keys_from_bytes(b"a master key of 29 bytes long")
+            .unwrap();
+        let master = FeistelNetwork::<8>::from_master_key(13, b"a master key of 29 bytes long");
+        assert_eq!(expanded.keys, master.keys);
+
+        let err = expanded.set_keys_from_bytes(&[0x42; 15]).unwrap_err();
+        assert_eq!(err, KeyMaterialError::TooShort(15));
+        let mut long = DynFeistelNetwork::new(13, MAX_MASTER_KEY_ROUNDS + 1);
+        let err = long.set_keys_from_bytes(&[0x42; 32]).unwrap_err();
+        assert_eq!(
+            err,
+            KeyMaterialError::TooManyRounds(MAX_MASTER_KEY_ROUNDS + 1)
+        );
+    }
+
+    #[test]
+    fn tweak() {
+        let network = FeistelNetwork::<8>::with_keys(13, [3, 1, 4, 1, 5, 9, 2, 6]);
+        let tweaked = network.tweak(0);
+        assert_ne!(tweaked.keys, network.keys);
+        assert_eq!(tweaked.keys[0], WyHashRound::default().round(3, 0));
+        assert_eq!(tweaked.permute(42), network.tweak(0).permute(42));
+
+        let dynamic = DynFeistelNetwork::with_keys(13, &network.keys);
+        assert_eq!(dynamic.tweak(7).permute(42), network.tweak(7).permute(42));
+        let wide = FeistelNetwork128::with_keys(13, network.keys);
+        assert_eq!(
+            wide.tweak(7).permute(42),
+            u128::from(network.tweak(7).permute(42))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn len_must_fit() {
+        FeistelNetwork::<8>::new(65);
+    }
+
+    #[test]
+    fn contains() {
+        let network = FeistelNetwork::<8>::new(6);
+        assert!(network.contains(0));
+        assert!(network.contains(63));
+        assert!(!network.contains(64));
+        assert!(FeistelNetwork::<8>::new(64).contains(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn len_must_be_positive() {
+        FeistelNetwork::<8>::new(0);
+    }
+
+    #[test]
+    fn unbalanced() {
+        fn check<const ROUNDS: usize>(bit_len: u8) {
+            let network = FeistelNetwork::with_keys(bit_len, [0x5A; ROUNDS]);
+            let mut seen = vec![false; 1 << bit_len];
+            for value in 0..1 << bit_len {
+                let result = network.permute(value);
+                assert!(network.contains(result));
+                assert!(!seen[result as usize]);
+                seen[result as usize] = true;
+                assert_eq!(network.permute_inv(result), value);
+            }
+        }
+        // An odd number of rounds swaps the lengths of the halves
+        for bit_len in [1, 3, 13] {
+            check::<7>(bit_len);
+            check::<8>(bit_len);
+        }
+    }
+
+    #[test]
+    fn round_function() {
+        #[derive(Clone, Copy)]
+        struct Xor;
+
+        impl RoundFunction for Xor {
+            fn round(&self, key: u64, input: u64) -> u64 {
+                key ^ input
+            }
+        }
+
+        let keys = [0x3C; 8];
+        let default = FeistelNetwork::with_keys(12, keys);
+        let wyhash = FeistelNetwork::with_round_function(12, keys, WyHashRound::default());
+        let xor = FeistelNetwork::with_round_function(12, keys, Xor);
+        for value in 0..1 << 12 {
+            assert_eq!(default.permute(value), wyhash.permute(value));
+            assert_eq!(xor.permute_inv(xor.permute(value)), value);
+        }
+    }
+
+    #[test]
+    fn siphash_vectors() {
+        let network =
+            FeistelNetwork::with_round_function(20, [1, 2, 3, 4, 5, 6, 7, 8], SipHashRound::new(9));
+        let outputs: Vec<_> = (0..4).map(|value| network.permute(value)).collect();
+        assert_eq!(outputs, [326460, 463988, 498282, 289550]);
+        let other = FeistelNetwork::with_round_function(
+            20,
+            [1, 2, 3, 4, 5, 6, 7, 8],
+            SipHashRound::new(10),
+        );
+        assert_ne!(network.permute(0), other.permute(0));
+    }
+
+    #[test]
+    fn half_siphash_vectors() {
+        use super::{half_siphash, HalfSipHashRound};
+
+        // The reference vectors of the key 00 01 .. 07, for the messages
+        // 00 01 .. n-1 of 0 and 4 bytes
+        let key = 0x0706_0504_0302_0100;
+        assert_eq!(half_siphash(key, &[]), 0x5b9f_35a9);
+        assert_eq!(half_siphash(key, &[0x0302_0100]), 0x8946_6e2a);
+        let round = HalfSipHashRound;
+        assert_eq!(round.round(key, 0x0302_0100), 0x8946_6e2a);
+        assert_ne!(round.round(key, 1), round.round(key, 1 << 32 | 1));
+        let keys = [1, 2, 3, 4, 5, 6, 7, 8];
+        let network = FeistelNetwork::with_round_function(20, keys, round);
+        let outputs: Vec<_> = (0..4).map(|value| network.permute(value)).collect();
+        assert_eq!(outputs, [509729, 841845, 331545, 1001000]);
+        let wide = FeistelNetwork::with_round_function(64, keys, round);
+        assert_eq!(wide.permute_inv(wide.permute(u64::MAX)), u64::MAX);
+    }
+
+    #[test]
+    fn narrow_networks() {
+        // The constant path always uses 128-bit arithmetic
+        for bit_len in 1..=33 {
+            let mut network = FeistelNetwork::with_keys(bit_len, [3, 1, 4, 1, 5, 9, 2]);
+            network.set_whitening(0x1234_5678, 0x9ABC_DEF0);
+            let values = [0, 1, 0x5555_5555, u64::MAX];
+            for value in values.map(|value| value & super::core::mask(bit_len) as u64) {
+                let output = network.permute(value);
+                assert_eq!(output, network.permute_const(value));
+                assert_eq!(network.permute_inv(output), value);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "blake3")]
+    fn blake3_vectors() {
+        use super::Blake3Round;
+
+        let round = Blake3Round::new([7; 32]);
+        assert_eq!(round.round(1, 2), 16618669010092310538);
+        let keys = [1, 2, 3, 4, 5, 6, 7, 8];
+        let network = FeistelNetwork::with_round_function(20, keys, round);
+        let outputs: Vec<_> = (0..4).map(|value| network.permute(value)).collect();
+        assert_eq!(outputs, [535584, 27611, 523384, 460492]);
+        let other = FeistelNetwork::with_round_function(20, keys, Blake3Round::new([8; 32]));
+        assert_ne!(network.permute(0), other.permute(0));
+    }
+
+    #[test]
+    #[cfg(feature = "aes")]
+    fn aes_vectors() {
+        use super::AesRound;
+
+        // The key and plaintext of the FIPS 197 example vector
+        let key = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        let round = AesRound::new(key);
+        let output = round.round(0x7766_5544_3322_1100, 0xFFEE_DDCC_BBAA_9988);
+        // The first half of the ciphertext 69c4e0d86a7b0430d8cdb78070b4c55a
+        assert_eq!(output, 0x3004_7B6A_D8E0_C469);
+        let keys = [1, 2, 3, 4, 5, 6, 7, 8];
+        let network = FeistelNetwork::with_round_function(20, keys, round);
+        let outputs: Vec<_> = (0..4).map(|value| network.permute(value)).collect();
+        assert_eq!(outputs, [549591, 582111, 302020, 998758]);
+    }
+
+    #[test]
+    fn hmac_sha256_vectors() {
+        use super::HmacSha256Round;
+
+        let round = HmacSha256Round::new([0x0B; 32]);
+        // The first 8 bytes of HMAC-SHA-256(0x0B * 32, 1u64 LE || 2u64 LE)
+        assert_eq!(round.round(1, 2), 4635547227581378520);
+        let keys = [1, 2, 3, 4, 5, 6, 7, 8];
+        let network = FeistelNetwork::with_round_function(20, keys, round);
+        let outputs: Vec<_> = (0..4).map(|value| network.permute(value)).collect();
+        assert_eq!(outputs, [233626, 835025, 368470, 269859]);
+    }
+
+    #[test]
+    fn whitening() {
+        let keys = [0x77; 8];
+        let plain = FeistelNetwork::with_keys(11, keys);
+        let mut whitened = FeistelNetwork::with_keys(11, keys);
+        whitened.set_whitening(0, 0);
+        assert_eq!(whitened.permute(5), plain.permute(5));
+
+        whitened.set_whitening(0x1234_5678, u64::MAX);
+        let mut seen = [false; 1 << 11];
+        for value in 0..1 << 11 {
+            let result = whitened.permute(value);
+            assert!(whitened.contains(result));
+            assert!(!seen[result as usize]);
+            seen[result as usize] = true;
+            assert_eq!(result, plain.permute(value ^ 0x678) ^ 0x7FF);
+            assert_eq!(whitened.permute_inv(result), value);
+        }
+    }
+
+    #[test]
+    fn for_domain_is_tight() {
+        assert_eq!(FeistelNetwork::<8>::bit_len_for(347), 9);
+        assert_eq!(FeistelNetwork::<8>::for_domain(347).bit_len(), 9);
+        assert_eq!(FeistelNetwork::<8>::for_domain(1 << 12).bit_len(), 13);
+    }
+}
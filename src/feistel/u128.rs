@@ -0,0 +1,183 @@
+use super::core::{network_methods, Core, Network};
+use super::{expand_master_key, RoundFunction, WyHashRound};
+use core::fmt;
+
+/// A Feistel network that permutes 128-bit integers, for domains larger than
+/// 2^64, such as compound keys like `(tenant_id, day)`.
+///
+/// The halves of the input have up to 64 bits each, so every [`RoundFunction`]
+/// can be used. Apart from the width of the integers, this behaves like a
+/// [`FeistelNetwork`](crate::feistel::FeistelNetwork), and networks of up to 64
+/// bits permute integers the same way.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::feistel::FeistelNetwork128;
+///
+/// let network = FeistelNetwork128::<8>::with_keys(128, [3, 1, 4, 1, 5, 9, 2, 6]);
+/// let key = (42u128 << 64) | 19_000;
+/// let output = network.permute(key);
+/// assert_eq!(network.permute_inv(output), key);
+/// ```
+#[derive(Clone)]
+pub struct FeistelNetwork128<const ROUNDS: usize, F = WyHashRound> {
+    pub(super) keys: [u64; ROUNDS],
+    pub(super) core: Core<F>,
+}
+
+impl<const ROUNDS: usize> FeistelNetwork128<ROUNDS> {
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`.
+    ///
+    /// The round keys are initially set to zero. Prefer
+    /// [`FeistelNetwork128::from_master_key`], or initialize the keys by mutating
+    /// `FeistelNetwork128::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u128::BITS`.
+    pub fn new(bit_len: u8) -> Self {
+        Self::with_keys(bit_len, [0; ROUNDS])
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u128::BITS`.
+    pub fn with_keys(bit_len: u8, keys: [u64; ROUNDS]) -> Self {
+        Self::with_round_function(bit_len, keys, WyHashRound::default())
+    }
+
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`, whose
+    /// round keys are derived from a secret master key. See
+    /// [`FeistelNetwork::from_master_key`](crate::feistel::FeistelNetwork::from_master_key).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u128::BITS`, or if `ROUNDS`
+    /// is greater than
+    /// [`MAX_MASTER_KEY_ROUNDS`](crate::feistel::MAX_MASTER_KEY_ROUNDS).
+    pub fn from_master_key(bit_len: u8, master_key: &[u8]) -> Self {
+        let mut network = Self::new(bit_len);
+        expand_master_key(master_key, bit_len, &mut network.keys);
+        network
+    }
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> FeistelNetwork128<ROUNDS, F> {
+    /// Creates a Feistel network to permute a domain of size `2^bit_len`,
+    /// using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u128::BITS`.
+    pub fn with_round_function(bit_len: u8, keys: [u64; ROUNDS], round_function: F) -> Self {
+        Self {
+            keys,
+            core: Core::new(bit_len, u128::BITS, round_function),
+        }
+    }
+
+    /// Returns a mutable reference to the array of round keys used by
+    /// the Feistel network.
+    ///
+    /// The round keys must be secret, independent and uniformly random.
+    /// Prefer [`FeistelNetwork128::from_master_key`].
+    pub fn keys_mut(&mut self) -> &mut [u64; ROUNDS] {
+        &mut self.keys
+    }
+
+    network_methods!(
+        u128,
+        "FeistelNetwork128",
+        "FeistelNetwork128::<8>::from_master_key(13, b\"a secret master key\")"
+    );
+}
+
+impl<const ROUNDS: usize, F: RoundFunction> Network for FeistelNetwork128<ROUNDS, F> {
+    type Word = u128;
+    type Function = F;
+
+    fn core(&self) -> &Core<F> {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core<F> {
+        &mut self.core
+    }
+
+    fn round_keys(&self) -> &[u64] {
+        &self.keys
+    }
+
+    fn round_keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    fn word(value: u128) -> u128 {
+        value
+    }
+}
+
+impl<const ROUNDS: usize, F> fmt::Debug for FeistelNetwork128<ROUNDS, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeistelNetwork128")
+            .field("bit_len", &self.core.bit_len())
+            .field("rounds", &ROUNDS)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::Zeroize for FeistelNetwork128<ROUNDS, F> {
+    fn zeroize(&mut self) {
+        self.keys.zeroize();
+        self.core.whitening.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> Drop for FeistelNetwork128<ROUNDS, F> {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(self);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const ROUNDS: usize, F> zeroize::ZeroizeOnDrop for FeistelNetwork128<ROUNDS, F> {}
+
+#[cfg(test)]
+mod tests {
+    use super::FeistelNetwork128;
+    use crate::feistel::FeistelNetwork;
+
+    #[test]
+    fn wide() {
+        let keys = [0x1F; 7];
+        for bit_len in [5, 12, 64] {
+            let network = FeistelNetwork::with_keys(bit_len, keys);
+            let wide = FeistelNetwork128::with_keys(bit_len, keys);
+            for value in 0..1 << 5 {
+                assert_eq!(wide.permute(value.into()), network.permute(value).into());
+            }
+        }
+
+        let mut network = FeistelNetwork128::<8>::with_keys(128, [0x2E; 8]);
+        network.set_whitening(u128::MAX, 1 << 100);
+        for value in [0, 1, u64::MAX as u128 + 1, u128::MAX] {
+            assert_eq!(network.permute_inv(network.permute(value)), value);
+        }
+
+        // Bounded permutation of a 65-bit domain
+        let network = FeistelNetwork128::<8>::with_keys(65, [0x3D; 8]);
+        let domain_len = (1 << 64) + 3;
+        assert!(network.contains(domain_len));
+        assert!(!network.contains(1 << 65));
+        for value in [0, 1 << 63, domain_len - 1] {
+            let output = network.permute_bounded(value, domain_len);
+            assert!(output < domain_len);
+            assert_eq!(network.permute_bounded_inv(output, domain_len), value);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{hex, Normalizer};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+use sha2::{Digest, Sha256};
+
+/// The length of a list fingerprint in bytes.
+const FINGERPRINT_LEN: usize = 32;
+
+/// A hash of a list of words, to check that two deployments use the exact
+/// same list before comparing their answers.
+///
+/// The fingerprint covers the words and their order, after normalizing each
+/// word with a [`Normalizer`], so lists that only differ in how their words
+/// are encoded have the same fingerprint. It doesn't cover the seed or the
+/// configuration of a generator, which are covered by its
+/// [`Commitment`](crate::Commitment) along with the fingerprint.
+///
+/// Fingerprints are formatted as lowercase hexadecimal strings.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{CaseFolding, ListFingerprint, Normalizer};
+///
+/// let normalizer = Normalizer::new().case_folding(CaseFolding::Lowercase);
+/// let fingerprint = ListFingerprint::new(&["apple", "grape", "lemon"], &normalizer);
+/// assert_eq!(fingerprint, ListFingerprint::new(&["APPLE", "Grape", "lemon"], &normalizer));
+/// assert_ne!(fingerprint, ListFingerprint::new(&["grape", "apple", "lemon"], &normalizer));
+/// assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ListFingerprint([u8; FINGERPRINT_LEN]);
+
+impl ListFingerprint {
+    /// Computes the fingerprint of a list of words, once normalized by the
+    /// given normalizer.
+    pub fn new<T: AsRef<str>>(words: &[T], normalizer: &Normalizer) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(b"wordle-generator list fingerprint v1");
+        // The list and every word are length-prefixed
+        hasher.update((words.len() as u64).to_le_bytes());
+        for word in words {
+            let word = normalizer.normalize(word.as_ref());
+            hasher.update((word.len() as u64).to_le_bytes());
+            hasher.update(word.as_bytes());
+        }
+        Self(hasher.finalize().into())
+    }
+
+    /// Returns the bytes of the fingerprint.
+    pub fn as_bytes(&self) -> &[u8; FINGERPRINT_LEN] {
+        &self.0
+    }
+}
+
+impl From<[u8; FINGERPRINT_LEN]> for ListFingerprint {
+    fn from(bytes: [u8; FINGERPRINT_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl fmt::Display for ListFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::write(f, &self.0)
+    }
+}
+
+impl FromStr for ListFingerprint {
+    type Err = ParseFingerprintError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode_array(s).ok_or(ParseFingerprintError(()))?;
+        Ok(Self(bytes))
+    }
+}
+
+/// An error which can be returned when parsing a [`ListFingerprint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseFingerprintError(());
+
+impl fmt::Display for ParseFingerprintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "list fingerprint should be {} hexadecimal digits",
+            2 * FINGERPRINT_LEN
+        )
+    }
+}
+
+impl Error for ParseFingerprintError {}
@@ -0,0 +1,567 @@
+//! NIST format-preserving encryption of integer domains, via the FF1 and
+//! FF3-1 modes of [NIST SP 800-38G Rev. 1](https://doi.org/10.6028/NIST.SP.800-38G).
+//!
+//! Both modes are Feistel-based block cipher modes over AES, which permute the
+//! numeral strings of a given length in some radix. Here, the numeral string
+//! `X[1..len]` is identified with the integer `NUM_radix(X)`, so a mode
+//! permutes the integers `0..radix^len`, and permutes smaller domains (e.g.
+//! the indices of a word list) via cycle walking.
+//!
+//! The standard requires domains of at least a million values. Cycle walking
+//! from such a domain down to a few thousand words takes hundreds of
+//! steps, so these modes are much slower than a
+//! [`FeistelNetwork`](crate::feistel::FeistelNetwork). Prefer them when
+//! standard-compliant encryption is a requirement.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::fpe::Ff1;
+//!
+//! let key = [0x2B, 0x7E, 0x15, 0x16, 0x28, 0xAE, 0xD2, 0xA6, 0xAB, 0xF7, 0x15, 0x88, 0x09, 0xCF, 0x4F, 0x3C];
+//! let ff1 = Ff1::new(&key, 10, 10, b"").unwrap();
+//! assert_eq!(ff1.permute(123_456_789), 2_433_477_484);
+//! assert_eq!(ff1.permute_inv(2_433_477_484), 123_456_789);
+//! ```
+
+use crate::seed::wipe_flat;
+use crate::Permutation;
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::{Aes128, Aes192, Aes256, Block};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// The minimum size of the domain required by the standard.
+const MIN_DOMAIN_LEN: u128 = 1_000_000;
+
+/// The FF1 mode, which permutes the integers `0..radix^len`.
+///
+/// The mode applies 10 Feistel rounds, with a round function based on AES
+/// CBC-MAC keyed by a 128, 192 or 256-bit key. Every permutation is further
+/// selected by a tweak of any length, which needn't be secret.
+#[derive(Clone)]
+pub struct Ff1 {
+    cipher: Cipher,
+    domain: Domain,
+    tweak: Vec<u8>,
+}
+
+impl Ff1 {
+    /// Creates an FF1 permutation of `0..radix^len` with the given AES key
+    /// and tweak.
+    ///
+    /// Returns an error if the key isn't 16, 24 or 32 bytes long, the radix
+    /// is not in `2..=65536`, or `radix^len` is less than a million or
+    /// greater than `u64::MAX`.
+    pub fn new(key: &[u8], radix: u32, len: u32, tweak: &[u8]) -> Result<Self, FpeError> {
+        Ok(Self {
+            cipher: Cipher::new(key)?,
+            domain: Domain::new(radix, len)?,
+            tweak: tweak.to_vec(),
+        })
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is outside the domain.
+    pub fn permute(&self, input: u64) -> u64 {
+        self.encrypt(input, false)
+    }
+
+    /// Returns the input that [`Ff1::permute`] maps to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is outside the domain.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        self.encrypt(output, true)
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// which is found by cycle walking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the mode.
+    pub fn permute_bounded(&self, input: u64, domain_len: u64) -> u64 {
+        self.domain
+            .walk(input, domain_len, |value| self.permute(value))
+    }
+
+    /// Returns the input that [`Ff1::permute_bounded`] maps to `output`.
+    pub fn permute_bounded_inv(&self, output: u64, domain_len: u64) -> u64 {
+        self.domain
+            .walk(output, domain_len, |value| self.permute_inv(value))
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        self.domain.contains(input)
+    }
+
+    /// Returns the size of the permuted domain, `radix^len`.
+    pub fn domain_len(&self) -> u64 {
+        self.domain.len()
+    }
+
+    fn encrypt(&self, input: u64, decrypt: bool) -> u64 {
+        let Domain { radix, len: n } = self.domain;
+        assert!(self.contains(input), "input must be within the domain");
+        let (u, v) = (n / 2, n - n / 2);
+        let (pow_u, pow_v) = (self.domain.pow(u), self.domain.pow(v));
+        // The number of bytes of `NUM_radix(B)`, and of the output of the PRF
+        let b = (u128::BITS - (pow_v - 1).leading_zeros()).div_ceil(8) as usize;
+        let d = 4 * b.div_ceil(4) + 4;
+
+        let t = self.tweak.len();
+        let mut message = Vec::with_capacity(16 + (t + b + 1).next_multiple_of(16));
+        message.extend_from_slice(&[1, 2, 1]);
+        message.extend_from_slice(&radix.to_be_bytes()[1..]);
+        message.extend_from_slice(&[10, u as u8]);
+        message.extend_from_slice(&n.to_be_bytes());
+        message.extend_from_slice(&(t as u32).to_be_bytes());
+        message.extend_from_slice(&self.tweak);
+        message.resize(message.len() + (16 - (t + b + 1) % 16) % 16, 0);
+        let prefix_len = message.len();
+
+        let (mut a, mut b_num) = (u128::from(input) / pow_v, u128::from(input) % pow_v);
+        for i in 0..10u8 {
+            let i = if decrypt { 9 - i } else { i };
+            let num = if decrypt { a } else { b_num };
+            message.truncate(prefix_len);
+            message.push(i);
+            message.extend_from_slice(&num.to_be_bytes()[16 - b..]);
+            let r = self.cipher.cbc_mac(&message);
+            let y = r[..d].iter().fold(0, |y, &byte| y << 8 | u128::from(byte));
+            let modulus = if i % 2 == 0 { pow_u } else { pow_v };
+            if decrypt {
+                let c = (b_num + modulus - y % modulus) % modulus;
+                (a, b_num) = (c, a);
+            } else {
+                let c = (a + y % modulus) % modulus;
+                (a, b_num) = (b_num, c);
+            }
+        }
+        (a * pow_v + b_num) as u64
+    }
+}
+
+impl Permutation for Ff1 {
+    fn permute(&self, input: u64) -> u64 {
+        Ff1::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        Ff1::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        Ff1::domain_len(self).into()
+    }
+}
+
+impl fmt::Debug for Ff1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ff1")
+            .field("radix", &self.domain.radix)
+            .field("len", &self.domain.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The FF3-1 mode, which permutes the integers `0..radix^len`.
+///
+/// The mode applies 8 Feistel rounds, each encrypting a single AES block
+/// keyed by a 128, 192 or 256-bit key. Every permutation is further selected
+/// by a 56-bit tweak, which needn't be secret.
+#[derive(Clone)]
+pub struct Ff3_1 {
+    cipher: Cipher,
+    domain: Domain,
+    // The left and right halves of the tweak, `T_L` and `T_R`
+    tweak: [[u8; 4]; 2],
+}
+
+impl Ff3_1 {
+    /// Creates an FF3-1 permutation of `0..radix^len` with the given AES key
+    /// and tweak.
+    ///
+    /// Returns an error if the key isn't 16, 24 or 32 bytes long, the radix
+    /// is not in `2..=65536`, or `radix^len` is less than a million or
+    /// greater than `u64::MAX`.
+    pub fn new(key: &[u8], radix: u32, len: u32, tweak: [u8; 7]) -> Result<Self, FpeError> {
+        let left = [tweak[0], tweak[1], tweak[2], tweak[3] & 0xF0];
+        let right = [tweak[4], tweak[5], tweak[6], tweak[3] << 4];
+        Self::with_tweak_halves(key, radix, len, [left, right])
+    }
+
+    fn with_tweak_halves(
+        key: &[u8],
+        radix: u32,
+        len: u32,
+        tweak: [[u8; 4]; 2],
+    ) -> Result<Self, FpeError> {
+        // The key is applied in reverse byte order
+        let mut buf = [0; 32];
+        let reversed = buf
+            .get_mut(..key.len())
+            .ok_or(FpeError::InvalidKeyLen(key.len()))?;
+        reversed.copy_from_slice(key);
+        reversed.reverse();
+        let cipher = Cipher::new(reversed);
+        // SAFETY: the buffer is a byte array, and isn't used again
+        unsafe { wipe_flat(&mut buf) };
+        Ok(Self {
+            cipher: cipher?,
+            domain: Domain::new(radix, len)?,
+            tweak,
+        })
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is outside the domain.
+    pub fn permute(&self, input: u64) -> u64 {
+        self.encrypt(input, false)
+    }
+
+    /// Returns the input that [`Ff3_1::permute`] maps to `output`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output` is outside the domain.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        self.encrypt(output, true)
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// which is found by cycle walking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the mode.
+    pub fn permute_bounded(&self, input: u64, domain_len: u64) -> u64 {
+        self.domain
+            .walk(input, domain_len, |value| self.permute(value))
+    }
+
+    /// Returns the input that [`Ff3_1::permute_bounded`] maps to `output`.
+    pub fn permute_bounded_inv(&self, output: u64, domain_len: u64) -> u64 {
+        self.domain
+            .walk(output, domain_len, |value| self.permute_inv(value))
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        self.domain.contains(input)
+    }
+
+    /// Returns the size of the permuted domain, `radix^len`.
+    pub fn domain_len(&self) -> u64 {
+        self.domain.len()
+    }
+
+    fn encrypt(&self, input: u64, decrypt: bool) -> u64 {
+        let n = self.domain.len;
+        assert!(self.contains(input), "input must be within the domain");
+        let (u, v) = (n - n / 2, n / 2);
+        let pow_v = self.domain.pow(v);
+
+        let (mut a, mut b) = (u128::from(input) / pow_v, u128::from(input) % pow_v);
+        for i in 0..8u8 {
+            let i = if decrypt { 7 - i } else { i };
+            let (m, other, w) = if i % 2 == 0 {
+                (u, v, self.tweak[1])
+            } else {
+                (v, u, self.tweak[0])
+            };
+            // The halves are reversed numeral strings, and `b` has the length
+            // of the other half
+            let num = if decrypt {
+                self.domain.rev(a, other)
+            } else {
+                self.domain.rev(b, other)
+            };
+            let mut block = Block::default();
+            block[..4].copy_from_slice(&w);
+            block[3] ^= i;
+            block[4..].copy_from_slice(&num.to_be_bytes()[4..]);
+            block.reverse();
+            self.cipher.encrypt(&mut block);
+            block.reverse();
+            let y = u128::from_be_bytes(block.into());
+            let modulus = self.domain.pow(m);
+            if decrypt {
+                let num = self.domain.rev(b, m);
+                let c = (num + modulus - y % modulus) % modulus;
+                (a, b) = (self.domain.rev(c, m), a);
+            } else {
+                let num = self.domain.rev(a, m);
+                let c = (num + y % modulus) % modulus;
+                (a, b) = (b, self.domain.rev(c, m));
+            }
+        }
+        (a * pow_v + b) as u64
+    }
+}
+
+impl Permutation for Ff3_1 {
+    fn permute(&self, input: u64) -> u64 {
+        Ff3_1::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        Ff3_1::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        Ff3_1::domain_len(self).into()
+    }
+}
+
+impl fmt::Debug for Ff3_1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ff3_1")
+            .field("radix", &self.domain.radix)
+            .field("len", &self.domain.len)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An error returned when creating a format-preserving encryption mode with
+/// invalid parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FpeError {
+    /// The AES key isn't 16, 24 or 32 bytes long.
+    InvalidKeyLen(usize),
+    /// The radix is not in `2..=65536`.
+    InvalidRadix(u32),
+    /// The domain size `radix^len` is less than a million or greater than
+    /// `u64::MAX`.
+    InvalidDomain {
+        /// The radix of the numerals.
+        radix: u32,
+        /// The number of numerals.
+        len: u32,
+    },
+}
+
+impl fmt::Display for FpeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FpeError::InvalidKeyLen(len) => {
+                write!(f, "AES key length (is {}) should be 16, 24 or 32", len)
+            }
+            FpeError::InvalidRadix(radix) => {
+                write!(f, "radix (is {}) should be in 2..=65536", radix)
+            }
+            FpeError::InvalidDomain { radix, len } => write!(
+                f,
+                "domain size {}^{} should be in 1000000..=2^64-1",
+                radix, len
+            ),
+        }
+    }
+}
+
+impl Error for FpeError {}
+
+/// An AES block cipher with a 128, 192 or 256-bit key.
+#[derive(Clone)]
+enum Cipher {
+    Aes128(Aes128),
+    Aes192(Aes192),
+    Aes256(Aes256),
+}
+
+impl Cipher {
+    fn new(key: &[u8]) -> Result<Self, FpeError> {
+        let invalid = |_| FpeError::InvalidKeyLen(key.len());
+        match key.len() {
+            16 => Aes128::new_from_slice(key).map(Cipher::Aes128),
+            24 => Aes192::new_from_slice(key).map(Cipher::Aes192),
+            32 => Aes256::new_from_slice(key).map(Cipher::Aes256),
+            _ => return Err(FpeError::InvalidKeyLen(key.len())),
+        }
+        .map_err(invalid)
+    }
+
+    fn encrypt(&self, block: &mut Block) {
+        match self {
+            Cipher::Aes128(cipher) => cipher.encrypt_block(block),
+            Cipher::Aes192(cipher) => cipher.encrypt_block(block),
+            Cipher::Aes256(cipher) => cipher.encrypt_block(block),
+        }
+    }
+
+    /// Returns the CBC-MAC of `message`, whose length is a multiple of the
+    /// block size.
+    fn cbc_mac(&self, message: &[u8]) -> Block {
+        let mut mac = Block::default();
+        for chunk in message.chunks_exact(16) {
+            mac.iter_mut().zip(chunk).for_each(|(x, y)| *x ^= y);
+            self.encrypt(&mut mac);
+        }
+        mac
+    }
+}
+
+/// The numeral strings of `len` numerals in some radix.
+#[derive(Clone, Copy)]
+struct Domain {
+    radix: u32,
+    len: u32,
+}
+
+impl Domain {
+    fn new(radix: u32, len: u32) -> Result<Self, FpeError> {
+        if !(2..=1 << 16).contains(&radix) {
+            return Err(FpeError::InvalidRadix(radix));
+        }
+        match u128::from(radix).checked_pow(len) {
+            Some(domain_len) if (MIN_DOMAIN_LEN..=u64::MAX.into()).contains(&domain_len) => {
+                Ok(Self { radix, len })
+            }
+            _ => Err(FpeError::InvalidDomain { radix, len }),
+        }
+    }
+
+    fn len(&self) -> u64 {
+        self.pow(self.len) as u64
+    }
+
+    fn pow(&self, exp: u32) -> u128 {
+        u128::from(self.radix).pow(exp)
+    }
+
+    fn contains(&self, input: u64) -> bool {
+        input < self.len()
+    }
+
+    /// Returns the integer of the reverse of the numeral string of length
+    /// `len` of `value`.
+    fn rev(&self, mut value: u128, len: u32) -> u128 {
+        let radix = u128::from(self.radix);
+        (0..len).fold(0, |rev, _| {
+            let digit = value % radix;
+            value /= radix;
+            rev * radix + digit
+        })
+    }
+
+    /// Re-applies `permute` until the output falls within `0..domain_len`.
+    fn walk(&self, input: u64, domain_len: u64, permute: impl Fn(u64) -> u64) -> u64 {
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            domain_len <= self.len(),
+            "domain_len (is {}) should be <= {}",
+            domain_len,
+            self.len()
+        );
+        assert!(
+            input < domain_len,
+            "value (is {}) should be < {}",
+            input,
+            domain_len
+        );
+        let mut value = permute(input);
+        while value >= domain_len {
+            value = permute(value);
+        }
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Ff1, Ff3_1, FpeError};
+    use std::collections::HashSet;
+
+    fn hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // The samples of NIST SP 800-38G, whose numerals are `0123456789`
+    #[test]
+    fn ff1_vectors() {
+        let key = hex("2B7E151628AED2A6ABF7158809CF4F3CEF4359D8D580AA4F7F036D6F04FC6A94");
+        let tweak = hex("39383736353433323130");
+        let samples = [
+            (16, &[][..], 2433477484),
+            (16, &tweak, 6124200773),
+            (24, &[], 2830668132),
+            (24, &tweak, 2496655549),
+            (32, &[], 6657667009),
+            (32, &tweak, 1001623463),
+        ];
+        for (key_len, tweak, expected) in samples {
+            let ff1 = Ff1::new(&key[..key_len], 10, 10, tweak).unwrap();
+            assert_eq!(ff1.permute(123456789), expected);
+            assert_eq!(ff1.permute_inv(expected), 123456789);
+        }
+    }
+
+    // The FF3 samples of NIST SP 800-38G, with 64-bit tweaks
+    #[test]
+    fn ff3_vectors() {
+        let key = hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+        let tweak = hex("D8E7920AFA330A73");
+        let halves = [
+            tweak[..4].try_into().unwrap(),
+            tweak[4..].try_into().unwrap(),
+        ];
+        let ff3 = Ff3_1::with_tweak_halves(&key, 10, 18, halves).unwrap();
+        assert_eq!(ff3.permute(890121234567890000), 750918814058654607);
+        assert_eq!(ff3.permute_inv(750918814058654607), 890121234567890000);
+    }
+
+    #[test]
+    fn ff3_1_bijective() {
+        let key = hex("EF4359D8D580AA4F7F036D6F04FC6A94");
+        let ff3 = Ff3_1::new(&key, 2, 20, [1, 2, 3, 4, 5, 6, 7]).unwrap();
+        for input in (0..ff3.domain_len()).step_by(997) {
+            assert_eq!(ff3.permute_inv(ff3.permute(input)), input);
+        }
+        let other = Ff3_1::new(&key, 2, 20, [1, 2, 3, 4, 5, 6, 8]).unwrap();
+        assert_ne!(ff3.permute(0), other.permute(0));
+    }
+
+    #[test]
+    fn bounded() {
+        let key = [7; 16];
+        let ff1 = Ff1::new(&key, 2, 20, b"words").unwrap();
+        let outputs: HashSet<_> = (0..500)
+            .map(|input| {
+                let output = ff1.permute_bounded(input, 500_000);
+                assert_eq!(ff1.permute_bounded_inv(output, 500_000), input);
+                output
+            })
+            .collect();
+        assert_eq!(outputs.len(), 500);
+        assert!(outputs.iter().all(|&output| output < 500_000));
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let err = Ff1::new(&[0; 15], 10, 10, b"").unwrap_err();
+        assert_eq!(err, FpeError::InvalidKeyLen(15));
+        let err = Ff1::new(&[0; 16], 1, 10, b"").unwrap_err();
+        assert_eq!(err, FpeError::InvalidRadix(1));
+        let err = Ff1::new(&[0; 16], 10, 5, b"").unwrap_err();
+        assert_eq!(err, FpeError::InvalidDomain { radix: 10, len: 5 });
+        let err = Ff3_1::new(&[0; 16], 2, 64, [0; 7]).unwrap_err();
+        assert_eq!(err, FpeError::InvalidDomain { radix: 2, len: 64 });
+        assert!(Ff1::new(&[0; 16], 2, 63, b"").is_ok());
+    }
+}
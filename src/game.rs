@@ -0,0 +1,304 @@
+use crate::feedback::evaluate_letters;
+use crate::{Keyboard, LetterFeedback, Normalizer};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+
+/// The default maximum number of guesses of a [`Game`].
+pub const DEFAULT_MAX_GUESSES: usize = 6;
+
+/// The state of a game of a single day.
+///
+/// A game checks the guesses against the answer of the day, and keeps track of
+/// the remaining attempts. With the `serde` feature, the state can be saved and
+/// restored (e.g. to resume a game after reloading an app). Note that the saved
+/// state contains the answer.
+///
+/// Games don't check whether a guess is in the word list, which should be done
+/// via [`Wordle::is_valid_guess`](crate::Wordle::is_valid_guess) beforehand.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{GameStatus, Seed, Wordle};
+///
+/// let words = ["apple", "grape", "lemon"];
+/// let wordle = Wordle::from_seed(&words, 3, Seed::new([0; 32]));
+/// let mut game = wordle.new_game(0);
+/// for word in words {
+///     if game.is_over() {
+///         break;
+///     }
+///     game.guess(word).unwrap();
+/// }
+/// assert_eq!(game.status(), GameStatus::Won);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game {
+    day: u64,
+    answer: String,
+    guesses: Vec<Guess>,
+    max_guesses: usize,
+    hard_mode: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    normalizer: Normalizer,
+}
+
+impl Game {
+    /// Creates a game of the given day, in which `answer` must be guessed in
+    /// at most [`DEFAULT_MAX_GUESSES`] guesses.
+    pub fn new(day: u64, answer: &str) -> Self {
+        Self {
+            day,
+            answer: answer.to_string(),
+            guesses: Vec::new(),
+            max_guesses: DEFAULT_MAX_GUESSES,
+            hard_mode: false,
+            normalizer: Normalizer::new(),
+        }
+    }
+
+    /// Sets the maximum number of guesses.
+    pub fn max_guesses(mut self, max_guesses: usize) -> Self {
+        self.max_guesses = max_guesses;
+        self
+    }
+
+    /// Sets whether the game is played in hard mode, in which every revealed
+    /// letter must be used in subsequent guesses, with correct letters in
+    /// the same position.
+    pub fn hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Sets the normalizer applied to the answer and to every guess, which
+    /// doesn't change words by default.
+    ///
+    /// Guesses are recorded in their normalized form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{CaseFolding, Game, GameStatus, Normalizer};
+    ///
+    /// let normalizer = Normalizer::new().case_folding(CaseFolding::Lowercase);
+    /// let mut game = Game::new(0, "árbol").normalizer(normalizer);
+    /// game.guess("ÁRBOL").unwrap();
+    /// assert_eq!(game.guesses()[0].word(), "árbol");
+    /// assert_eq!(game.status(), GameStatus::Won);
+    /// ```
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        if let Cow::Owned(answer) = normalizer.normalize(&self.answer) {
+            self.answer = answer;
+        }
+        self.normalizer = normalizer;
+        self
+    }
+
+    /// Returns the day of the game.
+    pub fn day(&self) -> u64 {
+        self.day
+    }
+
+    /// Returns the answer of the game.
+    pub fn answer(&self) -> &str {
+        &self.answer
+    }
+
+    /// Returns the guesses made so far, in order.
+    pub fn guesses(&self) -> &[Guess] {
+        &self.guesses
+    }
+
+    /// Returns the state of each letter across the guesses made so far.
+    pub fn keyboard(&self) -> Keyboard {
+        let mut keyboard = Keyboard::new();
+        for guess in &self.guesses {
+            keyboard.update(&guess.word, &guess.feedback);
+        }
+        keyboard
+    }
+
+    /// Returns the maximum number of guesses.
+    pub fn max_attempts(&self) -> usize {
+        self.max_guesses
+    }
+
+    /// Returns the number of guesses left.
+    pub fn remaining_attempts(&self) -> usize {
+        self.max_guesses.saturating_sub(self.guesses.len())
+    }
+
+    /// Returns whether the game is played in hard mode.
+    pub fn is_hard_mode(&self) -> bool {
+        self.hard_mode
+    }
+
+    /// Returns the status of the game.
+    pub fn status(&self) -> GameStatus {
+        match self.guesses.last() {
+            Some(guess) if guess.is_correct() => GameStatus::Won,
+            _ if self.guesses.len() >= self.max_guesses => GameStatus::Lost,
+            _ => GameStatus::InProgress,
+        }
+    }
+
+    /// Returns whether the game is won or lost.
+    pub fn is_over(&self) -> bool {
+        self.status() != GameStatus::InProgress
+    }
+
+    /// Makes a guess, and returns the feedback for each of its letters.
+    ///
+    /// Returns an error if the game is over, the guess has a different number
+    /// of letters than the answer, or the guess doesn't use the revealed
+    /// letters in hard mode. Invalid guesses don't count as attempts.
+    pub fn guess(&mut self, word: &str) -> Result<&[LetterFeedback], GuessError> {
+        if self.is_over() {
+            return Err(GuessError::GameOver);
+        }
+        let word = &*self.normalizer.normalize(word);
+        let letters: Vec<char> = word.chars().collect();
+        let answer: Vec<char> = self.answer.chars().collect();
+        if letters.len() != answer.len() {
+            return Err(GuessError::WordLength {
+                expected: answer.len(),
+                found: letters.len(),
+            });
+        }
+        if self.hard_mode {
+            for guess in &self.guesses {
+                guess.check_hard_mode(&letters)?;
+            }
+        }
+        let mut feedback = vec![LetterFeedback::Absent; letters.len()];
+        evaluate_letters(&letters, &answer, &mut feedback);
+        self.guesses.push(Guess::new(word, feedback));
+        Ok(&self.guesses.last().unwrap().feedback)
+    }
+}
+
+/// A guess of a [`Game`], along with its feedback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Guess {
+    word: String,
+    feedback: Vec<LetterFeedback>,
+}
+
+impl Guess {
+    pub(crate) fn new(word: &str, feedback: Vec<LetterFeedback>) -> Self {
+        Self {
+            word: word.to_string(),
+            feedback,
+        }
+    }
+
+    /// Returns the guessed word.
+    pub fn word(&self) -> &str {
+        &self.word
+    }
+
+    /// Returns the feedback for each letter of the guess.
+    pub fn feedback(&self) -> &[LetterFeedback] {
+        &self.feedback
+    }
+
+    /// Returns whether the guess is the answer.
+    pub fn is_correct(&self) -> bool {
+        self.feedback.iter().all(|&f| f == LetterFeedback::Correct)
+    }
+
+    /// Returns an error if `letters` don't use the letters revealed by this guess.
+    fn check_hard_mode(&self, letters: &[char]) -> Result<(), GuessError> {
+        let revealed: Vec<(usize, char, LetterFeedback)> = self
+            .word
+            .chars()
+            .zip(&self.feedback)
+            .enumerate()
+            .map(|(pos, (letter, &feedback))| (pos, letter, feedback))
+            .collect();
+        for &(pos, letter, feedback) in &revealed {
+            if feedback == LetterFeedback::Correct && letters[pos] != letter {
+                return Err(GuessError::MisplacedLetter {
+                    letter,
+                    position: pos,
+                });
+            }
+        }
+        for &(_, letter, feedback) in &revealed {
+            if feedback == LetterFeedback::Absent {
+                continue;
+            }
+            let required = revealed
+                .iter()
+                .filter(|&&(_, other, f)| other == letter && f != LetterFeedback::Absent)
+                .count();
+            let used = letters.iter().filter(|&&other| other == letter).count();
+            if used < required {
+                return Err(GuessError::MissingLetter(letter));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The status of a [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameStatus {
+    /// The answer hasn't been guessed, and guesses are left.
+    InProgress,
+    /// The answer was guessed.
+    Won,
+    /// Every guess was used without guessing the answer.
+    Lost,
+}
+
+/// An error which can be returned when making a guess in a [`Game`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GuessError {
+    /// The game is already won or lost.
+    GameOver,
+    /// The guess has a different number of letters than the answer.
+    WordLength {
+        /// The number of letters of the answer.
+        expected: usize,
+        /// The number of letters of the guess.
+        found: usize,
+    },
+    /// In hard mode, a correct letter was not kept in its position.
+    MisplacedLetter {
+        /// The correct letter.
+        letter: char,
+        /// The zero-based position of the letter.
+        position: usize,
+    },
+    /// In hard mode, a revealed letter was not used.
+    MissingLetter(char),
+}
+
+impl fmt::Display for GuessError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GuessError::GameOver => f.write_str("game is over"),
+            GuessError::WordLength { expected, found } => write!(
+                f,
+                "guess should have {} letters, but has {}",
+                expected, found
+            ),
+            GuessError::MisplacedLetter { letter, position } => {
+                write!(f, "letter {} must be {}", position + 1, letter)
+            }
+            GuessError::MissingLetter(letter) => write!(f, "guess must contain {}", letter),
+        }
+    }
+}
+
+impl Error for GuessError {}
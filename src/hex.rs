@@ -0,0 +1,25 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Writes the bytes as lowercase hexadecimal digits.
+pub(crate) fn write<W: fmt::Write>(out: &mut W, bytes: &[u8]) -> fmt::Result {
+    bytes
+        .iter()
+        .try_for_each(|byte| write!(out, "{:02x}", byte))
+}
+
+/// Returns the bytes encoded by a hexadecimal string, in either case.
+pub(crate) fn decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+    s.as_bytes()
+        .chunks_exact(2)
+        .map(|digits| u8::from_str_radix(core::str::from_utf8(digits).ok()?, 16).ok())
+        .collect()
+}
+
+/// Returns the `N` bytes encoded by a hexadecimal string.
+pub(crate) fn decode_array<const N: usize>(s: &str) -> Option<[u8; N]> {
+    decode(s)?.try_into().ok()
+}
@@ -0,0 +1,149 @@
+//! A daily-word HTTP service, built on [axum](https://docs.rs/axum).
+//!
+//! The service exposes the following routes:
+//!
+//! - `GET /v1/today` returns the day and word of the current day.
+//! - `GET /v1/day/{n}` returns the word of day `n`.
+//! - `GET /v1/verify?day={n}&word={word}` returns whether `word` is the
+//!   word of day `n`, without revealing it.
+//!
+//! Words of days after the current day are never revealed, so the routes
+//! return `404 Not Found` for them.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use std::time::UNIX_EPOCH;
+//! use wordle_generator::http::WordleService;
+//! use wordle_generator::{Seed, WordleBuilder};
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let words = vec!["apple".to_owned(), "grape".to_owned()];
+//! let wordle = WordleBuilder::from_vec(words)
+//!     .seed(Seed::generate())
+//!     .build()
+//!     .unwrap();
+//! let router = WordleService::new(wordle, UNIX_EPOCH).into_router();
+//! let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
+//! axum::serve(listener, router).await
+//! # }
+//! ```
+
+use crate::Wordle;
+use alloc::sync::Arc;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::time::SystemTime;
+
+/// The number of seconds in a day.
+const SECS_PER_DAY: u64 = 86_400;
+
+/// A daily-word service over a generator.
+pub struct WordleService<T: AsRef<str> + 'static> {
+    wordle: Wordle<'static, T>,
+    epoch: SystemTime,
+}
+
+impl<T: AsRef<str> + Send + Sync + 'static> WordleService<T> {
+    /// Creates a service whose day `0` starts at `epoch`.
+    pub fn new(wordle: Wordle<'static, T>, epoch: SystemTime) -> Self {
+        Self { wordle, epoch }
+    }
+
+    /// Returns the current day, or `None` if the epoch is in the future.
+    pub fn today(&self) -> Option<u64> {
+        let elapsed = SystemTime::now().duration_since(self.epoch).ok()?;
+        Some(elapsed.as_secs() / SECS_PER_DAY)
+    }
+
+    /// Returns the word of the given day, unless the day is after the current day.
+    fn released(&self, day: u64) -> Result<&str, ApiError> {
+        match self.today() {
+            Some(today) if day <= today => Ok(self.wordle.get(day)),
+            _ => Err(ApiError::NotReleased),
+        }
+    }
+
+    /// Returns a router that serves the routes of the service.
+    pub fn into_router(self) -> Router {
+        Router::new()
+            .route("/v1/today", get(today::<T>))
+            .route("/v1/day/{n}", get(day::<T>))
+            .route("/v1/verify", get(verify::<T>))
+            .with_state(Arc::new(self))
+    }
+}
+
+type SharedService<T> = State<Arc<WordleService<T>>>;
+
+#[derive(Serialize)]
+struct DayResponse<'a> {
+    day: u64,
+    word: &'a str,
+}
+
+#[derive(Deserialize)]
+struct VerifyQuery {
+    day: u64,
+    word: String,
+}
+
+#[derive(Serialize)]
+struct VerifyResponse {
+    day: u64,
+    correct: bool,
+}
+
+/// An error returned by a route.
+enum ApiError {
+    /// The day is after the current day.
+    NotReleased,
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, message) = match self {
+            ApiError::NotReleased => (StatusCode::NOT_FOUND, "day has not started yet"),
+        };
+        let body = Json(serde_json::json!({ "error": message }));
+        (status, body).into_response()
+    }
+}
+
+async fn today<T>(State(service): SharedService<T>) -> Result<Response, ApiError>
+where
+    T: AsRef<str> + Send + Sync + 'static,
+{
+    let day = service.today().ok_or(ApiError::NotReleased)?;
+    let word = service.released(day)?;
+    Ok(Json(DayResponse { day, word }).into_response())
+}
+
+async fn day<T>(
+    State(service): SharedService<T>,
+    Path(day): Path<u64>,
+) -> Result<Response, ApiError>
+where
+    T: AsRef<str> + Send + Sync + 'static,
+{
+    let word = service.released(day)?;
+    Ok(Json(DayResponse { day, word }).into_response())
+}
+
+async fn verify<T>(
+    State(service): SharedService<T>,
+    Query(query): Query<VerifyQuery>,
+) -> Result<Json<VerifyResponse>, ApiError>
+where
+    T: AsRef<str> + Send + Sync + 'static,
+{
+    let word = service.released(query.day)?;
+    Ok(Json(VerifyResponse {
+        day: query.day,
+        correct: word == query.word,
+    }))
+}
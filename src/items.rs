@@ -0,0 +1,38 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+
+/// A list of items, either borrowed or owned by the generator.
+pub(crate) enum Items<'a, T> {
+    Borrowed(&'a [T]),
+    Owned(Vec<T>),
+}
+
+impl<'a, T: Clone> Items<'a, T> {
+    /// Converts the list into an owned list, cloning the items if borrowed.
+    pub fn into_owned(self) -> Items<'static, T> {
+        match self {
+            Items::Borrowed(items) => Items::Owned(items.to_vec()),
+            Items::Owned(items) => Items::Owned(items),
+        }
+    }
+}
+
+impl<'a, T: Clone> Clone for Items<'a, T> {
+    fn clone(&self) -> Self {
+        match self {
+            Items::Borrowed(items) => Items::Borrowed(items),
+            Items::Owned(items) => Items::Owned(items.clone()),
+        }
+    }
+}
+
+impl<'a, T> Deref for Items<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            Items::Borrowed(items) => items,
+            Items::Owned(items) => items,
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::feistel::FeistelNetwork;
+use crate::{DailyPicker, FeistelPermutation, KeyedPermutation, Seed, DEFAULT_ROUNDS};
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// An iterator over consecutive days and their items.
+///
+/// This struct is created by [`DailyPicker::iter_from`].
+pub struct Picks<'p, 'a, T, const ROUNDS: usize, P = FeistelPermutation> {
+    picker: &'p DailyPicker<'a, T, ROUNDS, P>,
+    day: Option<u64>,
+    // The permutation of the last visited window and seed
+    current: Option<(u64, Seed, P)>,
+}
+
+impl<'p, 'a, T, const ROUNDS: usize, P> Picks<'p, 'a, T, ROUNDS, P> {
+    pub(crate) fn new(picker: &'p DailyPicker<'a, T, ROUNDS, P>, day: u64) -> Self {
+        Self {
+            picker,
+            day: Some(day),
+            current: None,
+        }
+    }
+}
+
+impl<'p, 'a, T, const ROUNDS: usize, P: KeyedPermutation> Iterator for Picks<'p, 'a, T, ROUNDS, P> {
+    type Item = (u64, &'p T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let day = self.day?;
+        self.day = day.checked_add(1);
+
+        let (window, day_in_window) = self.picker.locate(day);
+        let seed = *self
+            .picker
+            .seed_at(day.wrapping_add(self.picker.day_offset));
+        let network = match &self.current {
+            Some((current, current_seed, network))
+                if *current == window && *current_seed == seed =>
+            {
+                network
+            }
+            _ => {
+                let network = self.picker.network(&seed, window);
+                &self.current.insert((window, seed, network)).2
+            }
+        };
+        let idx = self.picker.index(network, window, day_in_window);
+        Some((day, &self.picker.items[idx]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.day {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<'p, 'a, T, const ROUNDS: usize, P: KeyedPermutation> FusedIterator
+    for Picks<'p, 'a, T, ROUNDS, P>
+{
+}
+
+/// An iterator over consecutive days and their words.
+///
+/// This struct is created by [`Wordle::iter_from`](crate::Wordle::iter_from).
+pub struct Iter<'w, 'a, T, const ROUNDS: usize, P = FeistelPermutation> {
+    picks: Picks<'w, 'a, T, ROUNDS, P>,
+}
+
+impl<'w, 'a, T, const ROUNDS: usize, P> Iter<'w, 'a, T, ROUNDS, P> {
+    pub(crate) fn new(picks: Picks<'w, 'a, T, ROUNDS, P>) -> Self {
+        Self { picks }
+    }
+}
+
+impl<'w, 'a, T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation> Iterator
+    for Iter<'w, 'a, T, ROUNDS, P>
+{
+    type Item = (u64, &'w str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.picks.next().map(|(day, word)| (day, word.as_ref()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.picks.size_hint()
+    }
+}
+
+impl<'w, 'a, T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation> FusedIterator
+    for Iter<'w, 'a, T, ROUNDS, P>
+{
+}
+
+/// An iterator over the integers `0..n` in a keyed pseudorandom order.
+///
+/// The order is a permutation of `0..n` by a [`FeistelNetwork`] with cycle
+/// walking, whose round keys are derived from a secret key, as in
+/// [`FeistelNetwork::from_master_key`]. Every integer is yielded exactly once,
+/// the `i`-th integer can be looked up directly via [`ShuffledRange::get`],
+/// and the iterator holds no memory besides the network, however large `n` is.
+///
+/// The same key and length always yield the same order. See the
+/// [`feistel`](crate::feistel) module for security caveats.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::ShuffledRange;
+///
+/// let range = ShuffledRange::new(10, b"a secret key");
+/// let mut order: Vec<_> = range.clone().collect();
+/// assert_eq!(order[3], range.get(3));
+/// assert_eq!(range.position(order[3]), 3);
+/// order.sort();
+/// assert!(order.into_iter().eq(0..10));
+/// ```
+#[derive(Clone)]
+pub struct ShuffledRange {
+    network: FeistelNetwork<DEFAULT_ROUNDS>,
+    len: u64,
+    // The positions of the next integers yielded from the front and the back
+    front: u64,
+    back: u64,
+}
+
+impl ShuffledRange {
+    /// Creates an iterator over `0..n` in the order determined by `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key can't derive the round keys (see
+    /// [`FeistelNetwork::from_master_key`]), which never happens with the
+    /// default number of rounds.
+    pub fn new(n: u64, key: &[u8]) -> Self {
+        // The smallest power of two that holds every integer
+        let bit_len = (u64::BITS - n.saturating_sub(1).leading_zeros()).max(1) as u8;
+        Self {
+            network: FeistelNetwork::from_master_key(bit_len, key),
+            len: n,
+            front: 0,
+            back: n,
+        }
+    }
+
+    /// Returns the number of integers in the range, which doesn't change as
+    /// the iterator advances.
+    pub fn range_len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the integer at position `index` of the order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the length of the range.
+    pub fn get(&self, index: u64) -> u64 {
+        self.network.permute_bounded(index, self.len)
+    }
+
+    /// Returns the position of `value` in the order, which is the inverse
+    /// of [`ShuffledRange::get`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value` is not less than the length of the range.
+    pub fn position(&self, value: u64) -> u64 {
+        self.network.permute_bounded_inv(value, self.len)
+    }
+}
+
+impl Iterator for ShuffledRange {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.front == self.back {
+            return None;
+        }
+        let value = self.get(self.front);
+        self.front += 1;
+        Some(value)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<u64> {
+        let remaining = self.back - self.front;
+        match u64::try_from(n) {
+            Ok(n) if n < remaining => {
+                self.front += n;
+                self.next()
+            }
+            _ => {
+                self.front = self.back;
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.back - self.front) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl DoubleEndedIterator for ShuffledRange {
+    fn next_back(&mut self) -> Option<u64> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.get(self.back))
+    }
+}
+
+impl FusedIterator for ShuffledRange {}
+
+impl fmt::Debug for ShuffledRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The network doesn't reveal its keys
+        f.debug_struct("ShuffledRange")
+            .field("network", &self.network)
+            .field("len", &self.len)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish()
+    }
+}
@@ -0,0 +1,85 @@
+use crate::LetterFeedback;
+use alloc::collections::BTreeMap;
+
+/// The state of a letter on a keyboard, aggregated over every guess.
+///
+/// States are ordered by precedence: a letter takes the highest state it
+/// received in any guess.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LetterState {
+    /// The letter hasn't been guessed.
+    #[default]
+    Unknown,
+    /// The letter is not in the answer.
+    Absent,
+    /// The letter is in the answer, at an unknown position.
+    Present,
+    /// The letter is in the answer, at a known position.
+    Correct,
+}
+
+impl From<LetterFeedback> for LetterState {
+    fn from(feedback: LetterFeedback) -> Self {
+        match feedback {
+            LetterFeedback::Correct => LetterState::Correct,
+            LetterFeedback::Present => LetterState::Present,
+            LetterFeedback::Absent => LetterState::Absent,
+        }
+    }
+}
+
+/// The state of each letter across guesses, e.g. to render an on-screen keyboard.
+///
+/// A letter that appears more times in a guess than in the answer receives
+/// [`LetterFeedback::Absent`] for the extra occurrences, but the keyboard
+/// keeps the highest state of the letter, so it is still shown as present
+/// or correct.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{evaluate, Keyboard, LetterState};
+///
+/// let mut keyboard = Keyboard::new();
+/// let feedback: [_; 5] = evaluate("geese", "those");
+/// keyboard.update("geese", &feedback);
+/// assert_eq!(keyboard.state('g'), LetterState::Absent);
+/// assert_eq!(keyboard.state('e'), LetterState::Correct);
+/// assert_eq!(keyboard.state('t'), LetterState::Unknown);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keyboard {
+    // The letters that are not unknown
+    states: BTreeMap<char, LetterState>,
+}
+
+impl Keyboard {
+    /// Creates a keyboard in which every letter is unknown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the state of the letters of a guess, given its feedback.
+    ///
+    /// Letters and feedback are paired in order, and extra letters or
+    /// feedback are ignored.
+    pub fn update(&mut self, guess: &str, feedback: &[LetterFeedback]) {
+        for (letter, &feedback) in guess.chars().zip(feedback) {
+            let state = self.states.entry(letter).or_default();
+            *state = (*state).max(feedback.into());
+        }
+    }
+
+    /// Returns the state of the given letter.
+    pub fn state(&self, letter: char) -> LetterState {
+        self.states.get(&letter).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the guessed letters and their states, ordered
+    /// by letter.
+    pub fn iter(&self) -> impl Iterator<Item = (char, LetterState)> + '_ {
+        self.states.iter().map(|(&letter, &state)| (letter, state))
+    }
+}
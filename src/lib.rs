@@ -1,58 +1,489 @@
-mod feistel;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
-use crate::feistel::FeistelNetwork;
-use bytemuck::bytes_of_mut;
-use sha2::{Digest, Sha512};
+extern crate alloc;
 
-pub type Seed = [u8; 32];
+mod absurdle;
+mod algorithm;
+mod builder;
+mod commitment;
+mod config;
+mod dictionary;
+#[cfg(feature = "drand")]
+pub mod drand;
+mod error;
+mod feedback;
+pub mod feistel;
+mod fingerprint;
+#[cfg(feature = "fpe")]
+pub mod fpe;
+mod game;
+mod hex;
+#[cfg(feature = "http")]
+pub mod http;
+mod items;
+mod iter;
+mod keyboard;
+#[cfg(any(feature = "list-en-5", feature = "list-en-6", feature = "list-es-5"))]
+pub mod lists;
+#[cfg(feature = "mmap")]
+mod mapped;
+mod multi;
+mod normalize;
+mod permutation;
+mod picker;
+mod puzzle;
+mod seed;
+mod share;
+pub mod shuffle;
+#[cfg(feature = "solver")]
+pub mod solver;
+#[cfg(feature = "std")]
+pub mod stats;
+mod tags;
+#[cfg(feature = "test_support")]
+pub mod test_support;
+pub mod test_vectors;
+#[cfg(feature = "vrf")]
+pub mod vrf;
+mod weights;
+mod word_list;
+
+pub use crate::absurdle::Absurdle;
+pub use crate::algorithm::{Algorithm, SecurityLevel};
+pub use crate::builder::WordleBuilder;
+pub use crate::commitment::{Commitment, ParseCommitmentError};
+pub use crate::config::WordleConfig;
+pub use crate::error::WordleError;
+pub use crate::feedback::{evaluate, try_evaluate, LetterFeedback};
+pub use crate::fingerprint::{ListFingerprint, ParseFingerprintError};
+pub use crate::game::{Game, GameStatus, Guess, GuessError, DEFAULT_MAX_GUESSES};
+pub use crate::iter::{Iter, Picks, ShuffledRange};
+pub use crate::keyboard::{Keyboard, LetterState};
+#[cfg(feature = "mmap")]
+pub use crate::mapped::MappedWordList;
+pub use crate::multi::{ListSchedule, MultiWordle};
+pub use crate::normalize::{CaseFolding, Normalizer};
+pub use crate::permutation::{
+    FeistelPermutation, FisherYatesPermutation, KeyedPermutation, Permutation, PermutationKey,
+    FISHER_YATES_MAX_LEN,
+};
+pub use crate::picker::DailyPicker;
+pub use crate::puzzle::PuzzleId;
+pub use crate::seed::{ParseSeedError, Seed, SEED_LEN};
+#[cfg(feature = "passphrase")]
+pub use crate::seed::{PassphraseError, PassphraseKdf};
+pub use crate::share::{Palette, ShareGrid};
+pub use crate::tags::{TagFilter, Tags};
+pub use crate::word_list::{
+    Duplicate, DuplicatePolicy, ParseOptions, ValidationReport, Violation, ViolationKind, WordList,
+    WordListError, WordListValidator,
+};
+
+use crate::dictionary::Dictionary;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// The default number of Feistel rounds.
+///
+/// The Luby-Rackoff theorem shows that 4 rounds are enough to resist all
+/// adaptive chosen plaintext and chosen ciphertext attacks, for sufficiently
+/// large block sizes. However, we support arbitrarily small domains.
+pub const DEFAULT_ROUNDS: usize = 8;
 
 /// A cryptographically secure random wordle generator.
-pub struct Wordle<'a, T: AsRef<str>> {
-    words: &'a [T],
-    window_len: u64,
-    seed: Seed,
-    hasher: Sha512,
-    // The Luby-Rackoff theorem shows that 4 rounds are enough to resist all
-    // adaptive chosen plaintext and chosen ciphertext attacks, for sufficiently
-    // large block sizes. However, we support arbitrarily small domains.
-    network: FeistelNetwork<8>,
+///
+/// This is a [`DailyPicker`] over a list of words. The generator either
+/// borrows its list of words, or owns it if created via
+/// [`WordleBuilder::from_vec`] or [`Wordle::into_owned`].
+///
+/// The [`Debug`](core::fmt::Debug) representation of the generator doesn't
+/// reveal its seed. The generator is [`Send`] and [`Sync`] whenever its words
+/// are, so it can be shared between threads or async tasks.
+#[derive(Clone)]
+pub struct Wordle<'a, T: AsRef<str>, const ROUNDS: usize = DEFAULT_ROUNDS, P = FeistelPermutation> {
+    picker: DailyPicker<'a, T, ROUNDS, P>,
+    // The words that can be guessed, besides the answers
+    guesses: Option<Dictionary<'a, T>>,
+    // The puzzle number of day zero
+    puzzle_offset: u64,
+    // The normalizer of guesses
+    normalizer: Normalizer,
 }
 
 impl<'a, T: AsRef<str>> Wordle<'a, T> {
-    /// Creates a new generator seeded via [`rand::random`].
+    /// Creates a new generator seeded via [`Seed::generate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` is empty or `window_len` is zero.
+    #[cfg(feature = "std")]
     pub fn new(words: &'a [T], window_len: u64) -> Self {
-        Self::from_seed(words, window_len, rand::random())
+        Self::from_seed(words, window_len, Seed::generate())
     }
 
     /// Creates a new generator using a seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `words` is empty or `window_len` is zero.
     pub fn from_seed(words: &'a [T], window_len: u64, seed: Seed) -> Self {
-        Self {
-            words,
-            window_len,
-            seed,
-            hasher: Sha512::new(),
-            network: FeistelNetwork::for_domain(words.len()),
+        match Self::try_from_seed(words, window_len, seed) {
+            Ok(wordle) => wordle,
+            Err(err) => panic!("{}", err),
         }
     }
 
-    fn update_window(&mut self, window: u64) {
-        // SHA512 produces enough output for 8 64-bit round keys
-        let key_bytes = bytes_of_mut(self.network.keys_mut());
-        self.hasher.update(&self.seed);
-        self.hasher.update(&window.to_ne_bytes());
-        Digest::finalize_into_reset(&mut self.hasher, key_bytes.into());
+    /// Creates a new generator seeded via [`Seed::generate`], or returns an error
+    /// if `words` is empty or `window_len` is zero.
+    #[cfg(feature = "std")]
+    pub fn try_new(words: &'a [T], window_len: u64) -> Result<Self, WordleError> {
+        Self::try_from_seed(words, window_len, Seed::generate())
+    }
+
+    /// Creates a new generator using a seed, or returns an error if `words`
+    /// is empty or `window_len` is zero.
+    pub fn try_from_seed(words: &'a [T], window_len: u64, seed: Seed) -> Result<Self, WordleError> {
+        Self::builder(words)
+            .window_len(window_len)
+            .seed(seed)
+            .build()
+    }
+
+    /// Creates a builder to configure a new generator over `words`.
+    pub fn builder(words: &'a [T]) -> WordleBuilder<'a, T> {
+        WordleBuilder::new(words)
+    }
+}
+
+impl<'a, T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation> Wordle<'a, T, ROUNDS, P> {
+    /// Returns whether `word` can be guessed, i.e. it is either an answer or
+    /// an allowed guess.
+    ///
+    /// The word is normalized by the normalizer of the generator (see
+    /// [`WordleBuilder::normalizer`]) and then compared exactly, so the lists
+    /// should be normalized in the same way.
+    pub fn is_valid_guess(&self, word: &str) -> bool {
+        let word = &*self.normalizer.normalize(word);
+        let allowed = self.guesses.as_ref().is_some_and(|d| d.contains(word));
+        allowed || self.picker.items().iter().any(|w| w.as_ref() == word)
+    }
+
+    /// Returns the puzzle number of the given day.
+    pub fn puzzle_id(&self, day: u64) -> PuzzleId {
+        PuzzleId::new(day.wrapping_add(self.puzzle_offset))
+    }
+
+    /// Returns the day of the given puzzle number, or `None` if the puzzle
+    /// is numbered before day zero.
+    pub fn day_of_puzzle(&self, puzzle: PuzzleId) -> Option<u64> {
+        puzzle.get().checked_sub(self.puzzle_offset)
+    }
+
+    /// Returns the word for the given day, along with its puzzle number.
+    pub fn get_puzzle(&self, day: u64) -> (PuzzleId, &str) {
+        (self.puzzle_id(day), self.get(day))
+    }
+
+    /// Returns the share grid of a game of the given day, numbered by its
+    /// puzzle number.
+    pub fn share_grid<'r, const N: usize>(
+        &self,
+        day: u64,
+        rows: &'r [[LetterFeedback; N]],
+    ) -> ShareGrid<'r, N> {
+        ShareGrid::new(self.puzzle_id(day), rows)
+    }
+
+    /// Starts a game whose answer is the word of the given day.
+    pub fn new_game(&self, day: u64) -> Game {
+        Game::new(day, self.get(day)).normalizer(self.normalizer)
+    }
+
+    /// Starts an adversarial game of the given day, whose answer is narrowed
+    /// down lazily from the words that can be picked.
+    ///
+    /// See [`Absurdle`] for details.
+    pub fn absurdle(&self, day: u64) -> Absurdle<'_> {
+        let picker = &self.picker;
+        let words = picker
+            .items()
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| picker.banned.binary_search(idx).is_err())
+            .map(|(_, word)| word.as_ref())
+            .collect();
+        let seed = picker.seed_at(day.wrapping_add(picker.day_offset));
+        let mut label = b"absurdle:".to_vec();
+        label.extend_from_slice(&day.to_le_bytes());
+        Absurdle::new(words, seed.derive_child(&label))
+    }
+
+    /// Returns the underlying picker.
+    pub fn picker(&self) -> &DailyPicker<'a, T, ROUNDS, P> {
+        &self.picker
+    }
+
+    /// Returns the number of words to pick from.
+    pub fn word_count(&self) -> usize {
+        self.picker.item_count()
+    }
+
+    /// Returns the number of consecutive days in which no word repeats.
+    pub fn window_len(&self) -> u64 {
+        self.picker.window_len()
+    }
+
+    /// Returns the fingerprint of the initial seed.
+    ///
+    /// See [`Seed::fingerprint`] for details.
+    pub fn seed_fingerprint(&self) -> u64 {
+        self.picker.seed_fingerprint()
+    }
+
+    /// Returns the version of the algorithm that derives the schedule.
+    pub fn algorithm(&self) -> Algorithm {
+        self.picker.algorithm()
+    }
+
+    /// Returns the label mixed into the derivation of every permutation, if
+    /// any. See [`WordleBuilder::label`].
+    pub fn label(&self) -> Option<&[u8]> {
+        self.picker.label()
+    }
+
+    /// Returns the configuration of the generator, excluding its words.
+    ///
+    /// See [`WordleConfig`] for details.
+    pub fn config(&self) -> WordleConfig {
+        self.picker.config()
+    }
+
+    /// Returns a hash commitment to the seed, configuration and list of words.
+    ///
+    /// Vetoing a day changes the commitment. See [`Commitment`] for details.
+    pub fn commitment(&self) -> Commitment {
+        Commitment::new(&self.picker.config(), &self.list_fingerprint())
+    }
+
+    /// Returns the fingerprint of the list of words, once normalized by the
+    /// normalizer of the generator.
+    ///
+    /// See [`ListFingerprint`] for details.
+    pub fn list_fingerprint(&self) -> ListFingerprint {
+        ListFingerprint::new(self.picker.items(), &self.normalizer)
+    }
+
+    /// Returns whether `word` is the word of the given day, and the generator
+    /// matches the commitment.
+    ///
+    /// This lets anyone check a published answer once the seed is revealed,
+    /// by rebuilding the generator with the same configuration and words.
+    pub fn verify_answer(&self, day: u64, word: &str, commitment: &Commitment) -> bool {
+        self.commitment() == *commitment && self.get(day) == word
+    }
+
+    /// Converts the generator into one that owns its list of words,
+    /// cloning the words if they are borrowed.
+    pub fn into_owned(self) -> Wordle<'static, T, ROUNDS, P>
+    where
+        T: Clone,
+    {
+        Wordle {
+            picker: self.picker.into_owned(),
+            guesses: self.guesses.map(Dictionary::into_owned),
+            puzzle_offset: self.puzzle_offset,
+            normalizer: self.normalizer,
+        }
+    }
+
+    /// Returns a generator over the same words whose seed is derived from
+    /// this generator's seed and the given label.
+    ///
+    /// See [`DailyPicker::derive_child`] for details.
+    pub fn derive_child(&self, label: &[u8]) -> Wordle<'_, T, ROUNDS, P> {
+        Wordle {
+            picker: self.picker.derive_child(label),
+            guesses: self.guesses.as_ref().map(Dictionary::borrowed),
+            puzzle_offset: self.puzzle_offset,
+            normalizer: self.normalizer,
+        }
+    }
+
+    /// Returns a generator that only picks the words that match `filter`,
+    /// e.g. for themed puzzles, whose seed is derived from this generator's
+    /// seed and the filter.
+    ///
+    /// Every word can still be guessed. See [`DailyPicker::filtered`] for
+    /// details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no allowed word matches the filter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{Seed, TagFilter, Tags, Wordle};
+    ///
+    /// let words = ["horse", "apple", "zebra", "grape", "tiger"];
+    /// let mut tags = Tags::new();
+    /// for idx in [0, 2, 4] {
+    ///     tags.insert(idx, "animals");
+    /// }
+    /// let wordle = Wordle::builder(&words).seed(Seed::new([0; 32])).build().unwrap();
+    /// let animals = wordle.filtered(&tags, &TagFilter::new().all_of(["animals"])).unwrap();
+    /// assert_eq!(animals.window_len(), 3);
+    /// let mut picked = animals.get_range(0..3);
+    /// picked.sort();
+    /// assert_eq!(picked, ["horse", "tiger", "zebra"]);
+    /// ```
+    pub fn filtered(
+        &self,
+        tags: &Tags,
+        filter: &TagFilter,
+    ) -> Result<Wordle<'_, T, ROUNDS, P>, WordleError> {
+        Ok(Wordle {
+            picker: self.picker.filtered(tags, filter)?,
+            guesses: self.guesses.as_ref().map(Dictionary::borrowed),
+            puzzle_offset: self.puzzle_offset,
+            normalizer: self.normalizer,
+        })
+    }
+
+    /// Replaces the seed from the given day onwards.
+    ///
+    /// See [`DailyPicker::rotate_seed`] for details.
+    pub fn rotate_seed(&mut self, from_day: u64, seed: Seed) {
+        self.picker.rotate_seed(from_day, seed);
+    }
+
+    /// Returns the window of the given day.
+    ///
+    /// See [`DailyPicker::window_of`] for details.
+    pub fn window_of(&self, day: u64) -> u64 {
+        self.picker.window_of(day)
+    }
+
+    /// Returns the range of days in the window of the given day.
+    ///
+    /// See [`DailyPicker::window_bounds`] for details.
+    pub fn window_bounds(&self, day: u64) -> Range<u64> {
+        self.picker.window_bounds(day)
+    }
+
+    /// Returns the number of days from the given day (inclusive) until the
+    /// end of its window.
+    pub fn days_remaining_in_window(&self, day: u64) -> u64 {
+        self.picker.days_remaining_in_window(day)
     }
 
     /// Returns the word for the given day.
-    pub fn get(&mut self, day: u64) -> &'a str {
-        let window = day / self.window_len;
-        self.update_window(window);
-
-        let day = day % self.window_len;
-        let mut idx = self.network.permute(day);
-        while idx >= self.words.len() as u64 {
-            idx = self.network.permute(idx);
+    pub fn get(&self, day: u64) -> &str {
+        self.picker.get(day).as_ref()
+    }
+
+    /// Returns the word for the given day, along with its index in the list of words.
+    pub fn get_with_index(&self, day: u64) -> (usize, &str) {
+        let (idx, word) = self.picker.get_with_index(day);
+        (idx, word.as_ref())
+    }
+
+    /// Vetoes the word picked for the given day, and returns its replacement.
+    ///
+    /// See [`DailyPicker::veto`] for details.
+    pub fn veto(&mut self, day: u64) -> &str {
+        self.picker.veto(day).as_ref()
+    }
+
+    /// Returns the words of every day of the given window, in order.
+    ///
+    /// The round keys of the window are only derived once.
+    pub fn window_words(&self, window: u64) -> Vec<&str> {
+        self.picker
+            .window_items(window)
+            .into_iter()
+            .map(AsRef::as_ref)
+            .collect()
+    }
+
+    /// Returns `n` distinct words for the given day.
+    ///
+    /// See [`DailyPicker::get_many`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the number of words that can be picked.
+    /// See [`Wordle::try_get_many`] for a non-panicking variant.
+    pub fn get_many(&self, day: u64, n: usize) -> Vec<&str> {
+        match self.try_get_many(day, n) {
+            Ok(words) => words,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Returns `n` distinct words for the given day, or an error if `n` is
+    /// greater than the number of words that can be picked.
+    pub fn try_get_many(&self, day: u64, n: usize) -> Result<Vec<&str>, WordleError> {
+        let words = self.picker.try_get_many(day, n)?;
+        Ok(words.into_iter().map(AsRef::as_ref).collect())
+    }
+
+    /// Returns the first day of the given window on which `word` is picked,
+    /// or `None` if the word is not picked during the window.
+    ///
+    /// The returned day can be passed to [`Wordle::get`] to obtain `word`.
+    pub fn day_of(&self, word: &str, window: u64) -> Option<u64> {
+        let indices = self
+            .picker
+            .items()
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| candidate.as_ref() == word)
+            .map(|(idx, _)| idx);
+        self.picker.first_day_of(indices, window)
+    }
+
+    /// Returns an iterator over the days starting at `day`, and their words.
+    ///
+    /// The round keys of each window are only derived once, which makes this
+    /// more efficient than calling [`Wordle::get`] for consecutive days.
+    pub fn iter_from(&self, day: u64) -> Iter<'_, 'a, T, ROUNDS, P> {
+        Iter::new(self.picker.iter_from(day))
+    }
+
+    /// Returns the words for every day in the given range.
+    ///
+    /// The round keys of each window are only derived once.
+    pub fn get_range(&self, days: Range<u64>) -> Vec<&str> {
+        self.picker
+            .get_range(days)
+            .into_iter()
+            .map(AsRef::as_ref)
+            .collect()
+    }
+}
+
+impl<'a, T: AsRef<str>, const ROUNDS: usize, P> From<DailyPicker<'a, T, ROUNDS, P>>
+    for Wordle<'a, T, ROUNDS, P>
+{
+    fn from(picker: DailyPicker<'a, T, ROUNDS, P>) -> Self {
+        Self {
+            picker,
+            guesses: None,
+            puzzle_offset: 0,
+            normalizer: Normalizer::new(),
         }
-        self.words[idx as usize].as_ref()
+    }
+}
+
+impl<'a, T: AsRef<str>, const ROUNDS: usize, P> fmt::Debug for Wordle<'a, T, ROUNDS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wordle")
+            .field("picker", &self.picker)
+            .field("guess_count", &self.guesses.as_ref().map(Dictionary::len))
+            .field("puzzle_offset", &self.puzzle_offset)
+            .field("normalizer", &self.normalizer)
+            .finish()
     }
 }
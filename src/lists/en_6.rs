@@ -0,0 +1,100 @@
+/// 865 common six-letter English words, in alphabetical order.
+pub const EN_6: &[&str] = &[
+    "ablaze", "absent", "absorb", "accent", "accept", "access", "accuse", "across", "acting",
+    "action", "active", "actual", "adjust", "admire", "adverb", "advice", "advise", "affair",
+    "afford", "afraid", "agency", "agenda", "almond", "almost", "always", "amount", "anchor",
+    "angler", "animal", "annual", "answer", "antler", "anyone", "anyway", "appeal", "appear",
+    "arcade", "archer", "armpit", "around", "arrest", "arrive", "artist", "asleep", "aspect",
+    "assess", "assist", "assume", "attach", "attack", "attend", "august", "author", "autumn",
+    "avenue", "badger", "bakery", "bamboo", "banana", "banner", "barely", "barley", "barrel",
+    "basket", "battle", "beacon", "beauty", "beaver", "became", "become", "beetle", "before",
+    "behalf", "behave", "behind", "belief", "bellow", "belong", "beside", "better", "beyond",
+    "biceps", "binder", "bishop", "bitter", "blazer", "blonde", "blouse", "bobcat", "bonnet",
+    "border", "borrow", "bother", "bottle", "bottom", "bought", "bounce", "branch", "breach",
+    "breath", "breeze", "bridge", "bridle", "bright", "broken", "broker", "bronze", "brunch",
+    "bubble", "bucket", "budget", "buffet", "bumper", "bundle", "burden", "bureau", "burger",
+    "butler", "butter", "button", "cactus", "camera", "campus", "canary", "candle", "cannon",
+    "canopy", "canvas", "carbon", "career", "carpet", "carrot", "cashew", "casino", "castle",
+    "casual", "cattle", "caught", "cavity", "celery", "cellar", "cement", "center", "cereal",
+    "chance", "change", "chapel", "charge", "cheeky", "cheese", "cherry", "chilly", "choice",
+    "chorus", "chosen", "church", "cinema", "circle", "citrus", "clergy", "clever", "client",
+    "closed", "closer", "cobalt", "cocoon", "coffee", "collar", "collie", "colony", "column",
+    "combat", "comedy", "common", "condor", "cookie", "copper", "corner", "corset", "cotton",
+    "county", "couple", "course", "cousin", "cradle", "crayon", "crease", "create", "credit",
+    "crisis", "critic", "cruise", "crunch", "cuddle", "curfew", "cursor", "custom", "dainty",
+    "damage", "dancer", "danger", "daring", "dazzle", "debate", "decade", "decide", "defeat",
+    "defend", "define", "degree", "deluxe", "demand", "denial", "dental", "depend", "deputy",
+    "desert", "design", "desire", "detail", "detect", "detour", "device", "devote", "diesel",
+    "dimple", "dinner", "dipper", "direct", "divide", "doctor", "dollar", "domain", "donate",
+    "donkey", "doodle", "double", "dragon", "drawer", "driven", "driver", "during", "easily",
+    "eating", "editor", "effect", "effort", "eighty", "either", "elapse", "eleven", "embark",
+    "emblem", "emerge", "empire", "employ", "enable", "enamel", "ending", "endure", "energy",
+    "engage", "engine", "enigma", "enough", "ensure", "entire", "entity", "equity", "errand",
+    "escape", "estate", "ethnic", "evolve", "exceed", "except", "excuse", "exotic", "expand",
+    "expect", "expert", "export", "expose", "extend", "extent", "fabric", "facing", "factor",
+    "fairly", "falcon", "fallen", "family", "famous", "farmer", "faster", "father", "fathom",
+    "fellow", "female", "fennel", "ferret", "fiddle", "fierce", "figure", "fillet", "filter",
+    "finger", "finish", "fiscal", "fleece", "flight", "flinch", "floppy", "flower", "fluffy",
+    "flying", "fodder", "follow", "forage", "forest", "forget", "formal", "format", "former",
+    "fossil", "foster", "fourth", "freeze", "fridge", "friend", "frozen", "frugal", "fumble",
+    "funnel", "future", "gadget", "galaxy", "gallon", "gambit", "garage", "garden", "gargle",
+    "garlic", "gather", "gazebo", "gender", "gentle", "geyser", "giggle", "ginger", "glance",
+    "glider", "global", "goblet", "goblin", "golden", "gopher", "gospel", "govern", "gravel",
+    "growth", "grumpy", "guitar", "gutter", "hamlet", "hammer", "handle", "happen", "harbor",
+    "hardly", "hazard", "health", "heaven", "height", "helmet", "hermit", "hiccup", "hidden",
+    "hockey", "holder", "honest", "hoodie", "hornet", "humble", "hunger", "hunter", "hurdle",
+    "ignore", "iguana", "impact", "impala", "import", "impose", "income", "indeed", "indigo",
+    "infant", "inform", "injury", "insect", "inside", "insist", "intend", "invent", "invest",
+    "island", "itself", "jacket", "jaguar", "jersey", "jigsaw", "jostle", "jovial", "juggle",
+    "jungle", "junior", "kernel", "kettle", "kidney", "kimono", "kindle", "kitten", "knight",
+    "ladder", "lagoon", "laptop", "lather", "latter", "launch", "lawyer", "leader", "league",
+    "legend", "legume", "length", "lentil", "lesson", "letter", "likely", "linger", "liquid",
+    "listen", "litter", "little", "lively", "living", "lizard", "locate", "locket", "locust",
+    "lonely", "lovely", "lumber", "luxury", "magnet", "magpie", "making", "mallet", "manage",
+    "manner", "mantle", "marble", "margin", "marina", "market", "mascot", "master", "matter",
+    "meadow", "meddle", "medium", "mellow", "member", "memory", "menace", "mental", "merely",
+    "meteor", "method", "midday", "middle", "mingle", "minnow", "minute", "mirror", "mitten",
+    "mobile", "modern", "modest", "molten", "moment", "monkey", "mosaic", "mostly", "mother",
+    "motion", "motive", "muffin", "mumble", "muscle", "museum", "mussel", "mutton", "mutual",
+    "myself", "napkin", "narrow", "nation", "native", "nature", "nearby", "nearly", "nectar",
+    "needle", "nephew", "nettle", "nibble", "nickel", "nobody", "noodle", "normal", "notice",
+    "notion", "nugget", "number", "object", "oblong", "obtain", "occupy", "ocelot", "octave",
+    "office", "online", "option", "orange", "origin", "outfit", "output", "oxygen", "oyster",
+    "paddle", "pagoda", "palace", "pantry", "papaya", "parade", "pardon", "parent", "parrot",
+    "partly", "pastel", "pebble", "pellet", "pencil", "pepper", "period", "permit", "person",
+    "pewter", "phrase", "pickle", "pigeon", "pillow", "plague", "planet", "plasma", "player",
+    "please", "pledge", "plenty", "pocket", "poetry", "police", "policy", "polish", "pollen",
+    "poodle", "portal", "potato", "powder", "prefer", "pretty", "priest", "prince", "prison",
+    "profit", "proper", "public", "puffin", "pulley", "puppet", "purple", "pursue", "puzzle",
+    "quartz", "quiver", "rabbit", "racing", "radish", "radius", "raisin", "ramble", "random",
+    "rarely", "rather", "rating", "rattle", "reader", "really", "reason", "recall", "recent",
+    "recess", "recipe", "record", "reduce", "reflex", "reform", "refuse", "regard", "region",
+    "relate", "relief", "remain", "remedy", "remind", "remote", "remove", "repair", "repeat",
+    "replay", "report", "rescue", "resist", "resort", "result", "retail", "retain", "retire",
+    "return", "reveal", "review", "reward", "rhythm", "ribbon", "riddle", "riding", "ripple",
+    "rising", "ritual", "robust", "rocket", "rodent", "rookie", "rotten", "rubber", "rumble",
+    "rustic", "saddle", "safety", "salami", "salmon", "salute", "sample", "sandal", "saying",
+    "scarce", "scenic", "scheme", "school", "screen", "script", "scroll", "search", "season",
+    "second", "secret", "sector", "secure", "select", "seller", "senior", "sequel", "sermon",
+    "sesame", "settle", "severe", "shadow", "shield", "should", "shower", "shrimp", "sickle",
+    "signal", "silent", "silver", "simple", "single", "siphon", "sister", "sizzle", "skater",
+    "sketch", "sleigh", "slight", "slogan", "sluice", "smooth", "snazzy", "soccer", "social",
+    "socket", "sorbet", "sphere", "spider", "spirit", "spoken", "sponge", "spread", "spring",
+    "sprout", "square", "squash", "squint", "stable", "stanza", "staple", "statue", "status",
+    "steady", "stench", "sticky", "strain", "strand", "stream", "street", "stress", "strict",
+    "strike", "string", "stripe", "stroke", "strong", "stucco", "studio", "submit", "suburb",
+    "sudden", "suffer", "sulfur", "summer", "summit", "summon", "sunset", "superb", "supply",
+    "surely", "survey", "swerve", "switch", "symbol", "syntax", "system", "tablet", "tackle",
+    "talent", "tandem", "target", "tattoo", "teapot", "temple", "tenant", "tender", "tennis",
+    "thanks", "theory", "thirty", "thorny", "thread", "threat", "thrill", "throat", "throne",
+    "thwart", "ticket", "timber", "tinsel", "toddle", "toffee", "tomato", "tomcat", "tongue",
+    "topple", "toward", "travel", "treaty", "trivia", "trophy", "tumble", "tundra", "tunnel",
+    "turkey", "turnip", "turtle", "tuxedo", "twelve", "twenty", "tycoon", "umpire", "unique",
+    "unless", "unlike", "update", "uproar", "upshot", "urchin", "useful", "valley", "velvet",
+    "vendor", "verbal", "versus", "vessel", "victim", "violet", "violin", "vision", "visual",
+    "volume", "vortex", "voyage", "waffle", "waiter", "wallet", "walnut", "walrus", "wander",
+    "warmth", "wealth", "weapon", "weasel", "weekly", "weight", "wheeze", "wicked", "wiggle",
+    "window", "winner", "winter", "wisdom", "within", "wizard", "wobble", "wombat", "wonder",
+    "wooden", "worker", "worthy", "writer", "yellow", "yogurt", "zenith", "zigzag", "zipper",
+    "zombie",
+];
@@ -0,0 +1,58 @@
+/// 513 common five-letter Spanish words, in alphabetical order.
+///
+/// Words are written without accents (e.g. `arbol` for *árbol*), as is usual
+/// in Spanish word games, but `ñ` is a letter of its own.
+pub const ES_5: &[&str] = &[
+    "abajo", "abeja", "abril", "abrir", "acero", "actor", "adios", "agudo", "aguja", "ahora",
+    "ajeno", "alado", "alamo", "album", "aldea", "algas", "alias", "almas", "altar", "altos",
+    "alzar", "amado", "amigo", "ancho", "angel", "anima", "animo", "antes", "apodo", "arbol",
+    "arena", "argot", "armas", "arroz", "asado", "asilo", "aspas", "astro", "atajo", "atlas",
+    "atomo", "avena", "aviso", "ayuda", "azote", "bahia", "baile", "bajar", "balas", "balon",
+    "banco", "banda", "barba", "barco", "barro", "bases", "beber", "bello", "besar", "bicho",
+    "bingo", "blusa", "bocas", "bolsa", "bomba", "borde", "botas", "bravo", "brazo", "breve",
+    "brisa", "broma", "bruja", "buena", "bueno", "burla", "cable", "cabra", "cacao", "caida",
+    "cajas", "calle", "calma", "calor", "campo", "canal", "canoa", "canto", "capaz", "cargo",
+    "carne", "carta", "casas", "casco", "causa", "cazar", "cebra", "cejas", "celda", "cenar",
+    "censo", "cerca", "cerdo", "cerro", "chico", "ciclo", "cielo", "cinco", "circo", "cisne",
+    "claro", "clase", "clave", "clima", "cobre", "coche", "cofre", "colar", "color", "comer",
+    "copas", "coral", "corte", "corto", "cosas", "costa", "crema", "cruce", "crudo", "cuero",
+    "cueva", "cuida", "culpa", "curso", "dados", "danza", "datos", "deber", "decir", "dedos",
+    "delta", "denso", "deseo", "dicha", "dieta", "dolor", "dosis", "drama", "ducha", "duelo",
+    "dueño", "dulce", "dunas", "durar", "echar", "ellos", "enano", "enero", "envio", "epica",
+    "error", "etapa", "exito", "facil", "falda", "falso", "falta", "fango", "fauna", "feliz",
+    "feria", "fibra", "ficha", "fiera", "filas", "final", "firma", "flaco", "flora", "flota",
+    "fondo", "forma", "frase", "freno", "fresa", "fruta", "fuego", "fuera", "fumar", "funda",
+    "furia", "gafas", "galgo", "gallo", "ganar", "garra", "gasto", "genio", "gente", "globo",
+    "golpe", "gordo", "gorra", "gotas", "grado", "grano", "grasa", "grave", "grito", "grupo",
+    "guapo", "guiar", "gusto", "habla", "hacer", "hacia", "hecho", "helar", "heroe", "hielo",
+    "hijos", "hilos", "hogar", "hojas", "horno", "hotel", "huevo", "humor", "ideal", "idear",
+    "igual", "islas", "jabon", "jamas", "jamon", "jarra", "jaula", "joven", "joyas", "juego",
+    "jueza", "jugar", "julio", "junio", "junto", "jurar", "justo", "labio", "lados", "lagos",
+    "lapiz", "largo", "latas", "lavar", "lazos", "leche", "lecho", "legal", "lejos", "lento",
+    "letra", "libra", "libre", "libro", "lider", "limon", "lindo", "linea", "listo", "llama",
+    "llave", "lleno", "local", "lucha", "luego", "lugar", "lunes", "madre", "magia", "mango",
+    "mania", "manos", "manta", "marca", "marco", "mares", "marzo", "matar", "mayor", "media",
+    "medio", "mejor", "menor", "menos", "mente", "mesas", "metal", "metro", "miedo", "miles",
+    "minas", "mirar", "mismo", "mitad", "moler", "monte", "moral", "morir", "motor", "mover",
+    "mucho", "muela", "mujer", "mundo", "museo", "musgo", "nacer", "nadar", "nariz", "naves",
+    "necio", "negro", "nieve", "niños", "noche", "norte", "notas", "novia", "nuevo", "nunca",
+    "obras", "oeste", "oliva", "ollas", "ondas", "opera", "orden", "oreja", "osado", "otoño",
+    "padre", "pagar", "palco", "palma", "panal", "papel", "parar", "pared", "parte", "pasar",
+    "paseo", "pasta", "patio", "pausa", "pavor", "pecho", "pedir", "peine", "pelea", "perla",
+    "perro", "pesca", "pieza", "pilas", "pinar", "pista", "plata", "playa", "plaza", "plazo",
+    "plomo", "pluma", "pobre", "poder", "poema", "poeta", "polvo", "poner", "porta", "prado",
+    "prisa", "prosa", "pudor", "pulpo", "punta", "punto", "queso", "quien", "radio", "rampa",
+    "rango", "rasgo", "ratos", "rayos", "razon", "recto", "regla", "reina", "reloj", "renta",
+    "resto", "reyes", "rezar", "riego", "risas", "ritmo", "robar", "roble", "rocas", "rodar",
+    "rollo", "rombo", "ronda", "rosas", "rubio", "rueda", "ruido", "rumbo", "rural", "sabio",
+    "sabor", "sacar", "salir", "salsa", "salto", "salud", "santo", "selva", "senda", "señal",
+    "serie", "siete", "siglo", "signo", "silla", "sitio", "sobre", "socio", "solar", "soles",
+    "sonar", "soñar", "sopas", "suave", "subir", "sucio", "sudor", "suelo", "sueño", "surco",
+    "tabla", "talla", "tanto", "tapas", "tarde", "tarea", "techo", "tejer", "telas", "temor",
+    "tenaz", "tener", "tenis", "terco", "tigre", "tinta", "tipos", "tirar", "tocar", "todos",
+    "tomar", "toros", "torre", "total", "trago", "traje", "trama", "trato", "trece", "tribu",
+    "trigo", "tropa", "trozo", "tubos", "tumba", "turno", "union", "untar", "usual", "vacas",
+    "vagon", "valer", "valle", "vapor", "vasos", "veloz", "venta", "verde", "vidas", "viejo",
+    "vigor", "virus", "visto", "vivir", "volar", "votar", "yegua", "yerba", "zanja", "zarpa",
+    "zonas", "zorro", "zumos",
+];
@@ -0,0 +1,37 @@
+//! Curated word lists, compiled in behind features.
+//!
+//! | Feature     | List     | Words                     |
+//! |-------------|----------|---------------------------|
+//! | `list-en-5` | [`EN_5`] | Five-letter English words |
+//! | `list-en-6` | [`EN_6`] | Six-letter English words  |
+//! | `list-es-5` | [`ES_5`] | Five-letter Spanish words |
+//!
+//! Every list is lowercase, in alphabetical order, and has no duplicates. Changing a list
+//! changes every schedule generated from it, so the lists never change within
+//! a major version.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "list-en-5")]
+//! # {
+//! use wordle_generator::{lists, Seed, Wordle};
+//!
+//! let wordle = Wordle::builder(lists::EN_5).seed(Seed::new([0; 32])).build().unwrap();
+//! assert!(lists::EN_5.contains(&wordle.get(0)));
+//! # }
+//! ```
+
+#[cfg(feature = "list-en-5")]
+mod en_5;
+#[cfg(feature = "list-en-6")]
+mod en_6;
+#[cfg(feature = "list-es-5")]
+mod es_5;
+
+#[cfg(feature = "list-en-5")]
+pub use self::en_5::EN_5;
+#[cfg(feature = "list-en-6")]
+pub use self::en_6::EN_6;
+#[cfg(feature = "list-es-5")]
+pub use self::es_5::ES_5;
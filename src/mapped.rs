@@ -0,0 +1,140 @@
+use crate::word_list::parse_words;
+use crate::{ParseOptions, WordListError};
+use memmap2::Mmap;
+use std::fmt;
+use std::fs::File;
+use std::path::Path;
+
+/// A word list backed by a memory-mapped file, for lists that are too large
+/// to load into memory, such as the items of a generic picker.
+///
+/// The file has the format of a [`WordList`](crate::WordList), and is only
+/// read when it is opened and when its words are indexed. The words are never
+/// copied: [`MappedWordList::words`] returns an index of slices of the
+/// mapping, which takes 16 bytes per word on 64-bit platforms, so the operating
+/// system loads the pages of the file as words are picked, and can evict them
+/// afterwards.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{MappedWordList, Seed, Wordle};
+///
+/// # let dir = std::env::temp_dir().join("wordle-generator-mapped-doc");
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("items.txt");
+/// std::fs::write(&path, "apple\ngrape\nlemon\n").unwrap();
+///
+/// // SAFETY: the file is not modified while it is mapped
+/// let list = unsafe { MappedWordList::open(&path) }.unwrap();
+/// let words = list.words();
+/// assert_eq!(words, ["apple", "grape", "lemon"]);
+/// let wordle = Wordle::builder(&words).seed(Seed::new([0; 32])).build().unwrap();
+/// assert!(words.contains(&wordle.get(0)));
+/// ```
+pub struct MappedWordList {
+    map: Mmap,
+    options: ParseOptions,
+    len: usize,
+    // The weight of each word, if the list is weighted
+    weights: Option<Vec<u32>>,
+}
+
+impl MappedWordList {
+    /// Maps the file at `path`, and checks that it is a valid list with the
+    /// default options.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, e.g.
+    /// by another process, which is undefined behavior. See [`Mmap::map`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be mapped, or if it is not a valid
+    /// list.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> Result<Self, WordListError> {
+        // SAFETY: guaranteed by the caller
+        unsafe { Self::open_with(path, &ParseOptions::new()) }
+    }
+
+    /// Maps the file at `path`, and checks that it is a valid list with the
+    /// given options.
+    ///
+    /// The words are never normalized or deduplicated, since they aren't
+    /// copied, so the normalizer and the duplicate policy of the options are
+    /// ignored. Normalize and deduplicate the file beforehand instead.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, e.g.
+    /// by another process, which is undefined behavior. See [`Mmap::map`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can't be mapped, or if it is not a valid
+    /// list.
+    pub unsafe fn open_with<P: AsRef<Path>>(
+        path: P,
+        options: &ParseOptions,
+    ) -> Result<Self, WordListError> {
+        let file = File::open(path).map_err(WordListError::Io)?;
+        // SAFETY: guaranteed by the caller
+        let map = unsafe { Mmap::map(&file) }.map_err(WordListError::Io)?;
+        let mut len = 0;
+        let mut weights = Vec::new();
+        parse_words(&map, options, |_, _, weight| {
+            len += 1;
+            weights.extend(weight);
+        })?;
+        let weights = match weights.is_empty() {
+            true => None,
+            false => Some(weights),
+        };
+        Ok(Self {
+            map,
+            options: options.clone(),
+            len,
+            weights,
+        })
+    }
+
+    /// Returns the number of words.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the list has no words, which is never the case.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns an index of the words, which borrows them from the mapping.
+    ///
+    /// This reads the whole file, so the index should be kept for as long as
+    /// the words are picked, e.g. by passing it to
+    /// [`Wordle::builder`](crate::Wordle::builder).
+    pub fn words(&self) -> Vec<&str> {
+        let mut words = Vec::with_capacity(self.len);
+        // The list was checked when it was mapped
+        parse_words(&self.map, &self.options, |_, word, _| words.push(word)).unwrap();
+        words
+    }
+
+    /// Returns the weight of each word, or `None` if the list has no weights.
+    ///
+    /// See [`WordleBuilder::weights`](crate::WordleBuilder::weights).
+    pub fn weights(&self) -> Option<&[u32]> {
+        self.weights.as_deref()
+    }
+}
+
+impl fmt::Debug for MappedWordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedWordList")
+            .field("file_len", &self.map.len())
+            .field("len", &self.len)
+            .field("weighted", &self.weights.is_some())
+            .finish()
+    }
+}
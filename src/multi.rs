@@ -0,0 +1,172 @@
+use crate::{
+    FeistelPermutation, KeyedPermutation, Seed, ShuffledRange, Wordle, WordleError, DEFAULT_ROUNDS,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+/// How a [`MultiWordle`] chooses the list of each day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum ListSchedule {
+    /// Draws every day of each window of `window_len` days from the same list.
+    ///
+    /// Windows are grouped in turns of one window per list, so every list is
+    /// used once per turn, in an order determined by the seed.
+    Windows {
+        /// The number of consecutive days drawn from the same list.
+        window_len: u64,
+    },
+    /// Draws each day from the list at the index of its weekday, where index
+    /// 0 is the weekday of day 0.
+    ///
+    /// For example, if day 0 is a Thursday (as the Unix epoch), index 2 is
+    /// the list of Saturdays.
+    Weekdays([usize; 7]),
+}
+
+/// A generator that holds several lists, and draws each day from one of them.
+///
+/// Each list has its own generator, and the [`ListSchedule`] determines the
+/// list of each day. The generator of a list is only consulted on the days
+/// drawn from it, which it numbers consecutively: e.g. if a list is only used
+/// on Saturdays, its day `n` is the `n`-th Saturday. So every list goes through
+/// its own windows without gaps, and no word repeats within a window of its
+/// list.
+///
+/// The lists should have different seeds, e.g. derived from a single seed via
+/// [`Seed::derive_child`], so that lists with the same words pick them in a
+/// different order.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{ListSchedule, MultiWordle, Seed, Wordle};
+///
+/// let seed = Seed::new([0; 32]);
+/// let easy = ["apple", "grape", "lemon", "mango", "peach"];
+/// let hard = ["fjord", "glyph", "nymph", "crypt"];
+/// let lists = vec![
+///     Wordle::from_seed(&easy, 5, seed.derive_child(b"easy")),
+///     Wordle::from_seed(&hard, 4, seed.derive_child(b"hard")),
+/// ];
+/// // Day 0 is a Thursday, so Saturdays are at index 2
+/// let schedule = ListSchedule::Weekdays([0, 0, 1, 0, 0, 0, 0]);
+/// let wordle = MultiWordle::new(seed, lists, schedule).unwrap();
+/// assert!(hard.contains(&wordle.get(2)));
+/// assert!(hard.contains(&wordle.get(9)));
+/// assert!(easy.contains(&wordle.get(3)));
+/// assert_eq!(wordle.locate(9), (1, 1));
+/// ```
+#[derive(Clone)]
+pub struct MultiWordle<
+    'a,
+    T: AsRef<str>,
+    const ROUNDS: usize = DEFAULT_ROUNDS,
+    P = FeistelPermutation,
+> {
+    lists: Vec<Wordle<'a, T, ROUNDS, P>>,
+    schedule: ListSchedule,
+    // Determines the order of the lists in each turn of windows
+    seed: Seed,
+}
+
+impl<'a, T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation> MultiWordle<'a, T, ROUNDS, P> {
+    /// Creates a generator that draws each day from one of the `lists`, as
+    /// determined by the schedule and the seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no lists, the window length is zero, or
+    /// a weekday refers to a list that doesn't exist.
+    pub fn new(
+        seed: Seed,
+        lists: Vec<Wordle<'a, T, ROUNDS, P>>,
+        schedule: ListSchedule,
+    ) -> Result<Self, WordleError> {
+        if lists.is_empty() {
+            return Err(WordleError::NoLists);
+        }
+        match schedule {
+            ListSchedule::Windows { window_len: 0 } => return Err(WordleError::ZeroWindowLen),
+            ListSchedule::Windows { .. } => {}
+            ListSchedule::Weekdays(weekdays) => {
+                if let Some(&list) = weekdays.iter().find(|&&list| list >= lists.len()) {
+                    return Err(WordleError::ListOutOfBounds(list));
+                }
+            }
+        }
+        Ok(Self {
+            lists,
+            schedule,
+            seed,
+        })
+    }
+
+    /// Returns the generators of the lists.
+    pub fn lists(&self) -> &[Wordle<'a, T, ROUNDS, P>] {
+        &self.lists
+    }
+
+    /// Returns the schedule that determines the list of each day.
+    pub fn schedule(&self) -> ListSchedule {
+        self.schedule
+    }
+
+    /// Returns the index of the list of the given day, and the day passed to
+    /// the generator of that list.
+    pub fn locate(&self, day: u64) -> (usize, u64) {
+        match self.schedule {
+            ListSchedule::Windows { window_len } => {
+                let count = self.lists.len() as u64;
+                let (window, pos) = (day / window_len, day % window_len);
+                let (turn, slot) = (window / count, window % count);
+                let label = [
+                    b"wordle-generator list order:".as_slice(),
+                    &turn.to_le_bytes(),
+                ];
+                let key = self.seed.derive_child(&label.concat());
+                let list = ShuffledRange::new(count, key.as_bytes()).get(slot);
+                (list as usize, turn * window_len + pos)
+            }
+            ListSchedule::Weekdays(weekdays) => {
+                let weekday = (day % 7) as usize;
+                let list = weekdays[weekday];
+                let per_week = weekdays.iter().filter(|&&l| l == list).count() as u64;
+                let earlier = weekdays[..weekday].iter().filter(|&&l| l == list).count();
+                (list, day / 7 * per_week + earlier as u64)
+            }
+        }
+    }
+
+    /// Returns the word for the given day.
+    pub fn get(&self, day: u64) -> &str {
+        self.get_with_list(day).1
+    }
+
+    /// Returns the word for the given day, along with the index of its list.
+    pub fn get_with_list(&self, day: u64) -> (usize, &str) {
+        let (list, list_day) = self.locate(day);
+        (list, self.lists[list].get(list_day))
+    }
+
+    /// Returns the words for the given range of days.
+    pub fn get_range(&self, days: Range<u64>) -> Vec<&str> {
+        days.map(|day| self.get(day)).collect()
+    }
+}
+
+impl<'a, T: AsRef<str>, const ROUNDS: usize, P> fmt::Debug for MultiWordle<'a, T, ROUNDS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The seed is redacted
+        f.debug_struct("MultiWordle")
+            .field("lists", &self.lists)
+            .field("schedule", &self.schedule)
+            .field(
+                "seed_fingerprint",
+                &format_args!("{:016x}", self.seed.fingerprint()),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+use core::iter;
+#[cfg(feature = "unicode")]
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+/// How a [`Normalizer`] folds the case of letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[non_exhaustive]
+pub enum CaseFolding {
+    /// Keeps the case of letters.
+    #[default]
+    Preserve,
+    /// Lowercases letters as defined by Unicode, e.g. `É` to `é`, and `Σ` to
+    /// `ς` at the end of a word.
+    Lowercase,
+    /// Lowercases letters as in Turkish and Azerbaijani, where `I` is the
+    /// uppercase of the dotless `ı`, and `İ` the uppercase of `i`.
+    Turkic,
+}
+
+/// Normalizes words, so that words that look the same compare equal.
+///
+/// Unicode can encode accented letters in several ways, e.g. `é` either as a
+/// single scalar value or as an `e` followed by a combining acute accent. A
+/// list and a guess that use different encodings don't match, and the letters
+/// of the guess are evaluated as different letters. With the `unicode` feature,
+/// the normalizer can convert words to Normalization Form C (NFC), which
+/// composes letters and accents whenever possible.
+///
+/// The normalizer doesn't change words by default. Words should be normalized
+/// in the same way as the lists they are compared against, so the same
+/// normalizer should be used for both (see [`WordList::normalize`] and
+/// [`WordleBuilder::normalizer`]).
+///
+/// [`WordList::normalize`]: crate::WordList::normalize
+/// [`WordleBuilder::normalizer`]: crate::WordleBuilder::normalizer
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{CaseFolding, Normalizer};
+///
+/// let normalizer = Normalizer::new().case_folding(CaseFolding::Turkic);
+/// assert_eq!(normalizer.normalize("IŞIK"), "ışık");
+/// assert_eq!(normalizer.normalize("İNCİR"), "incir");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Normalizer {
+    #[cfg(feature = "unicode")]
+    #[cfg_attr(feature = "serde", serde(default))]
+    nfc: bool,
+    case_folding: CaseFolding,
+}
+
+impl Normalizer {
+    /// Creates a normalizer that doesn't change words.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether words are converted to Normalization Form C.
+    #[cfg(feature = "unicode")]
+    pub fn nfc(mut self, nfc: bool) -> Self {
+        self.nfc = nfc;
+        self
+    }
+
+    /// Sets how the case of letters is folded, which defaults to
+    /// [`CaseFolding::Preserve`].
+    pub fn case_folding(mut self, case_folding: CaseFolding) -> Self {
+        self.case_folding = case_folding;
+        self
+    }
+
+    /// Returns the normalized form of `word`, which borrows `word` if it is
+    /// already normalized.
+    ///
+    /// Normalizing a normalized word doesn't change it. Note that normalized
+    /// words can have a different number of letters, e.g. if `İ` is lowercased
+    /// by [`CaseFolding::Lowercase`] to `i` followed by a combining dot.
+    pub fn normalize<'w>(&self, word: &'w str) -> Cow<'w, str> {
+        let word = self.compose(Cow::Borrowed(word));
+        let folded = match self.case_folding {
+            CaseFolding::Preserve => return word,
+            CaseFolding::Lowercase => {
+                if word.chars().all(|c| c.to_lowercase().eq(iter::once(c))) {
+                    return word;
+                }
+                word.to_lowercase()
+            }
+            CaseFolding::Turkic => {
+                if word
+                    .chars()
+                    .all(|c| c != 'I' && c.to_lowercase().eq(iter::once(c)))
+                {
+                    return word;
+                }
+                let dotless: String = word
+                    .chars()
+                    .map(|c| match c {
+                        'I' => 'ı',
+                        'İ' => 'i',
+                        c => c,
+                    })
+                    .collect();
+                dotless.to_lowercase()
+            }
+        };
+        // Lowercasing can decompose letters
+        self.compose(Cow::Owned(folded))
+    }
+
+    #[cfg(feature = "unicode")]
+    fn compose<'w>(&self, word: Cow<'w, str>) -> Cow<'w, str> {
+        if !self.nfc || is_nfc_quick(word.chars()) == IsNormalized::Yes {
+            return word;
+        }
+        Cow::Owned(word.nfc().collect())
+    }
+
+    #[cfg(not(feature = "unicode"))]
+    fn compose<'w>(&self, word: Cow<'w, str>) -> Cow<'w, str> {
+        word
+    }
+}
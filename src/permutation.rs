@@ -0,0 +1,453 @@
+use crate::algorithm::AlgorithmRound;
+use crate::feistel::{
+    DynFeistelNetwork, HalfSipHashRound, HmacSha256Round, RoundPolicy, SipHashRound, WyHashRound,
+};
+use crate::seed::wipe_flat;
+use crate::{Algorithm, Seed};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use bytemuck::cast_slice_mut;
+use core::fmt;
+use sha2::{Digest, Sha512};
+
+/// A bijection of the integers `0..domain_len` onto themselves.
+///
+/// A [`DailyPicker`](crate::DailyPicker) maps the days of each window to
+/// distinct items via a permutation, which is a [`FeistelPermutation`] by
+/// default. [`FisherYatesPermutation`] shuffles tiny lists exactly instead.
+/// Pickers derive their permutations via [`KeyedPermutation`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::Permutation;
+///
+/// /// Reverses the order of `0..n`.
+/// struct Reverse(u64);
+///
+/// impl Permutation for Reverse {
+///     fn permute(&self, input: u64) -> u64 {
+///         self.0 - 1 - input
+///     }
+///
+///     fn permute_inv(&self, output: u64) -> u64 {
+///         self.permute(output)
+///     }
+///
+///     fn domain_len(&self) -> u128 {
+///         self.0.into()
+///     }
+/// }
+///
+/// let reverse = Reverse(5);
+/// assert_eq!(reverse.permute(1), 3);
+/// assert!(reverse.contains(4));
+/// ```
+pub trait Permutation {
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// The result is unspecified if `input` is outside the domain.
+    fn permute(&self, input: u64) -> u64;
+
+    /// Returns the input that [`Permutation::permute`] maps to `output`.
+    ///
+    /// The result is unspecified if `output` is outside the domain.
+    fn permute_inv(&self, output: u64) -> u64;
+
+    /// Returns the number of permuted integers, which is `2^64` for
+    /// permutations of every `u64`.
+    fn domain_len(&self) -> u128;
+
+    /// Returns whether `input` belongs to the permuted domain.
+    fn contains(&self, input: u64) -> bool {
+        u128::from(input) < self.domain_len()
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// which is found by cycle walking, in time independent of the input and
+    /// the output if supported.
+    ///
+    /// The default implementation cycle walks, so it takes time proportional
+    /// to the number of steps. Pickers call this instead of walking when
+    /// [`WordleBuilder::constant_time`](crate::WordleBuilder::constant_time)
+    /// is enabled.
+    ///
+    /// The result is unspecified if `input` is not less than `domain_len`.
+    fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+        permute_bounded(self, input, domain_len)
+    }
+}
+
+/// Returns the output of the permutation of `0..domain_len` for `input`,
+/// which is found by cycle walking.
+pub(crate) fn permute_bounded<P: Permutation + ?Sized>(
+    permutation: &P,
+    input: u64,
+    domain_len: u64,
+) -> u64 {
+    let mut value = permutation.permute(input);
+    while value >= domain_len {
+        value = permutation.permute(value);
+    }
+    value
+}
+
+/// A [`Permutation`] that a [`DailyPicker`](crate::DailyPicker) derives from
+/// its seed.
+///
+/// Implementing this trait allows swapping the permutation used by a picker,
+/// e.g. for an alternative cipher, or a predictable permutation in tests. See
+/// [`WordleBuilder::permutation`](crate::WordleBuilder::permutation).
+pub trait KeyedPermutation: Permutation + Sized {
+    /// Creates a permutation whose domain contains `0..domain_len`, keyed by
+    /// the given key.
+    ///
+    /// A larger domain is allowed, which the picker handles via cycle walking.
+    /// Windowless pickers request a domain of `2^64` integers.
+    fn from_key(domain_len: u128, key: &PermutationKey<'_>) -> Self;
+}
+
+/// The key material of a permutation, derived from the seed of a picker and
+/// the context of the permutation (e.g. the window).
+///
+/// The [`Debug`](core::fmt::Debug) representation doesn't reveal the seed.
+pub struct PermutationKey<'k> {
+    seed: &'k Seed,
+    context: &'k [&'k [u8]],
+    algorithm: Algorithm,
+    rounds: usize,
+    round_policy: Option<RoundPolicy>,
+    whitening: bool,
+}
+
+impl<'k> PermutationKey<'k> {
+    pub(crate) fn new(
+        seed: &'k Seed,
+        context: &'k [&'k [u8]],
+        algorithm: Algorithm,
+        rounds: usize,
+        round_policy: Option<RoundPolicy>,
+        whitening: bool,
+    ) -> Self {
+        Self {
+            seed,
+            context,
+            algorithm,
+            rounds,
+            round_policy,
+            whitening,
+        }
+    }
+
+    /// Returns the version of the algorithm of the picker.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the number of rounds of the picker.
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns the policy that chooses the minimum number of rounds of each
+    /// permutation, if any. See [`WordleBuilder::round_policy`](crate::WordleBuilder::round_policy).
+    pub fn round_policy(&self) -> Option<RoundPolicy> {
+        self.round_policy
+    }
+
+    /// Returns whether the picker whitens the input and output of its
+    /// permutations. See [`WordleBuilder::whitening`](crate::WordleBuilder::whitening).
+    pub fn whitening(&self) -> bool {
+        self.whitening
+    }
+
+    /// Fills `bytes` with key material.
+    ///
+    /// The bytes are SHA-512 digests of the seed and the context. SHA512
+    /// produces 64 bytes, and additional bytes are derived from subsequent
+    /// blocks, domain-separated by a counter.
+    pub fn fill_bytes(&self, bytes: &mut [u8]) {
+        for (block, chunk) in bytes.chunks_mut(64).enumerate() {
+            let mut hasher = self.hasher();
+            if block > 0 {
+                hasher.update(self.algorithm.encode_u64(block as u64));
+            }
+            let mut digest = hasher.finalize_reset();
+            chunk.copy_from_slice(&digest[..chunk.len()]);
+            // The buffer of the hasher still contains the seed
+            // SAFETY: the hasher state and the digest only hold integer
+            // arrays, and aren't used again
+            unsafe {
+                wipe_flat(&mut hasher);
+                wipe_flat(&mut digest);
+            }
+        }
+    }
+
+    /// Returns a key derived from the seed and the context, and
+    /// domain-separated from other keys by `label`.
+    pub fn shared_key(&self, label: &[u8]) -> [u8; 32] {
+        let mut hasher = self.hasher();
+        hasher.update(label);
+        let mut digest = hasher.finalize_reset();
+        let key = digest[..32].try_into().unwrap();
+        // SAFETY: the hasher state and the digest only hold integer arrays,
+        // and aren't used again
+        unsafe {
+            wipe_flat(&mut hasher);
+            wipe_flat(&mut digest);
+        }
+        key
+    }
+
+    fn hasher(&self) -> Sha512 {
+        let mut hasher = Sha512::new();
+        hasher.update(self.seed.as_bytes());
+        for part in self.context {
+            hasher.update(part);
+        }
+        hasher
+    }
+}
+
+impl fmt::Debug for PermutationKey<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PermutationKey")
+            .field("algorithm", &self.algorithm)
+            .field("rounds", &self.rounds)
+            .field("round_policy", &self.round_policy)
+            .field("whitening", &self.whitening)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The default permutation of a picker: a Feistel network with cycle
+/// walking, keyed and configured as specified by the [`Algorithm`].
+#[derive(Clone)]
+pub struct FeistelPermutation {
+    network: DynFeistelNetwork<AlgorithmRound>,
+}
+
+impl KeyedPermutation for FeistelPermutation {
+    fn from_key(domain_len: u128, key: &PermutationKey<'_>) -> Self {
+        let algorithm = key.algorithm();
+        assert!(domain_len > 0, "domain cannot be empty");
+        // Computed from the full domain, so every platform gets the same
+        // network regardless of the width of usize
+        let bit_len = (u128::BITS - domain_len.leading_zeros()).min(u64::BITS) as u8;
+        let bit_len = match algorithm.is_balanced() {
+            true => bit_len + bit_len % 2,
+            false => bit_len,
+        };
+        let round_function = match algorithm {
+            Algorithm::V4 => {
+                let mut shared = key.shared_key(b"siphash");
+                let round = SipHashRound::new(u64::from_le_bytes(shared[..8].try_into().unwrap()));
+                // SAFETY: the key is a byte array, and isn't used again
+                unsafe { wipe_flat(&mut shared) };
+                AlgorithmRound::SipHash(round)
+            }
+            Algorithm::V5 => {
+                let mut shared = key.shared_key(b"hmac-sha256");
+                let round = HmacSha256Round::new(shared);
+                // SAFETY: the key is a byte array, and isn't used again
+                unsafe { wipe_flat(&mut shared) };
+                AlgorithmRound::HmacSha256(round)
+            }
+            Algorithm::V6 => AlgorithmRound::HalfSipHash(HalfSipHashRound),
+            _ => {
+                let native_endian = algorithm.is_native_endian();
+                AlgorithmRound::WyHash(WyHashRound::with_native_endian(native_endian))
+            }
+        };
+        let rounds = match key.round_policy() {
+            Some(policy) => key.rounds().max(policy.rounds(bit_len)),
+            None => key.rounds(),
+        };
+        let mut network = DynFeistelNetwork::zeroed(bit_len, rounds, round_function);
+        key.fill_bytes(cast_slice_mut::<u64, u8>(network.keys_mut()));
+        if !algorithm.is_native_endian() {
+            for key in network.keys_mut() {
+                *key = u64::from_le(*key);
+            }
+        }
+        if key.whitening() {
+            let mut shared = key.shared_key(b"whitening");
+            let pre = u64::from_le_bytes(shared[..8].try_into().unwrap());
+            let post = u64::from_le_bytes(shared[8..16].try_into().unwrap());
+            network.set_whitening(pre, post);
+            // SAFETY: the key is a byte array, and isn't used again
+            unsafe { wipe_flat(&mut shared) };
+        }
+        Self { network }
+    }
+}
+
+impl FeistelPermutation {
+    /// Returns the underlying Feistel network.
+    pub(crate) fn network(&self) -> &DynFeistelNetwork<AlgorithmRound> {
+        &self.network
+    }
+}
+
+impl Permutation for FeistelPermutation {
+    fn permute(&self, input: u64) -> u64 {
+        self.network.permute(input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        self.network.permute_inv(output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.network.domain_len()
+    }
+
+    fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+        self.network
+            .permute_bounded_constant_time(input, domain_len)
+    }
+}
+
+impl fmt::Debug for FeistelPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FeistelPermutation")
+            .field("bit_len", &self.network.bit_len())
+            .field("rounds", &self.network.rounds())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The largest domain that a [`FisherYatesPermutation`] shuffles.
+pub const FISHER_YATES_MAX_LEN: u128 = 512;
+
+/// A permutation that shuffles tiny domains with a Fisher–Yates shuffle, and
+/// falls back to a [`FeistelPermutation`] for larger domains.
+///
+/// Domains of up to [`FISHER_YATES_MAX_LEN`] integers are shuffled into a
+/// table, which is (up to a negligible bias) a uniformly random permutation,
+/// unlike a Feistel network. The table isn't cached: the picker shuffles the
+/// whole domain whenever it derives a permutation, which is once per window
+/// when iterating (see [`Wordle::iter_from`](crate::Wordle::iter_from)), but
+/// once per day via [`Wordle::get`](crate::Wordle::get). Larger domains, and
+/// windowless pickers, use the same permutation as [`FeistelPermutation`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{FisherYatesPermutation, Seed, Wordle};
+///
+/// let words = ["apple", "grape", "lemon", "mango", "peach"];
+/// let wordle = Wordle::builder(&words)
+///     .seed(Seed::new([7; 32]))
+///     .window_len(5)
+///     .permutation::<FisherYatesPermutation>()
+///     .build()
+///     .unwrap();
+/// let mut window = wordle.window_words(0);
+/// window.sort();
+/// assert_eq!(window, words);
+/// ```
+#[derive(Clone)]
+pub struct FisherYatesPermutation {
+    shuffle: Shuffle,
+}
+
+#[derive(Clone)]
+enum Shuffle {
+    // The output of every input, and the input of every output
+    Table { outputs: Vec<u32>, inputs: Vec<u32> },
+    Feistel(Box<FeistelPermutation>),
+}
+
+impl KeyedPermutation for FisherYatesPermutation {
+    fn from_key(domain_len: u128, key: &PermutationKey<'_>) -> Self {
+        if domain_len > FISHER_YATES_MAX_LEN {
+            let feistel = FeistelPermutation::from_key(domain_len, key);
+            return Self {
+                shuffle: Shuffle::Feistel(Box::new(feistel)),
+            };
+        }
+        let len = domain_len as usize;
+        // One random integer per swap
+        let mut random = vec![0u64; len];
+        key.fill_bytes(cast_slice_mut::<u64, u8>(&mut random));
+        let mut outputs: Vec<u32> = (0..len as u32).collect();
+        for i in (1..len).rev() {
+            let random = u64::from_le(random[i]);
+            // An integer in 0..=i, with a bias of at most 2^-55
+            let j = ((u128::from(random) * (i as u128 + 1)) >> 64) as usize;
+            outputs.swap(i, j);
+        }
+        // SAFETY: the random integers aren't used again
+        random
+            .iter_mut()
+            .for_each(|random| unsafe { wipe_flat(random) });
+        let mut inputs = vec![0; len];
+        for (input, &output) in outputs.iter().enumerate() {
+            inputs[output as usize] = input as u32;
+        }
+        Self {
+            shuffle: Shuffle::Table { outputs, inputs },
+        }
+    }
+}
+
+impl Permutation for FisherYatesPermutation {
+    fn permute(&self, input: u64) -> u64 {
+        match &self.shuffle {
+            Shuffle::Table { outputs, .. } => outputs[input as usize].into(),
+            Shuffle::Feistel(feistel) => feistel.permute(input),
+        }
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        match &self.shuffle {
+            Shuffle::Table { inputs, .. } => inputs[output as usize].into(),
+            Shuffle::Feistel(feistel) => feistel.permute_inv(output),
+        }
+    }
+
+    fn domain_len(&self) -> u128 {
+        match &self.shuffle {
+            Shuffle::Table { outputs, .. } => outputs.len() as u128,
+            Shuffle::Feistel(feistel) => feistel.domain_len(),
+        }
+    }
+
+    fn permute_bounded_constant_time(&self, input: u64, domain_len: u64) -> u64 {
+        match &self.shuffle {
+            // Lookups in the table aren't constant-time
+            Shuffle::Table { .. } => permute_bounded(self, input, domain_len),
+            Shuffle::Feistel(feistel) => feistel.permute_bounded_constant_time(input, domain_len),
+        }
+    }
+}
+
+impl fmt::Debug for FisherYatesPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.shuffle {
+            Shuffle::Table { outputs, .. } => f
+                .debug_struct("FisherYatesPermutation")
+                .field("domain_len", &outputs.len())
+                .finish_non_exhaustive(),
+            Shuffle::Feistel(feistel) => f
+                .debug_tuple("FisherYatesPermutation")
+                .field(feistel)
+                .finish(),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for FisherYatesPermutation {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        if let Shuffle::Table { outputs, inputs } = &mut self.shuffle {
+            outputs.as_mut_slice().zeroize();
+            inputs.as_mut_slice().zeroize();
+        }
+    }
+}
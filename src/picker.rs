@@ -0,0 +1,980 @@
+use crate::feistel::RoundPolicy;
+use crate::items::Items;
+use crate::iter::Picks;
+use crate::permutation::permute_bounded;
+use crate::weights::Weights;
+use crate::{
+    Algorithm, FeistelPermutation, KeyedPermutation, PermutationKey, Seed, TagFilter, Tags,
+    WordleBuilder, WordleConfig, WordleError, DEFAULT_ROUNDS,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::Range;
+
+/// A cryptographically secure random picker of daily items.
+///
+/// Days are grouped into windows of consecutive days. Within a window, no
+/// item is picked twice (unless the window is longer than the list of items).
+///
+/// Picking only requires a shared reference and holds no hasher state between
+/// calls, so a picker is [`Send`] and [`Sync`] whenever its items are, and can
+/// be shared between threads (e.g. in an [`Arc`](alloc::sync::Arc)).
+///
+/// With the `zeroize` feature, the seeds and the round keys derived from them
+/// are wiped from memory once they are dropped. Seeds are [`Copy`], so copies
+/// held elsewhere (e.g. by the caller) must be wiped separately.
+#[derive(Clone)]
+pub struct DailyPicker<'a, T, const ROUNDS: usize = DEFAULT_ROUNDS, P = FeistelPermutation> {
+    pub(crate) items: Items<'a, T>,
+    pub(crate) window_len: u64,
+    pub(crate) seed: Seed,
+    pub(crate) day_offset: u64,
+    // The sorted indices of the items that are never picked
+    pub(crate) banned: Vec<usize>,
+    // The number of times the item of each (offset) day was vetoed
+    pub(crate) vetoes: BTreeMap<u64, u32>,
+    pub(crate) weights: Option<Weights>,
+    pub(crate) repeat_gap: u64,
+    // The (offset) days from which the seed is replaced, sorted by day
+    pub(crate) rotations: Vec<(u64, Seed)>,
+    // Whether days are permuted over the full 64-bit space, with windows of one day
+    pub(crate) windowless: bool,
+    pub(crate) algorithm: Algorithm,
+    // The label mixed into the derivation of every permutation, if any
+    pub(crate) label: Option<Vec<u8>>,
+    // The number of Feistel rounds, which is `ROUNDS` unless set at runtime
+    pub(crate) rounds: usize,
+    // The pinned size of the domain of the permutations of items, if any
+    pub(crate) capacity: Option<u64>,
+    // The policy that chooses the minimum number of rounds, if any
+    pub(crate) round_policy: Option<RoundPolicy>,
+    // Whether the input and output of the permutations are whitened
+    pub(crate) whitening: bool,
+    // Whether cycles are walked in constant time
+    pub(crate) constant_time: bool,
+    // The (offset) days from which the items from each index are picked, sorted by day
+    pub(crate) appends: Vec<(u64, usize)>,
+    // The permutations are derived on demand
+    pub(crate) permutation: PhantomData<fn() -> P>,
+}
+
+impl<'a, T> DailyPicker<'a, T> {
+    /// Creates a new picker seeded via [`Seed::generate`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` is empty or `window_len` is zero.
+    #[cfg(feature = "std")]
+    pub fn new(items: &'a [T], window_len: u64) -> Self {
+        Self::from_seed(items, window_len, Seed::generate())
+    }
+
+    /// Creates a new picker using a seed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` is empty or `window_len` is zero.
+    pub fn from_seed(items: &'a [T], window_len: u64, seed: Seed) -> Self {
+        match Self::try_from_seed(items, window_len, seed) {
+            Ok(picker) => picker,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Creates a new picker seeded via [`Seed::generate`], or returns an error
+    /// if `items` is empty or `window_len` is zero.
+    #[cfg(feature = "std")]
+    pub fn try_new(items: &'a [T], window_len: u64) -> Result<Self, WordleError> {
+        Self::try_from_seed(items, window_len, Seed::generate())
+    }
+
+    /// Creates a new picker using a seed, or returns an error if `items`
+    /// is empty or `window_len` is zero.
+    pub fn try_from_seed(items: &'a [T], window_len: u64, seed: Seed) -> Result<Self, WordleError> {
+        Self::builder(items)
+            .window_len(window_len)
+            .seed(seed)
+            .build_picker()
+    }
+
+    /// Creates a builder to configure a new picker over `items`.
+    pub fn builder(items: &'a [T]) -> WordleBuilder<'a, T> {
+        WordleBuilder::new(items)
+    }
+}
+
+impl<'a, T, const ROUNDS: usize, P: KeyedPermutation> DailyPicker<'a, T, ROUNDS, P> {
+    /// Returns the permutation of the days of the given window.
+    ///
+    /// The permutation is derived on each call, so the picker doesn't need
+    /// to hold any mutable state.
+    ///
+    /// In windowless mode, every window uses the same permutation, which
+    /// permutes the full 64-bit space of days.
+    pub(crate) fn network(&self, seed: &Seed, window: u64) -> P {
+        if self.windowless {
+            return self.key_network(1 << 64, seed, &[b"windowless"]);
+        }
+        self.derive_network(seed, &[&self.algorithm.encode_u64(window)])
+    }
+
+    /// Returns a permutation of the items keyed by the seed and the given context.
+    fn derive_network(&self, seed: &Seed, context: &[&[u8]]) -> P {
+        let domain_len = match (&self.weights, self.capacity) {
+            (_, Some(capacity)) => capacity,
+            (Some(weights), None) => weights.total(),
+            (None, None) => self.items.len() as u64,
+        };
+        self.key_network(domain_len.into(), seed, context)
+    }
+
+    /// Returns a permutation of the given domain, keyed by the seed and the
+    /// given context.
+    fn key_network(&self, domain_len: u128, seed: &Seed, context: &[&[u8]]) -> P {
+        let label_len;
+        let labelled: Vec<&[u8]>;
+        let context = match &self.label {
+            Some(label) => {
+                // The label is length-prefixed, so it can't be confused with
+                // the rest of the context
+                label_len = (label.len() as u64).to_le_bytes();
+                let prefix: [&[u8]; 3] = [b"wordle-generator label:", &label_len, label];
+                labelled = prefix.into_iter().chain(context.iter().copied()).collect();
+                &labelled
+            }
+            None => context,
+        };
+        let key = PermutationKey::new(
+            seed,
+            context,
+            self.algorithm,
+            self.rounds,
+            self.round_policy,
+            self.whitening,
+        );
+        P::from_key(domain_len, &key)
+    }
+
+    /// Converts the picker into one that owns its list of items,
+    /// cloning the items if they are borrowed.
+    pub fn into_owned(mut self) -> DailyPicker<'static, T, ROUNDS, P>
+    where
+        T: Clone,
+    {
+        // Fields are taken since the picker may implement Drop
+        DailyPicker {
+            items: mem::replace(&mut self.items, Items::Borrowed(&[])).into_owned(),
+            window_len: self.window_len,
+            seed: self.seed,
+            day_offset: self.day_offset,
+            banned: mem::take(&mut self.banned),
+            vetoes: mem::take(&mut self.vetoes),
+            weights: self.weights.take(),
+            repeat_gap: self.repeat_gap,
+            rotations: mem::take(&mut self.rotations),
+            windowless: self.windowless,
+            algorithm: self.algorithm,
+            label: self.label.take(),
+            rounds: self.rounds,
+            capacity: self.capacity,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            constant_time: self.constant_time,
+            appends: mem::take(&mut self.appends),
+            permutation: PhantomData,
+        }
+    }
+
+    /// Returns a picker over the same items whose seed is derived from this
+    /// picker's seed and the given label.
+    ///
+    /// This allows e.g. personalized daily items for each user or room, all
+    /// controlled by a single seed. The child has the same configuration,
+    /// except for vetoes. See [`Seed::derive_child`] for details.
+    pub fn derive_child(&self, label: &[u8]) -> DailyPicker<'_, T, ROUNDS, P> {
+        DailyPicker {
+            items: Items::Borrowed(&self.items),
+            window_len: self.window_len,
+            seed: self.seed.derive_child(label),
+            day_offset: self.day_offset,
+            banned: self.banned.clone(),
+            vetoes: BTreeMap::new(),
+            weights: self.weights.clone(),
+            repeat_gap: self.repeat_gap,
+            rotations: self
+                .rotations
+                .iter()
+                .map(|(day, seed)| (*day, seed.derive_child(label)))
+                .collect(),
+            windowless: self.windowless,
+            algorithm: self.algorithm,
+            label: self.label.clone(),
+            rounds: self.rounds,
+            capacity: self.capacity,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            constant_time: self.constant_time,
+            appends: self.appends.clone(),
+            permutation: PhantomData,
+        }
+    }
+
+    /// Returns a picker that only picks the items that match `filter`, whose
+    /// seed is derived from this picker's seed and the filter.
+    ///
+    /// The child has the same configuration, except for vetoes, and has the
+    /// same schedule for the same seed, filter and matching items, regardless
+    /// of the tags of other items. Its windows are shortened to the number of
+    /// matching items, so no item repeats within a window, and its repeat gap
+    /// is shortened accordingly. See [`DailyPicker::derive_child`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no allowed item matches the filter.
+    pub fn filtered(
+        &self,
+        tags: &Tags,
+        filter: &TagFilter,
+    ) -> Result<DailyPicker<'_, T, ROUNDS, P>, WordleError> {
+        let mut child = self.derive_child(&filter.label());
+        let unmatched = (0..self.items.len()).filter(|&idx| !filter.matches(tags, idx));
+        child.banned.extend(unmatched);
+        child.banned.sort_unstable();
+        child.banned.dedup();
+        if child.banned.len() == child.items.len() {
+            return Err(WordleError::AllBanned);
+        }
+        if !child.windowless {
+            child.window_len = child.window_len.min(child.allowed_len() as u64);
+            child.repeat_gap = child.repeat_gap.min(child.window_len / 3);
+        }
+        Ok(child)
+    }
+
+    /// Replaces the seed from the given day onwards.
+    ///
+    /// The items of earlier days don't change, so past schedules are preserved.
+    /// Rotations from later days are discarded. Rotating the seed in the middle
+    /// of a window can cause items to repeat within that window.
+    pub fn rotate_seed(&mut self, from_day: u64, seed: Seed) {
+        let from_day = from_day.wrapping_add(self.day_offset);
+        self.rotations.retain(|&(day, _)| day < from_day);
+        self.rotations.push((from_day, seed));
+    }
+
+    /// Returns the seed used on the given (offset) day.
+    pub(crate) fn seed_at(&self, day: u64) -> &Seed {
+        let idx = self.rotations.partition_point(|&(from, _)| from <= day);
+        match idx {
+            0 => &self.seed,
+            _ => &self.rotations[idx - 1].1,
+        }
+    }
+
+    /// Returns the seeds used in the given window, along with the positions
+    /// within the window of the days on which they are used.
+    fn window_seeds(&self, window: u64) -> Vec<(Range<u64>, &Seed)> {
+        let start = window.saturating_mul(self.window_len);
+        let end = start.saturating_add(self.window_len);
+        let mut segments = Vec::new();
+        let mut pos = 0;
+        let mut seed = self.seed_at(start);
+        for (day, next) in &self.rotations {
+            if (start.saturating_add(1)..end).contains(day) {
+                segments.push((pos..day - start, seed));
+                pos = day - start;
+                seed = next;
+            }
+        }
+        segments.push((pos..self.window_len, seed));
+        segments
+    }
+
+    /// Returns the list of items to pick from.
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Returns the number of items to pick from.
+    pub fn item_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns the number of consecutive days in which no item repeats.
+    ///
+    /// This is `1` in windowless mode.
+    pub fn window_len(&self) -> u64 {
+        self.window_len
+    }
+
+    /// Returns whether the picker is in windowless mode.
+    ///
+    /// See [`WordleBuilder::windowless`] for details.
+    pub fn is_windowless(&self) -> bool {
+        self.windowless
+    }
+
+    /// Returns the version of the algorithm that derives the schedule.
+    pub fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
+    /// Returns the policy that chooses the minimum number of rounds of each
+    /// permutation, if any. See [`WordleBuilder::round_policy`].
+    pub fn round_policy(&self) -> Option<RoundPolicy> {
+        self.round_policy
+    }
+
+    /// Returns whether the input and output of the permutations are whitened.
+    /// See [`WordleBuilder::whitening`].
+    pub fn is_whitened(&self) -> bool {
+        self.whitening
+    }
+
+    /// Returns whether the cycles of the permutations are walked in constant
+    /// time. See [`WordleBuilder::constant_time`].
+    pub fn is_constant_time(&self) -> bool {
+        self.constant_time
+    }
+
+    /// Returns the label mixed into the derivation of every permutation, if
+    /// any. See [`WordleBuilder::label`].
+    pub fn label(&self) -> Option<&[u8]> {
+        self.label.as_deref()
+    }
+
+    /// Returns the configuration of the picker, excluding its items.
+    ///
+    /// See [`WordleConfig`] for details.
+    pub fn config(&self) -> WordleConfig {
+        let offset = self.day_offset;
+        WordleConfig {
+            seed: self.seed,
+            window_len: self.window_len,
+            day_offset: offset,
+            algorithm: self.algorithm,
+            label: self.label.clone(),
+            rounds: self.rounds,
+            windowless: self.windowless,
+            banned: self.banned.clone(),
+            weights: self.weights.as_ref().map(Weights::to_vec),
+            repeat_gap: self.repeat_gap,
+            rotations: self
+                .rotations
+                .iter()
+                .map(|&(day, seed)| (day.wrapping_sub(offset), seed))
+                .collect(),
+            vetoes: self
+                .vetoes
+                .iter()
+                .map(|(&day, &count)| (day.wrapping_sub(offset), count))
+                .collect(),
+            capacity: self.capacity,
+            round_policy: self.round_policy,
+            whitening: self.whitening,
+            appends: self
+                .appends
+                .iter()
+                .map(|&(day, first)| (day.wrapping_sub(offset), first))
+                .collect(),
+        }
+    }
+
+    /// Returns the pinned size of the domain of the permutations, if any.
+    ///
+    /// See [`WordleBuilder::capacity`] for details.
+    pub fn capacity(&self) -> Option<u64> {
+        self.capacity
+    }
+
+    /// Returns the offset added to every day before looking up its item.
+    pub fn day_offset(&self) -> u64 {
+        self.day_offset
+    }
+
+    /// Returns the fingerprint of the initial seed.
+    ///
+    /// See [`Seed::fingerprint`] for details.
+    pub fn seed_fingerprint(&self) -> u64 {
+        self.seed.fingerprint()
+    }
+
+    /// Returns the window of the given day, and the position of the day within it.
+    pub(crate) fn locate(&self, day: u64) -> (u64, u64) {
+        let day = day.wrapping_add(self.day_offset);
+        (day / self.window_len, day % self.window_len)
+    }
+
+    /// Returns the window of the given day.
+    ///
+    /// The permutation of items changes at the start of every window.
+    pub fn window_of(&self, day: u64) -> u64 {
+        self.locate(day).0
+    }
+
+    /// Returns the range of days in the window of the given day.
+    ///
+    /// The range is truncated if the window contains days that can't be
+    /// represented, e.g. before day `0` due to the day offset.
+    pub fn window_bounds(&self, day: u64) -> Range<u64> {
+        let (_, pos) = self.locate(day);
+        day.saturating_sub(pos)..day.saturating_add(self.window_len - pos)
+    }
+
+    /// Returns the number of days from the given day (inclusive) until the
+    /// end of its window.
+    pub fn days_remaining_in_window(&self, day: u64) -> u64 {
+        self.window_len - self.locate(day).1
+    }
+
+    /// Returns the items that can be picked during the given window.
+    ///
+    /// Items appended from a later window are left out. Since the domain of
+    /// the permutations is pinned, the window picks the same items as it did
+    /// before they were appended.
+    fn pool(&self, window: u64) -> Pool {
+        let len = self
+            .appends
+            .iter()
+            .filter(|&&(day, _)| day.div_ceil(self.window_len) > window)
+            .map(|&(_, first)| first)
+            .fold(self.items.len(), usize::min);
+        let banned = self.banned.partition_point(|&idx| idx < len);
+        Pool {
+            len,
+            allowed_len: len - banned,
+            total: self
+                .weights
+                .as_ref()
+                .map_or(0, |weights| weights.total_of(len)),
+        }
+    }
+
+    /// Returns the index of the item picked for the given day of a window.
+    pub(crate) fn index(&self, network: &P, window: u64, day: u64) -> usize {
+        let pool = self.pool(window);
+        if self.windowless {
+            // Every window has a single day
+            return self.index_windowless(network, pool, window);
+        }
+        if !self.vetoes.is_empty() {
+            let day = window * self.window_len + day;
+            if let Some(idx) = self.replacement(network, pool, day) {
+                return idx;
+            }
+        }
+        let pos = self.reorder(network, pool, window, day);
+        self.pick(network, pool, pos, self.window_len)
+    }
+
+    /// Returns the index of the item picked for the given (offset) day in
+    /// windowless mode.
+    ///
+    /// The day is permuted over the full 64-bit space, and the result is
+    /// reduced modulo the number of items (or the total weight). Banned items
+    /// are skipped by continuing the permutation walk, and the `n`-th veto of
+    /// a day skips the first `n` distinct items of the walk.
+    fn index_windowless(&self, network: &P, pool: Pool, day: u64) -> usize {
+        let mut skipped = Vec::new();
+        let skips = self.vetoes.get(&day).copied().unwrap_or(0) as usize;
+        let mut value = day;
+        loop {
+            value = network.permute(value);
+            let idx = match &self.weights {
+                Some(weights) => weights.item(value % pool.total),
+                None => (value % pool.len as u64) as usize,
+            };
+            if self.is_banned(idx as u64) || skipped.contains(&idx) {
+                continue;
+            }
+            if skipped.len() == skips {
+                return idx;
+            }
+            skipped.push(idx);
+            if skipped.len() == pool.allowed_len {
+                // Every item was vetoed, start over
+                skipped.clear();
+            }
+        }
+    }
+
+    /// Returns the position whose item is picked on the given day of a window.
+    ///
+    /// If the first days of the window pick an item that was picked on the
+    /// last `repeat_gap` days of the previous window, their items are swapped
+    /// with those of the first suitable days past the first `repeat_gap` days.
+    /// The last days of a window are never swapped, so this doesn't depend on
+    /// any window but the previous one.
+    fn reorder(&self, network: &P, pool: Pool, window: u64, pos: u64) -> u64 {
+        let gap = self.repeat_gap;
+        let len = self.window_len;
+        if gap == 0 || window == 0 || pos >= len - gap {
+            return pos;
+        }
+        let prev_start = (window - 1) * len;
+        let mut prev_network: Option<(&Seed, P)> = None;
+        let recent: BTreeSet<_> = (len - gap..len)
+            .map(|prev_pos| {
+                let seed = self.seed_at(prev_start + prev_pos);
+                let prev_network = match &prev_network {
+                    Some((current, network)) if *current == seed => network,
+                    _ => {
+                        &prev_network
+                            .insert((seed, self.network(seed, window - 1)))
+                            .1
+                    }
+                };
+                self.index(prev_network, window - 1, prev_pos)
+            })
+            .collect();
+        let mut partners =
+            (gap..len - gap).filter(|&q| !recent.contains(&self.pick(network, pool, q, len)));
+        for head in 0..gap {
+            if !recent.contains(&self.pick(network, pool, head, len)) {
+                continue;
+            }
+            let partner = match partners.next() {
+                Some(partner) => partner,
+                None => break,
+            };
+            if head == pos {
+                return partner;
+            } else if partner == pos {
+                return head;
+            }
+        }
+        pos
+    }
+
+    /// Returns the index of the item that replaces the vetoed item of the
+    /// given (offset) day, or `None` if the day has no vetoes.
+    ///
+    /// Each day has its own permutation of replacement candidates. Candidates
+    /// that are picked on other days of the window, or that replace earlier
+    /// vetoed days of the window, are skipped. The `n`-th veto of a day
+    /// picks the `n`-th remaining candidate.
+    fn replacement(&self, network: &P, pool: Pool, day: u64) -> Option<usize> {
+        self.vetoes.get(&day)?;
+        let window_start = day - day % self.window_len;
+        let allowed_len = pool.allowed_len as u64;
+        let mut taken = BTreeSet::new();
+        for (&vetoed_day, &count) in self.vetoes.range(window_start..=day) {
+            let seed = self.seed_at(vetoed_day);
+            let candidates =
+                self.derive_network(seed, &[b"veto", &self.algorithm.encode_u64(vetoed_day)]);
+            let nth = (count - 1) as usize;
+            let idx = (0..allowed_len)
+                .map(|pos| self.pick(&candidates, pool, pos, allowed_len))
+                .filter(|idx| !taken.contains(idx))
+                .filter(|&idx| self.unpick(network, pool, idx, self.window_len).is_empty())
+                .nth(nth)
+                .unwrap_or_else(|| {
+                    // Every item is picked during the window, so repeats are unavoidable.
+                    self.pick(&candidates, pool, count as u64 % allowed_len, allowed_len)
+                });
+            if vetoed_day == day {
+                return Some(idx);
+            }
+            taken.insert(idx);
+        }
+        unreachable!()
+    }
+
+    /// Vetoes the item picked for the given day, and returns its replacement.
+    ///
+    /// The replacement is derived from the seed and the number of times the
+    /// day was vetoed, so it is deterministic. Vetoing a day doesn't change
+    /// the items of any other day, except for days of the same window that
+    /// were vetoed afterwards.
+    pub fn veto(&mut self, day: u64) -> &T {
+        let day = day.wrapping_add(self.day_offset);
+        *self.vetoes.entry(day).or_insert(0) += 1;
+        let (window, pos) = (day / self.window_len, day % self.window_len);
+        let network = self.network(self.seed_at(day), window);
+        &self.items[self.index(&network, window, pos)]
+    }
+
+    /// Returns whether the item at index `idx` is never picked.
+    fn is_banned(&self, idx: u64) -> bool {
+        self.banned.binary_search(&(idx as usize)).is_ok()
+    }
+
+    /// Returns the number of items that can be picked.
+    fn allowed_len(&self) -> usize {
+        self.items.len() - self.banned.len()
+    }
+
+    /// Returns the index picked at position `pos` of a sequence of `seq_len`
+    /// distinct picks.
+    ///
+    /// Cycle walking over the allowed indices gives a permutation of them.
+    /// Positions that are themselves banned indices are remapped to unused
+    /// allowed positions past the end of the sequence, so the picks stay
+    /// distinct. Positions whose walk doesn't visit a banned index pick the
+    /// same item as if no items were banned.
+    fn pick(&self, network: &P, pool: Pool, pos: u64, seq_len: u64) -> usize {
+        if let Some(weights) = &self.weights {
+            return self.pick_weighted(network, weights, pool, pos);
+        }
+        let start = self.start_pos(pool, pos, seq_len);
+        let idx = self.walk(network, pool, start).unwrap_or_else(|| {
+            // The cycle has no allowed indices, which can only happen if the
+            // sequence is longer than the number of allowed items.
+            let start = self.nth_allowed(pool, pos % pool.allowed_len as u64);
+            self.walk(network, pool, start).unwrap()
+        });
+        idx as usize
+    }
+
+    /// Returns the position from which the walk of position `pos` of a
+    /// sequence of `seq_len` picks starts.
+    fn start_pos(&self, pool: Pool, pos: u64, seq_len: u64) -> u64 {
+        match pos < pool.len as u64 && self.is_banned(pos) {
+            true => self.spare_pos(pool, pos, seq_len).unwrap_or(pos),
+            false => pos,
+        }
+    }
+
+    /// Walks the cycle of `start` until the next allowed index, or returns
+    /// `None` if there are none.
+    fn walk(&self, network: &P, pool: Pool, start: u64) -> Option<u64> {
+        if !network.contains(start) {
+            return None;
+        }
+        let len = pool.len as u64;
+        if self.constant_time && start < len {
+            // Every step stays within the items, and the cycle contains start
+            let mut idx = network.permute_bounded_constant_time(start, len);
+            while self.is_banned(idx) {
+                if idx == start {
+                    return None;
+                }
+                idx = network.permute_bounded_constant_time(idx, len);
+            }
+            return Some(idx);
+        }
+        let mut idx = network.permute(start);
+        while idx >= len || self.is_banned(idx) {
+            if idx == start {
+                return None;
+            }
+            idx = network.permute(idx);
+        }
+        Some(idx)
+    }
+
+    /// Returns the `n`-th allowed index.
+    fn nth_allowed(&self, pool: Pool, n: u64) -> u64 {
+        (0..pool.len as u64)
+            .filter(|&idx| !self.is_banned(idx))
+            .nth(n as usize)
+            .unwrap()
+    }
+
+    /// Returns the index picked at position `pos` of a sequence of distinct
+    /// weighted picks.
+    ///
+    /// The items are ordered by the first slot visited by the permutation,
+    /// which picks heavier items earlier. This takes time proportional to `pos`.
+    fn pick_weighted(&self, network: &P, weights: &Weights, pool: Pool, pos: u64) -> usize {
+        let pos = pos % pool.allowed_len as u64;
+        self.weighted_sequence(network, weights, pool)
+            .nth(pos as usize)
+            .unwrap()
+    }
+
+    /// Returns an iterator over the distinct allowed items, in the order
+    /// their first slot is visited by the permutation.
+    fn weighted_sequence<'s>(
+        &'s self,
+        network: &'s P,
+        weights: &'s Weights,
+        pool: Pool,
+    ) -> impl Iterator<Item = usize> + 's {
+        let total = pool.total;
+        let mut seen = BTreeSet::new();
+        (0..total)
+            .map(move |pos| match self.constant_time {
+                true => network.permute_bounded_constant_time(pos, total),
+                false => permute_bounded(network, pos, total),
+            })
+            .map(|slot| weights.item(slot))
+            .filter(move |&idx| !self.is_banned(idx as u64) && seen.insert(idx))
+    }
+
+    /// Returns the allowed position past `seq_len` that replaces the banned
+    /// position `pos`, if any.
+    fn spare_pos(&self, pool: Pool, pos: u64, seq_len: u64) -> Option<u64> {
+        let rank = self.banned.partition_point(|&idx| (idx as u64) < pos);
+        (seq_len..pool.len as u64)
+            .filter(|&spare| !self.is_banned(spare))
+            .nth(rank)
+    }
+
+    /// Returns every position of a sequence of `seq_len` picks at which the
+    /// item at index `idx` is picked, in increasing order. This inverts
+    /// [`DailyPicker::pick`].
+    ///
+    /// An item is picked at more than one position only if the sequence is
+    /// longer than the number of allowed items.
+    fn unpick(&self, network: &P, pool: Pool, idx: usize, seq_len: u64) -> Vec<u64> {
+        if idx >= pool.len || self.is_banned(idx as u64) {
+            return Vec::new();
+        }
+        let allowed_len = pool.allowed_len as u64;
+        let step = allowed_len.try_into().unwrap_or(usize::MAX);
+        if let Some(weights) = &self.weights {
+            // Positions past the allowed items wrap around
+            let Some(first) = self
+                .weighted_sequence(network, weights, pool)
+                .position(|candidate| candidate == idx)
+            else {
+                return Vec::new();
+            };
+            return (first as u64..seq_len).step_by(step).collect();
+        }
+        // Walk the cycle backwards until the previous allowed index, since
+        // the walk from any of the visited starts reaches idx
+        let len = pool.len as u64;
+        let mut positions = Vec::new();
+        let mut start = idx as u64;
+        loop {
+            start = network.permute_inv(start);
+            let allowed = start < len && !self.is_banned(start);
+            let pos = if allowed && start >= seq_len {
+                // The spare position of a banned position
+                let rank = (seq_len..start)
+                    .filter(|&spare| !self.is_banned(spare))
+                    .count();
+                self.banned
+                    .get(rank)
+                    .map(|&pos| pos as u64)
+                    .filter(|&pos| pos < len)
+            } else if start < len && !allowed {
+                // A banned position without a spare position starts from itself
+                Some(start).filter(|&pos| self.spare_pos(pool, pos, seq_len).is_none())
+            } else {
+                Some(start)
+            };
+            positions.extend(pos.filter(|&pos| pos < seq_len));
+            if allowed {
+                break;
+            }
+        }
+        // Positions whose cycle has no allowed index start from the allowed
+        // index of the same rank instead
+        let rank = start
+            - self
+                .banned
+                .partition_point(|&banned| (banned as u64) < start) as u64;
+        positions.extend((rank..seq_len).step_by(step).filter(|&pos| {
+            let start = self.start_pos(pool, pos, seq_len);
+            self.walk(network, pool, start).is_none()
+        }));
+        positions.sort_unstable();
+        positions
+    }
+
+    /// Returns the item for the given day.
+    pub fn get(&self, day: u64) -> &T {
+        self.get_with_index(day).1
+    }
+
+    /// Returns the item for the given day, along with its index in the list of items.
+    pub fn get_with_index(&self, day: u64) -> (usize, &T) {
+        let (window, pos) = self.locate(day);
+        let seed = self.seed_at(day.wrapping_add(self.day_offset));
+        let network = self.network(seed, window);
+        let idx = self.index(&network, window, pos);
+        (idx, &self.items[idx])
+    }
+
+    /// Returns the items of every day of the given window, in order.
+    ///
+    /// The round keys of the window are only derived once.
+    pub fn window_items(&self, window: u64) -> Vec<&T> {
+        let mut items = Vec::new();
+        for (positions, seed) in self.window_seeds(window) {
+            let network = self.network(seed, window);
+            items.extend(positions.map(|pos| &self.items[self.index(&network, window, pos)]));
+        }
+        items
+    }
+
+    /// Returns `n` distinct items for the given day.
+    ///
+    /// The items are picked by a permutation keyed by the seed and the day,
+    /// separate from the one used by [`DailyPicker::get`]. The first items
+    /// stay the same as `n` grows, so each slot is reproducible on its own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the number of items that can be picked.
+    /// See [`DailyPicker::try_get_many`] for a non-panicking variant.
+    pub fn get_many(&self, day: u64, n: usize) -> Vec<&T> {
+        match self.try_get_many(day, n) {
+            Ok(items) => items,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Returns `n` distinct items for the given day, or an error if `n` is
+    /// greater than the number of items that can be picked.
+    ///
+    /// See [`DailyPicker::get_many`] for details.
+    pub fn try_get_many(&self, day: u64, n: usize) -> Result<Vec<&T>, WordleError> {
+        let indices = self.many_indices(day, n)?;
+        Ok(indices.into_iter().map(|idx| &self.items[idx]).collect())
+    }
+
+    /// Returns the indices of `n` distinct items for the given day.
+    fn many_indices(&self, day: u64, n: usize) -> Result<Vec<usize>, WordleError> {
+        let pool = self.pool(self.window_of(day));
+        if n > pool.allowed_len {
+            return Err(WordleError::NotEnoughWords {
+                requested: n,
+                available: pool.allowed_len,
+            });
+        }
+        let day = day.wrapping_add(self.day_offset);
+        let network = self.derive_network(
+            self.seed_at(day),
+            &[b"many", &self.algorithm.encode_u64(day)],
+        );
+        Ok((0..n as u64)
+            .map(|slot| self.pick(&network, pool, slot, n as u64))
+            .collect())
+    }
+
+    /// Returns the first day of the given window on which the item at index
+    /// `idx` is picked, or `None` if the item is not picked during the window.
+    ///
+    /// Like [`DailyPicker::window_bounds`], this only considers the days of
+    /// the window from day `0`, which may be truncated by the day offset.
+    ///
+    /// The returned day can be passed to [`DailyPicker::get`] to obtain the item.
+    pub fn day_of_index(&self, idx: usize, window: u64) -> Option<u64> {
+        self.first_day_of(self.items.get(idx).map(|_| idx), window)
+    }
+
+    /// Returns the first day of the given window on which any of the items
+    /// at the given indices is picked.
+    pub(crate) fn first_day_of(
+        &self,
+        indices: impl IntoIterator<Item = usize>,
+        window: u64,
+    ) -> Option<u64> {
+        let pool = self.pool(window);
+        let indices: BTreeSet<_> = indices.into_iter().collect();
+        // Only consider windows whose days can all be represented
+        let window_start = window.checked_mul(self.window_len)?;
+        window_start.checked_add(self.window_len - 1)?;
+        if self.windowless {
+            let network = self.network(self.seed_at(window), window);
+            let idx = self.index_windowless(&network, pool, window);
+            return indices
+                .contains(&idx)
+                .then(|| window.checked_sub(self.day_offset))
+                .flatten();
+        }
+        // Days before the day offset can't be represented
+        let earliest = self.day_offset.saturating_sub(window_start);
+        let mut first = None;
+        for (positions, seed) in self.window_seeds(window) {
+            let network = self.network(seed, window);
+            let scheduled = indices
+                .iter()
+                .flat_map(|&idx| self.unpick(&network, pool, idx, self.window_len))
+                .map(|pos| self.reorder(&network, pool, window, pos))
+                .filter(|pos| positions.contains(pos))
+                .filter(|&pos| !self.vetoes.contains_key(&window_start.wrapping_add(pos)));
+            let replaced = self
+                .vetoes
+                .range(window_start + positions.start..window_start + positions.end)
+                .map(|(&day, _)| day)
+                .filter(|&day| {
+                    let idx = self.replacement(&network, pool, day).unwrap();
+                    indices.contains(&idx)
+                })
+                .map(|day| day - window_start);
+            let candidates = first.into_iter().chain(scheduled).chain(replaced);
+            first = candidates.filter(|&pos| pos >= earliest).min();
+        }
+        let day = window_start + first?;
+        day.checked_sub(self.day_offset)
+    }
+
+    /// Returns an iterator over the days starting at `day`, and their items.
+    ///
+    /// The round keys of each window are only derived once, which makes this
+    /// more efficient than calling [`DailyPicker::get`] for consecutive days.
+    pub fn iter_from(&self, day: u64) -> Picks<'_, 'a, T, ROUNDS, P> {
+        Picks::new(self, day)
+    }
+
+    /// Returns the items for every day in the given range.
+    ///
+    /// The round keys of each window are only derived once.
+    pub fn get_range(&self, days: Range<u64>) -> Vec<&T> {
+        let len = days.end.saturating_sub(days.start);
+        self.iter_from(days.start)
+            .take(len.try_into().unwrap_or(usize::MAX))
+            .map(|(_, item)| item)
+            .collect()
+    }
+}
+
+/// The items that can be picked during a window, which are a prefix of the
+/// list if later items were appended.
+#[derive(Clone, Copy)]
+struct Pool {
+    /// The number of items in the prefix.
+    len: usize,
+    /// The number of items in the prefix that aren't banned.
+    allowed_len: usize,
+    /// The total weight of the items in the prefix, if weighted.
+    total: u64,
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a, T, const ROUNDS: usize, P> Drop for DailyPicker<'a, T, ROUNDS, P> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.seed.zeroize();
+        for (_, seed) in &mut self.rotations {
+            seed.zeroize();
+        }
+    }
+}
+
+impl<'a, T, const ROUNDS: usize, P> fmt::Debug for DailyPicker<'a, T, ROUNDS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The seeds are redacted, and the items are summarized
+        f.debug_struct("DailyPicker")
+            .field("item_count", &self.items.len())
+            .field("window_len", &self.window_len)
+            .field(
+                "seed_fingerprint",
+                &format_args!("{:016x}", self.seed.fingerprint()),
+            )
+            .field("day_offset", &self.day_offset)
+            .field("rounds", &self.rounds)
+            .field("banned", &self.banned.len())
+            .field("vetoes", &self.vetoes.len())
+            .field("weighted", &self.weights.is_some())
+            .field("repeat_gap", &self.repeat_gap)
+            .field("rotations", &self.rotations.len())
+            .field("windowless", &self.windowless)
+            .field("algorithm", &self.algorithm)
+            .field("capacity", &self.capacity)
+            .field("round_policy", &self.round_policy)
+            .field("whitening", &self.whitening)
+            .field("constant_time", &self.constant_time)
+            .field("appends", &self.appends.len())
+            .finish()
+    }
+}
@@ -0,0 +1,63 @@
+use core::fmt;
+
+/// The human-facing number of a daily puzzle, as printed in share grids.
+///
+/// Puzzle numbers are days shifted by the puzzle offset of the generator, set
+/// via [`WordleBuilder::puzzle_offset`](crate::WordleBuilder::puzzle_offset).
+/// Use [`Wordle::puzzle_id`](crate::Wordle::puzzle_id) and
+/// [`Wordle::day_of_puzzle`](crate::Wordle::day_of_puzzle) to convert between
+/// days and puzzle numbers.
+///
+/// Puzzle numbers are formatted with commas between groups of three digits.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::PuzzleId;
+///
+/// assert_eq!(PuzzleId::new(812).to_string(), "812");
+/// assert_eq!(PuzzleId::new(1234).to_string(), "1,234");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct PuzzleId(u64);
+
+impl PuzzleId {
+    /// Creates a puzzle number.
+    pub const fn new(number: u64) -> Self {
+        Self(number)
+    }
+
+    /// Returns the puzzle number.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for PuzzleId {
+    fn from(number: u64) -> Self {
+        Self(number)
+    }
+}
+
+impl From<PuzzleId> for u64 {
+    fn from(id: PuzzleId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for PuzzleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_grouped(f, self.0)
+    }
+}
+
+/// Writes an integer with commas between groups of three digits.
+fn write_grouped(f: &mut fmt::Formatter<'_>, value: u64) -> fmt::Result {
+    if value < 1000 {
+        return write!(f, "{}", value);
+    }
+    write_grouped(f, value / 1000)?;
+    write!(f, ",{:03}", value % 1000)
+}
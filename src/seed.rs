@@ -0,0 +1,329 @@
+use crate::hex;
+use alloc::string::String;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use base64::Engine;
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+use hkdf::Hkdf;
+#[cfg(feature = "std")]
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha512};
+
+/// The length of a seed in bytes.
+pub const SEED_LEN: usize = 32;
+
+/// A secret seed that determines the sequence of picked items.
+///
+/// Seeds are formatted as lowercase hexadecimal strings, and can be parsed
+/// from either hexadecimal or base64 (with or without padding) strings.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::Seed;
+///
+/// let seed: Seed = "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".parse().unwrap();
+/// assert_eq!(
+///     seed.to_string(),
+///     "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Seed([u8; SEED_LEN]);
+
+impl Seed {
+    /// Creates a seed from its bytes.
+    pub const fn new(bytes: [u8; SEED_LEN]) -> Self {
+        Self(bytes)
+    }
+
+    /// Generates a random seed using the operating system's CSPRNG.
+    #[cfg(feature = "std")]
+    pub fn generate() -> Self {
+        let mut bytes = [0; SEED_LEN];
+        OsRng.fill_bytes(&mut bytes);
+        Self(bytes)
+    }
+
+    /// Derives a seed from a memorable passphrase and a salt, using the
+    /// [`PassphraseKdf::V1`] parameters.
+    ///
+    /// The derivation is deliberately slow to resist brute-force attacks,
+    /// but a passphrase is still weaker than a seed generated via
+    /// [`Seed::generate`]. The parameters used by this method never change.
+    ///
+    /// Returns an error if the salt is shorter than 8 bytes.
+    #[cfg(feature = "passphrase")]
+    pub fn from_passphrase(passphrase: &[u8], salt: &[u8]) -> Result<Self, PassphraseError> {
+        Self::from_passphrase_with(PassphraseKdf::V1, passphrase, salt)
+    }
+
+    /// Derives a seed from a memorable passphrase and a salt, using the
+    /// given key derivation parameters.
+    ///
+    /// Returns an error if the salt is shorter than 8 bytes.
+    #[cfg(feature = "passphrase")]
+    pub fn from_passphrase_with(
+        kdf: PassphraseKdf,
+        passphrase: &[u8],
+        salt: &[u8],
+    ) -> Result<Self, PassphraseError> {
+        use argon2::{Algorithm, Argon2, Params, Version};
+
+        let (algorithm, params) = match kdf {
+            PassphraseKdf::V1 => (Algorithm::Argon2id, Params::new(19 * 1024, 2, 1, None)),
+        };
+        let argon2 = Argon2::new(algorithm, Version::V0x13, params.map_err(PassphraseError)?);
+        let mut bytes = [0; SEED_LEN];
+        argon2
+            .hash_password_into(passphrase, salt, &mut bytes)
+            .map_err(PassphraseError)?;
+        Ok(Self(bytes))
+    }
+
+    /// Derives an independent seed for the given label.
+    ///
+    /// The child seed is derived via HKDF-SHA512, so children with different
+    /// labels are unrelated, and the parent seed can't be recovered from them.
+    pub fn derive_child(&self, label: &[u8]) -> Self {
+        let mut hkdf = Hkdf::<Sha512>::new(None, &self.0);
+        let mut bytes = [0; SEED_LEN];
+        // SEED_LEN is below the maximum output length
+        hkdf.expand_multi_info(&[b"wordle-generator child seed:", label], &mut bytes)
+            .unwrap();
+        // SAFETY: the HKDF state only holds integer arrays, and isn't used again
+        unsafe { wipe_flat(&mut hkdf) };
+        Self(bytes)
+    }
+
+    /// Returns a fingerprint that identifies the seed without revealing it.
+    ///
+    /// This allows checking whether two deployments use the same seed, e.g. in logs.
+    pub fn fingerprint(&self) -> u64 {
+        let mut hasher = Sha512::new()
+            .chain_update(b"wordle-generator seed fingerprint")
+            .chain_update(self.0);
+        let digest = hasher.finalize_reset();
+        // SAFETY: the hasher state only holds integer arrays, and isn't used again
+        unsafe { wipe_flat(&mut hasher) };
+        u64::from_be_bytes(digest[..8].try_into().unwrap())
+    }
+
+    /// Returns the bytes of the seed.
+    pub fn as_bytes(&self) -> &[u8; SEED_LEN] {
+        &self.0
+    }
+
+    /// Returns the padded base64 representation of the seed.
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(self.0)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for Seed {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// Overwrites a value with zeros, such as a hasher whose buffer may still
+/// contain the seed.
+///
+/// This is a no-op without the `zeroize` feature.
+///
+/// # Safety
+///
+/// The value must only contain integers (and arrays or structs of them), with
+/// no references, pointers, niches or `Drop` glue, so that all zeros is a
+/// valid value. It must not be used afterwards, other than being dropped.
+/// See [`zeroize::zeroize_flat_type`].
+#[inline]
+pub(crate) unsafe fn wipe_flat<V>(value: &mut V) {
+    #[cfg(feature = "zeroize")]
+    // SAFETY: guaranteed by the caller
+    unsafe {
+        zeroize::zeroize_flat_type(value)
+    }
+    #[cfg(not(feature = "zeroize"))]
+    let _ = value;
+}
+
+impl From<[u8; SEED_LEN]> for Seed {
+    fn from(bytes: [u8; SEED_LEN]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<Seed> for [u8; SEED_LEN] {
+    fn from(seed: Seed) -> Self {
+        seed.0
+    }
+}
+
+impl AsRef<[u8]> for Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the seed in logs
+        f.write_str("Seed(..)")
+    }
+}
+
+impl fmt::Display for Seed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        hex::write(f, &self.0)
+    }
+}
+
+impl FromStr for Seed {
+    type Err = ParseSeedError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0; SEED_LEN];
+        if s.len() == 2 * SEED_LEN {
+            bytes = hex::decode_array(s).ok_or(ParseSeedError(()))?;
+        } else {
+            let engine = if s.ends_with('=') {
+                &STANDARD
+            } else {
+                &STANDARD_NO_PAD
+            };
+            let decoded = engine.decode(s).map_err(|_| ParseSeedError(()))?;
+            if decoded.len() != SEED_LEN {
+                return Err(ParseSeedError(()));
+            }
+            bytes.copy_from_slice(&decoded);
+        }
+        Ok(Self(bytes))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Seed {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Seed {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <alloc::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// The versioned parameters used to derive a [`Seed`] from a passphrase.
+///
+/// The parameters of a version never change, so a passphrase always derives
+/// the same seed.
+#[cfg(feature = "passphrase")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PassphraseKdf {
+    /// Argon2id v1.3 with 19 MiB of memory, 2 iterations and 1 degree of
+    /// parallelism.
+    V1,
+}
+
+/// An error which can be returned when deriving a [`Seed`] from a passphrase.
+#[cfg(feature = "passphrase")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassphraseError(argon2::Error);
+
+#[cfg(feature = "passphrase")]
+impl fmt::Display for PassphraseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot derive seed from passphrase: {}", self.0)
+    }
+}
+
+#[cfg(feature = "passphrase")]
+impl Error for PassphraseError {}
+
+/// An error which can be returned when parsing a [`Seed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSeedError(());
+
+impl fmt::Display for ParseSeedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "seed should be {} hexadecimal digits or {} base64-encoded bytes",
+            2 * SEED_LEN,
+            SEED_LEN
+        )
+    }
+}
+
+impl Error for ParseSeedError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParseSeedError, Seed};
+
+    #[test]
+    fn hex_roundtrip() {
+        let seed = Seed::new([0xA5; 32]);
+        assert_eq!(seed.to_string().parse(), Ok(seed));
+        assert_eq!(seed.to_string().to_uppercase().parse(), Ok(seed));
+    }
+
+    #[test]
+    fn base64_roundtrip() {
+        let seed = Seed::generate();
+        let encoded = seed.to_base64();
+        assert_eq!(encoded.parse(), Ok(seed));
+        assert_eq!(encoded.trim_end_matches('=').parse(), Ok(seed));
+    }
+
+    #[test]
+    fn invalid() {
+        assert!("".parse::<Seed>().is_err());
+        assert!("zz".repeat(32).parse::<Seed>().is_err());
+        assert_eq!("AAEC".parse::<Seed>(), Err(ParseSeedError(())));
+    }
+
+    #[test]
+    #[cfg(feature = "passphrase")]
+    fn from_passphrase() {
+        let seed = Seed::from_passphrase(b"correct horse battery staple", b"wordle-salt").unwrap();
+        assert_eq!(
+            seed.to_string(),
+            "332055576ca014a67653b7c6cbc8ee9d0e3f377f92bff9199b98625c47540794"
+        );
+        assert_ne!(
+            seed,
+            Seed::from_passphrase(b"correct horse", b"wordle-salt").unwrap()
+        );
+        assert!(Seed::from_passphrase(b"correct horse", b"salt").is_err());
+    }
+
+    #[test]
+    fn derive_child() {
+        let seed = Seed::new([3; 32]);
+        let child = seed.derive_child(b"alice");
+        assert_eq!(child, seed.derive_child(b"alice"));
+        assert_ne!(child, seed.derive_child(b"bob"));
+        assert_ne!(child, seed);
+        assert_ne!(child, Seed::new([4; 32]).derive_child(b"alice"));
+    }
+
+    #[test]
+    fn fingerprint() {
+        let seed = Seed::new([5; 32]);
+        assert_eq!(seed.fingerprint(), Seed::new([5; 32]).fingerprint());
+        assert_ne!(seed.fingerprint(), Seed::new([6; 32]).fingerprint());
+    }
+
+    #[test]
+    fn debug_is_redacted() {
+        assert_eq!(format!("{:?}", Seed::new([1; 32])), "Seed(..)");
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{LetterFeedback, PuzzleId};
+use core::fmt;
+
+/// The emojis used to render the feedback of each letter in a [`ShareGrid`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Palette {
+    /// The emoji of [`LetterFeedback::Correct`] letters.
+    pub correct: char,
+    /// The emoji of [`LetterFeedback::Present`] letters.
+    pub present: char,
+    /// The emoji of [`LetterFeedback::Absent`] letters.
+    pub absent: char,
+}
+
+impl Palette {
+    /// Green and yellow squares, with white squares for absent letters.
+    pub const LIGHT: Palette = Palette {
+        correct: '🟩',
+        present: '🟨',
+        absent: '⬜',
+    };
+    /// Green and yellow squares, with black squares for absent letters.
+    pub const DARK: Palette = Palette {
+        correct: '🟩',
+        present: '🟨',
+        absent: '⬛',
+    };
+    /// Orange and blue squares, with white squares for absent letters.
+    pub const HIGH_CONTRAST_LIGHT: Palette = Palette {
+        correct: '🟧',
+        present: '🟦',
+        absent: '⬜',
+    };
+    /// Orange and blue squares, with black squares for absent letters.
+    pub const HIGH_CONTRAST_DARK: Palette = Palette {
+        correct: '🟧',
+        present: '🟦',
+        absent: '⬛',
+    };
+
+    /// Returns the emoji of the given feedback.
+    pub fn emoji(&self, feedback: LetterFeedback) -> char {
+        match feedback {
+            LetterFeedback::Correct => self.correct,
+            LetterFeedback::Present => self.present,
+            LetterFeedback::Absent => self.absent,
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::DARK
+    }
+}
+
+/// A spoiler-free summary of a game, as shared by players.
+///
+/// The grid is rendered via its [`Display`](fmt::Display) implementation,
+/// starting with a header that contains the puzzle number and the number of
+/// attempts (or `X` if the game was lost), followed by a row of emojis for
+/// each guess. The puzzle number of a day is given by
+/// [`Wordle::puzzle_id`](crate::Wordle::puzzle_id), or use
+/// [`Wordle::share_grid`](crate::Wordle::share_grid).
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{evaluate, ShareGrid};
+///
+/// let rows: [[_; 5]; 2] = [evaluate("crane", "those"), evaluate("those", "those")];
+/// let grid = ShareGrid::new(1234, &rows);
+/// assert_eq!(grid.to_string(), "Wordle 1,234 2/6\n\n⬛⬛⬛⬛🟩\n🟩🟩🟩🟩🟩");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct ShareGrid<'a, const N: usize> {
+    title: &'a str,
+    puzzle: PuzzleId,
+    rows: &'a [[LetterFeedback; N]],
+    max_attempts: usize,
+    hard_mode: bool,
+    palette: Palette,
+}
+
+impl<'a, const N: usize> ShareGrid<'a, N> {
+    /// Creates a grid for the given puzzle number and feedback rows, in the
+    /// order of the guesses.
+    ///
+    /// By default, the title is `Wordle`, games have 6 attempts, and the grid
+    /// uses [`Palette::DARK`].
+    pub fn new(puzzle: impl Into<PuzzleId>, rows: &'a [[LetterFeedback; N]]) -> Self {
+        Self {
+            title: "Wordle",
+            puzzle: puzzle.into(),
+            rows,
+            max_attempts: 6,
+            hard_mode: false,
+            palette: Palette::default(),
+        }
+    }
+
+    /// Sets the title shown before the puzzle number.
+    pub fn title(mut self, title: &'a str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Sets the maximum number of attempts.
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets whether the game was played in hard mode, which is marked with
+    /// an asterisk after the attempt count.
+    pub fn hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    /// Sets the emojis used to render the rows.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Returns whether the last row is a correct guess.
+    pub fn is_solved(&self) -> bool {
+        self.rows
+            .last()
+            .is_some_and(|row| row.iter().all(|&f| f == LetterFeedback::Correct))
+    }
+}
+
+impl<'a, const N: usize> fmt::Display for ShareGrid<'a, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.title, self.puzzle)?;
+        if self.is_solved() {
+            write!(f, " {}/{}", self.rows.len(), self.max_attempts)?;
+        } else {
+            write!(f, " X/{}", self.max_attempts)?;
+        }
+        if self.hard_mode {
+            f.write_str("*")?;
+        }
+        if !self.rows.is_empty() {
+            f.write_str("\n")?;
+        }
+        for row in self.rows {
+            f.write_str("\n")?;
+            for &feedback in row {
+                write!(f, "{}", self.palette.emoji(feedback))?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,784 @@
+//! Keyed permutations of small integer domains, via card shuffles.
+//!
+//! Unlike a [`FeistelNetwork`](crate::feistel::FeistelNetwork), which permutes
+//! a power-of-two domain and relies on cycle walking, these ciphers permute
+//! any domain `0..n` directly. Their security bounds hold for the tiny
+//! domains of word lists, at the cost of many more (but cheaper) rounds.
+//!
+//! - [`SwapOrNot`] implements the swap-or-not shuffle of Hoang, Morris and
+//!   Rogaway. Each round pairs every value with a partner determined by a
+//!   secret offset, and swaps the pair depending on one pseudorandom bit.
+//! - [`SometimesRecurse`] implements the sometimes-recurse shuffle of Morris
+//!   and Rogaway. It applies a swap-or-not shuffle, and recursively shuffles
+//!   the values that land in the lower half of the domain. Its bound holds even
+//!   if the adversary observes the whole domain, e.g. every word of a list.
+//! - [`Thorp`] implements the Thorp shuffle, a maximally unbalanced Feistel
+//!   network of `0..2^bit_len` whose rounds each compute a single
+//!   pseudorandom bit. Like a [`FeistelNetwork`](crate::feistel::FeistelNetwork),
+//!   it permutes other domains via cycle walking.
+//!
+//! The pseudorandom bits are computed by a [`RoundFunction`], and the same
+//! security caveats of the [`feistel`](crate::feistel) module apply.
+
+use crate::feistel::{RoundFunction, WyHashRound};
+use crate::Permutation;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The swap-or-not shuffle, which permutes the integers `0..domain_len`.
+///
+/// Each round is keyed by one 64-bit round key `k`, from which it derives the
+/// offset `K = F(k, domain_len) mod domain_len`. The round pairs each value
+/// `x` with `x' = K - x mod domain_len`, and swaps them if the lowest bit of
+/// `F(k, max(x, x'))` is set. Every round is an involution, so the inverse
+/// applies the rounds in reverse order.
+///
+/// The security bound of the shuffle decreases exponentially with the number
+/// of rounds, as long as the adversary observes a fraction of the domain
+/// (e.g. half of the words). Revealing almost every output of the permutation
+/// voids the bound.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::shuffle::SwapOrNot;
+///
+/// let keys: Vec<u64> = (0..64u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15)).collect();
+/// let shuffle = SwapOrNot::with_keys(347, &keys);
+/// let mut outputs: Vec<_> = (0..347).map(|value| shuffle.permute(value)).collect();
+/// outputs.sort();
+/// assert!(outputs.into_iter().eq(0..347));
+/// assert_eq!(shuffle.permute_inv(shuffle.permute(42)), 42);
+/// ```
+#[derive(Clone)]
+pub struct SwapOrNot<F = WyHashRound> {
+    domain_len: u64,
+    keys: Vec<u64>,
+    round_function: F,
+}
+
+impl SwapOrNot {
+    /// Creates a swap-or-not shuffle of `0..domain_len`, with 16 rounds per
+    /// bit of the largest integer in the domain.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `SwapOrNot::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn for_domain(domain_len: u64) -> Self {
+        Self::new(domain_len, default_rounds(domain_len))
+    }
+
+    /// Creates a swap-or-not shuffle of `0..domain_len` with the given
+    /// number of rounds.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `SwapOrNot::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn new(domain_len: u64, rounds: usize) -> Self {
+        let mut shuffle = Self::with_keys(domain_len, &[]);
+        shuffle.keys.resize(rounds, 0);
+        shuffle
+    }
+
+    /// Creates a swap-or-not shuffle of `0..domain_len`, with one round per key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn with_keys(domain_len: u64, keys: &[u64]) -> Self {
+        Self::with_round_function(domain_len, keys, WyHashRound::default())
+    }
+}
+
+impl<F: RoundFunction> SwapOrNot<F> {
+    /// Creates a swap-or-not shuffle of `0..domain_len`, with one round per key,
+    /// using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn with_round_function(domain_len: u64, keys: &[u64], round_function: F) -> Self {
+        assert!(domain_len > 0, "domain must not be empty");
+        Self {
+            domain_len,
+            keys: keys.to_vec(),
+            round_function,
+        }
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// The result is unspecified if `input` is outside the domain. In debug
+    /// builds, this panics instead.
+    pub fn permute(&self, input: u64) -> u64 {
+        debug_assert!(self.contains(input), "input must be within the domain");
+        self.keys
+            .iter()
+            .fold(input, |value, &key| self.round(key, value))
+    }
+
+    /// Returns the input that [`SwapOrNot::permute`] maps to `output`.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        debug_assert!(self.contains(output), "output must be within the domain");
+        self.keys
+            .iter()
+            .rev()
+            .fold(output, |value, &key| self.round(key, value))
+    }
+
+    fn round(&self, key: u64, value: u64) -> u64 {
+        swap_or_not(&self.round_function, self.domain_len, key, value)
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        input < self.domain_len
+    }
+
+    /// Returns the size of the permuted domain.
+    pub fn domain_len(&self) -> u64 {
+        self.domain_len
+    }
+
+    /// Returns the number of rounds.
+    pub fn rounds(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the round function of the shuffle.
+    pub fn round_function(&self) -> &F {
+        &self.round_function
+    }
+
+    /// Returns a mutable reference to the round keys used by the shuffle,
+    /// one per round.
+    pub fn keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+}
+
+impl<F: RoundFunction> Permutation for SwapOrNot<F> {
+    fn permute(&self, input: u64) -> u64 {
+        SwapOrNot::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        SwapOrNot::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.domain_len.into()
+    }
+}
+
+impl<F> fmt::Debug for SwapOrNot<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SwapOrNot")
+            .field("domain_len", &self.domain_len)
+            .field("rounds", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The sometimes-recurse shuffle, which permutes the integers `0..domain_len`.
+///
+/// The shuffle has one level for each halving of the domain: level `i`
+/// applies a [`SwapOrNot`] shuffle of `0..domain_len / 2^i`, and values that
+/// land in the lower half `0..domain_len / 2^(i + 1)` continue to the next
+/// level. Every level has the same number of rounds, each keyed by its own
+/// round key. Most values only visit a couple of levels, so permuting is
+/// roughly twice as slow as a single swap-or-not shuffle.
+///
+/// Unlike the swap-or-not shuffle alone, the security bound holds even if the
+/// adversary observes every output of the permutation.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::shuffle::SometimesRecurse;
+///
+/// let mut shuffle = SometimesRecurse::new(347, 32);
+/// assert_eq!(shuffle.levels(), 8);
+/// for (i, key) in shuffle.keys_mut().iter_mut().enumerate() {
+///     *key = (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+/// }
+/// let mut outputs: Vec<_> = (0..347).map(|value| shuffle.permute(value)).collect();
+/// outputs.sort();
+/// assert!(outputs.into_iter().eq(0..347));
+/// ```
+#[derive(Clone)]
+pub struct SometimesRecurse<F = WyHashRound> {
+    domain_len: u64,
+    // The number of rounds of each level
+    rounds: usize,
+    // The round keys of level `i` are `keys[i * rounds..(i + 1) * rounds]`
+    keys: Vec<u64>,
+    round_function: F,
+}
+
+impl SometimesRecurse {
+    /// Creates a sometimes-recurse shuffle of `0..domain_len`, with 16 rounds
+    /// per level and bit of the largest integer in the domain.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `SometimesRecurse::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn for_domain(domain_len: u64) -> Self {
+        Self::new(domain_len, default_rounds(domain_len))
+    }
+
+    /// Creates a sometimes-recurse shuffle of `0..domain_len` with the given
+    /// number of rounds per level.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `SometimesRecurse::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn new(domain_len: u64, rounds: usize) -> Self {
+        Self::with_round_function(domain_len, rounds, WyHashRound::default())
+    }
+}
+
+impl<F: RoundFunction> SometimesRecurse<F> {
+    /// Creates a sometimes-recurse shuffle of `0..domain_len` with the given
+    /// number of rounds per level, using the given round function.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `SometimesRecurse::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn with_round_function(domain_len: u64, rounds: usize, round_function: F) -> Self {
+        assert!(domain_len > 0, "domain must not be empty");
+        let levels = domain_len.ilog2() as usize;
+        Self {
+            domain_len,
+            rounds,
+            keys: vec![0; levels * rounds],
+            round_function,
+        }
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// The result is unspecified if `input` is outside the domain. In debug
+    /// builds, this panics instead.
+    pub fn permute(&self, input: u64) -> u64 {
+        debug_assert!(self.contains(input), "input must be within the domain");
+        let mut value = input;
+        for (level, keys) in self.keys.chunks_exact(self.rounds.max(1)).enumerate() {
+            let domain_len = self.domain_len >> level;
+            value = keys.iter().fold(value, |value, &key| {
+                swap_or_not(&self.round_function, domain_len, key, value)
+            });
+            if value >= domain_len / 2 {
+                break;
+            }
+        }
+        value
+    }
+
+    /// Returns the input that [`SometimesRecurse::permute`] maps to `output`.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        debug_assert!(self.contains(output), "output must be within the domain");
+        // Find the level that produced the output, i.e. the first level
+        // whose upper half contains it. Level `i` shuffles `0..domain_len >> i`.
+        let mut level = 0;
+        while output < (self.domain_len >> level) / 2 {
+            level += 1;
+        }
+        let levels = self.keys.chunks_exact(self.rounds.max(1)).enumerate();
+        levels
+            .take(level + 1)
+            .rev()
+            .fold(output, |value, (level, keys)| {
+                keys.iter().rev().fold(value, |value, &key| {
+                    swap_or_not(&self.round_function, self.domain_len >> level, key, value)
+                })
+            })
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        input < self.domain_len
+    }
+
+    /// Returns the size of the permuted domain.
+    pub fn domain_len(&self) -> u64 {
+        self.domain_len
+    }
+
+    /// Returns the number of levels, i.e. how many times the domain can be
+    /// halved until a single value remains.
+    pub fn levels(&self) -> usize {
+        self.domain_len.ilog2() as usize
+    }
+
+    /// Returns the number of rounds of each level.
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns the round function of the shuffle.
+    pub fn round_function(&self) -> &F {
+        &self.round_function
+    }
+
+    /// Returns a mutable reference to the round keys used by the shuffle, with
+    /// the keys of each level in order.
+    pub fn keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+}
+
+impl<F: RoundFunction> Permutation for SometimesRecurse<F> {
+    fn permute(&self, input: u64) -> u64 {
+        SometimesRecurse::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        SometimesRecurse::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.domain_len.into()
+    }
+}
+
+impl<F> fmt::Debug for SometimesRecurse<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SometimesRecurse")
+            .field("domain_len", &self.domain_len)
+            .field("rounds", &self.rounds)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The Thorp shuffle, which permutes the integers `0..2^bit_len`.
+///
+/// Each round splits the input into its most significant bit `b` and the
+/// remaining `bit_len - 1` bits `r`, and outputs `r || b ^ F(k, r)`, where
+/// `F(k, r)` is the lowest bit of the round function keyed by the round key.
+/// This is a maximally unbalanced Feistel network: `bit_len` rounds (one
+/// pass) rotate every bit of the input through the lowest position.
+///
+/// Morris, Rogaway and Stegers prove that the shuffle is secure after a number
+/// of passes linear in the bit length, even if the adversary observes the
+/// whole domain.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::shuffle::Thorp;
+///
+/// let keys: Vec<u64> = (0..81u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15)).collect();
+/// let shuffle = Thorp::with_keys(9, &keys);
+/// let mut outputs: Vec<_> = (0..347)
+///     .map(|value| shuffle.permute_bounded(value, 347))
+///     .collect();
+/// outputs.sort();
+/// assert!(outputs.into_iter().eq(0..347));
+/// ```
+#[derive(Clone)]
+pub struct Thorp<F = WyHashRound> {
+    bit_len: u8,
+    keys: Vec<u64>,
+    round_function: F,
+}
+
+impl Thorp {
+    /// Creates a Thorp shuffle of the smallest power-of-two domain that holds
+    /// `0..domain_len`, with `bit_len` passes of `bit_len` rounds.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `Thorp::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the domain size is zero.
+    pub fn for_domain(domain_len: u64) -> Self {
+        assert!(domain_len > 0, "domain must not be empty");
+        let bit_len = (u64::BITS - (domain_len - 1).leading_zeros()).max(1) as u8;
+        Self::new(bit_len, usize::from(bit_len) * usize::from(bit_len))
+    }
+
+    /// Creates a Thorp shuffle of `0..2^bit_len` with the given number of rounds.
+    ///
+    /// The round keys are initially set to zero. The shuffle should be initialized
+    /// by mutating `Thorp::keys_mut`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn new(bit_len: u8, rounds: usize) -> Self {
+        let mut shuffle = Self::with_keys(bit_len, &[]);
+        shuffle.keys.resize(rounds, 0);
+        shuffle
+    }
+
+    /// Creates a Thorp shuffle of `0..2^bit_len`, with one round per key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn with_keys(bit_len: u8, keys: &[u64]) -> Self {
+        Self::with_round_function(bit_len, keys, WyHashRound::default())
+    }
+}
+
+impl<F: RoundFunction> Thorp<F> {
+    /// Creates a Thorp shuffle of `0..2^bit_len`, with one round per key,
+    /// using the given round function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_len` is zero or greater than `u64::BITS`.
+    pub fn with_round_function(bit_len: u8, keys: &[u64], round_function: F) -> Self {
+        assert!(bit_len > 0, "bit_len must be positive");
+        assert!(
+            u32::from(bit_len) <= u64::BITS,
+            "bit_len (is {}) should be <= {}",
+            bit_len,
+            u64::BITS
+        );
+        Self {
+            bit_len,
+            keys: keys.to_vec(),
+            round_function,
+        }
+    }
+
+    /// Returns the output of the permutation for `input`.
+    ///
+    /// The result is unspecified if `input` is outside the domain. In debug
+    /// builds, this panics instead.
+    pub fn permute(&self, input: u64) -> u64 {
+        debug_assert!(self.contains(input), "input must be within the domain");
+        let shift = self.bit_len - 1;
+        self.keys.iter().fold(input, |value, &key| {
+            let (bit, rest) = (value >> shift, value & self.rest_mask());
+            let bit = bit ^ (self.round_function.round(key, rest) & 1);
+            rest << 1 | bit
+        })
+    }
+
+    /// Returns the input that [`Thorp::permute`] maps to `output`.
+    pub fn permute_inv(&self, output: u64) -> u64 {
+        debug_assert!(self.contains(output), "output must be within the domain");
+        let shift = self.bit_len - 1;
+        self.keys.iter().rev().fold(output, |value, &key| {
+            let (rest, bit) = (value >> 1, value & 1);
+            let bit = bit ^ (self.round_function.round(key, rest) & 1);
+            bit << shift | rest
+        })
+    }
+
+    /// Returns the output of the permutation of `0..domain_len` for `input`,
+    /// which is found by cycle walking.
+    /// See [`FeistelNetwork::permute_bounded`](crate::feistel::FeistelNetwork::permute_bounded).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` is not less than `domain_len`, or `domain_len` is
+    /// zero or greater than the domain of the shuffle.
+    pub fn permute_bounded(&self, input: u64, domain_len: u64) -> u64 {
+        self.check_bounded(input, domain_len);
+        let mut value = self.permute(input);
+        while value >= domain_len {
+            value = self.permute(value);
+        }
+        value
+    }
+
+    /// Returns the input that [`Thorp::permute_bounded`] maps to `output`.
+    pub fn permute_bounded_inv(&self, output: u64, domain_len: u64) -> u64 {
+        self.check_bounded(output, domain_len);
+        let mut value = self.permute_inv(output);
+        while value >= domain_len {
+            value = self.permute_inv(value);
+        }
+        value
+    }
+
+    fn check_bounded(&self, value: u64, domain_len: u64) {
+        assert!(domain_len > 0, "domain cannot be empty");
+        assert!(
+            self.contains(domain_len - 1),
+            "domain_len (is {}) should be <= 2^{}",
+            domain_len,
+            self.bit_len
+        );
+        assert!(
+            value < domain_len,
+            "value (is {}) should be < {}",
+            value,
+            domain_len
+        );
+    }
+
+    /// Returns whether `input` belongs to the permuted domain.
+    pub fn contains(&self, input: u64) -> bool {
+        input >> (self.bit_len - 1) <= 1
+    }
+
+    /// Returns the number of bits of the permuted integers.
+    pub fn bit_len(&self) -> u8 {
+        self.bit_len
+    }
+
+    /// Returns the number of rounds.
+    pub fn rounds(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns the round function of the shuffle.
+    pub fn round_function(&self) -> &F {
+        &self.round_function
+    }
+
+    /// Returns a mutable reference to the round keys used by the shuffle,
+    /// one per round.
+    pub fn keys_mut(&mut self) -> &mut [u64] {
+        &mut self.keys
+    }
+
+    // The mask of every bit but the most significant one
+    fn rest_mask(&self) -> u64 {
+        (1 << (self.bit_len - 1)) - 1
+    }
+}
+
+impl<F: RoundFunction> Permutation for Thorp<F> {
+    fn permute(&self, input: u64) -> u64 {
+        Thorp::permute(self, input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        Thorp::permute_inv(self, output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        1 << self.bit_len
+    }
+}
+
+impl<F> fmt::Debug for Thorp<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Thorp")
+            .field("bit_len", &self.bit_len)
+            .field("rounds", &self.keys.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Applies one round of the swap-or-not shuffle of `0..domain_len`.
+fn swap_or_not<F: RoundFunction>(round_function: &F, domain_len: u64, key: u64, value: u64) -> u64 {
+    // The domain size is never an input, so it separates the offsets
+    // from the swap bits.
+    let offset = round_function.round(key, domain_len) % domain_len;
+    let partner = if offset >= value {
+        offset - value
+    } else {
+        domain_len - (value - offset)
+    };
+    let swap = round_function.round(key, value.max(partner)) & 1 == 1;
+    if swap {
+        partner
+    } else {
+        value
+    }
+}
+
+/// Returns 16 rounds per bit of the largest integer in `0..domain_len`.
+fn default_rounds(domain_len: u64) -> usize {
+    let bit_len = u64::BITS - domain_len.saturating_sub(1).leading_zeros();
+    16 * bit_len.max(1) as usize
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> Drop for SwapOrNot<F> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.keys.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> Drop for SometimesRecurse<F> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.keys.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F> Drop for Thorp<F> {
+    fn drop(&mut self) {
+        use zeroize::Zeroize;
+
+        self.keys.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SometimesRecurse, SwapOrNot, Thorp};
+    use crate::feistel::SipHashRound;
+
+    fn keys(rounds: u64) -> Vec<u64> {
+        (0..rounds)
+            .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15))
+            .collect()
+    }
+
+    #[test]
+    fn bijective() {
+        for domain_len in [1, 2, 3, 7, 64, 347, 1000] {
+            let shuffle = SwapOrNot::with_keys(domain_len, &keys(48));
+            let mut outputs: Vec<_> = (0..domain_len).map(|i| shuffle.permute(i)).collect();
+            outputs.sort();
+            assert!(outputs.into_iter().eq(0..domain_len));
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let shuffle = SwapOrNot::with_keys(5757, &keys(64));
+        for input in 0..5757 {
+            assert_eq!(shuffle.permute_inv(shuffle.permute(input)), input);
+        }
+    }
+
+    #[test]
+    fn vectors() {
+        let shuffle = SwapOrNot::with_keys(1000, &keys(160));
+        let outputs = [0, 1, 2, 999].map(|input| shuffle.permute(input));
+        assert_eq!(outputs, [573, 836, 221, 764]);
+
+        let round_function = SipHashRound::new(0x0706_0504_0302_0100);
+        let shuffle = SwapOrNot::with_round_function(1000, &keys(160), round_function);
+        let outputs = [0, 1, 2, 999].map(|input| shuffle.permute(input));
+        assert_eq!(outputs, [582, 894, 631, 109]);
+    }
+
+    #[test]
+    fn for_domain() {
+        assert_eq!(SwapOrNot::for_domain(1).rounds(), 16);
+        assert_eq!(SwapOrNot::for_domain(2).rounds(), 16);
+        assert_eq!(SwapOrNot::for_domain(5757).rounds(), 208);
+        assert_eq!(SwapOrNot::for_domain(u64::MAX).rounds(), 1024);
+    }
+
+    #[test]
+    fn max_domain() {
+        let shuffle = SwapOrNot::with_keys(u64::MAX, &keys(8));
+        for input in [0, 1, u64::MAX / 2, u64::MAX - 1] {
+            let output = shuffle.permute(input);
+            assert!(shuffle.contains(output));
+            assert_eq!(shuffle.permute_inv(output), input);
+        }
+    }
+
+    fn sometimes_recurse(domain_len: u64, rounds: usize) -> SometimesRecurse {
+        let mut shuffle = SometimesRecurse::new(domain_len, rounds);
+        let len = shuffle.keys_mut().len() as u64;
+        shuffle.keys_mut().copy_from_slice(&keys(len));
+        shuffle
+    }
+
+    #[test]
+    fn sometimes_recurse_bijective() {
+        for domain_len in [1, 2, 3, 7, 64, 347, 1000] {
+            let shuffle = sometimes_recurse(domain_len, 24);
+            let mut outputs: Vec<_> = (0..domain_len).map(|i| shuffle.permute(i)).collect();
+            outputs.sort();
+            assert!(outputs.into_iter().eq(0..domain_len));
+            for input in 0..domain_len {
+                assert_eq!(shuffle.permute_inv(shuffle.permute(input)), input);
+            }
+        }
+    }
+
+    #[test]
+    fn sometimes_recurse_levels() {
+        assert_eq!(SometimesRecurse::new(1, 8).levels(), 0);
+        assert_eq!(SometimesRecurse::new(3, 8).levels(), 1);
+        let mut shuffle = SometimesRecurse::new(5757, 8);
+        assert_eq!(shuffle.levels(), 12);
+        assert_eq!(shuffle.keys_mut().len(), 96);
+        assert_eq!(SometimesRecurse::for_domain(5757).rounds(), 208);
+    }
+
+    #[test]
+    fn sometimes_recurse_vectors() {
+        let shuffle = sometimes_recurse(1000, 160);
+        let outputs = [0, 1, 2, 999].map(|input| shuffle.permute(input));
+        assert_eq!(outputs, [573, 836, 443, 764]);
+    }
+
+    #[test]
+    fn thorp_bijective() {
+        for bit_len in [1, 2, 3, 8, 11] {
+            let shuffle = Thorp::with_keys(bit_len, &keys(64));
+            let domain_len = 1 << bit_len;
+            let mut outputs: Vec<_> = (0..domain_len).map(|i| shuffle.permute(i)).collect();
+            outputs.sort();
+            assert!(outputs.into_iter().eq(0..domain_len));
+            for input in 0..domain_len {
+                assert_eq!(shuffle.permute_inv(shuffle.permute(input)), input);
+            }
+        }
+    }
+
+    #[test]
+    fn thorp_bounded() {
+        let shuffle = Thorp::with_keys(10, &keys(100));
+        let mut outputs: Vec<_> = (0..1000)
+            .map(|input| shuffle.permute_bounded(input, 1000))
+            .collect();
+        assert!(outputs
+            .iter()
+            .all(|&output| shuffle.permute_bounded_inv(output, 1000) < 1000));
+        outputs.sort();
+        assert!(outputs.into_iter().eq(0..1000));
+    }
+
+    #[test]
+    fn thorp_vectors() {
+        let shuffle = Thorp::with_keys(10, &keys(100));
+        let outputs = [0, 1, 2, 1023].map(|input| shuffle.permute(input));
+        assert_eq!(outputs, [1003, 234, 287, 178]);
+    }
+
+    #[test]
+    fn thorp_wide() {
+        let shuffle = Thorp::with_keys(64, &keys(64));
+        assert!(shuffle.contains(u64::MAX));
+        for input in [0, 1, u64::MAX / 2, u64::MAX] {
+            assert_eq!(shuffle.permute_inv(shuffle.permute(input)), input);
+        }
+        assert_eq!(Thorp::for_domain(5757).rounds(), 169);
+        assert_eq!(Thorp::for_domain(1).bit_len(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn domain_must_not_be_empty() {
+        SwapOrNot::new(0, 8);
+    }
+}
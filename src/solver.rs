@@ -0,0 +1,258 @@
+//! Suggestions of the next guess, and hints.
+//!
+//! A [`Solver`] keeps track of the answers that are consistent with the
+//! feedback of the guesses so far, and suggests the allowed guess that best
+//! splits them. This can power a hint button, or calibrate the difficulty of
+//! a schedule by counting the guesses needed to solve each day.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::solver::Solver;
+//! use wordle_generator::Game;
+//!
+//! let answers = ["those", "shoes", "whose", "chose", "hoses"];
+//! let guesses = ["crane", "sweat", "those"];
+//! let mut game = Game::new(0, "chose");
+//! let mut solver = Solver::new(&answers, &guesses);
+//! while !game.is_over() {
+//!     let guess = solver.suggest().unwrap();
+//!     let feedback = game.guess(guess).unwrap();
+//!     solver.apply(guess, feedback);
+//! }
+//! assert!(game.guesses().len() <= 3);
+//! ```
+
+use crate::feedback::{encode_pattern, evaluate_letters};
+use crate::{Game, LetterFeedback, LetterState};
+use std::collections::HashMap;
+
+/// The heuristic used to rank guesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Heuristic {
+    /// Maximizes the expected information of the feedback, i.e. the entropy
+    /// of the distribution of feedback patterns over the candidates.
+    #[default]
+    Entropy,
+    /// Minimizes the number of candidates left in the worst case.
+    Minimax,
+}
+
+/// A word and its letters.
+#[derive(Debug, Clone)]
+struct Word<'a> {
+    word: &'a str,
+    letters: Vec<char>,
+}
+
+impl<'a> Word<'a> {
+    fn new(word: &'a str) -> Self {
+        Self {
+            word,
+            letters: word.chars().collect(),
+        }
+    }
+}
+
+/// Suggests guesses that narrow down the answer.
+#[derive(Debug, Clone)]
+pub struct Solver<'a> {
+    // The answers consistent with the feedback so far
+    candidates: Vec<Word<'a>>,
+    guesses: Vec<Word<'a>>,
+    heuristic: Heuristic,
+}
+
+impl<'a> Solver<'a> {
+    /// Creates a solver for a game whose answer is one of `answers`, and that
+    /// accepts any of `answers` and `guesses` as guesses.
+    ///
+    /// Words with a different number of letters than the first answer are ignored.
+    pub fn new<T: AsRef<str>>(answers: &'a [T], guesses: &'a [T]) -> Self {
+        let candidates: Vec<_> = answers.iter().map(|w| Word::new(w.as_ref())).collect();
+        let len = candidates.first().map_or(0, |w| w.letters.len());
+        let candidates: Vec<_> = candidates
+            .into_iter()
+            .filter(|w| w.letters.len() == len)
+            .collect();
+        let guesses = guesses
+            .iter()
+            .map(|w| Word::new(w.as_ref()))
+            .filter(|w| w.letters.len() == len)
+            .collect();
+        Self {
+            candidates,
+            guesses,
+            heuristic: Heuristic::default(),
+        }
+    }
+
+    /// Sets the heuristic used to rank guesses.
+    pub fn heuristic(mut self, heuristic: Heuristic) -> Self {
+        self.heuristic = heuristic;
+        self
+    }
+
+    /// Discards the candidates that are inconsistent with the feedback of a guess.
+    pub fn apply(&mut self, guess: &str, feedback: &[LetterFeedback]) {
+        let guess: Vec<char> = guess.chars().collect();
+        let mut actual = vec![LetterFeedback::Absent; feedback.len()];
+        self.candidates.retain(|candidate| {
+            if candidate.letters.len() != guess.len() || guess.len() != feedback.len() {
+                return false;
+            }
+            evaluate_letters(&guess, &candidate.letters, &mut actual);
+            actual == feedback
+        });
+    }
+
+    /// Discards the candidates that are inconsistent with the guesses of a game.
+    pub fn apply_game(&mut self, game: &Game) {
+        for guess in game.guesses() {
+            self.apply(guess.word(), guess.feedback());
+        }
+    }
+
+    /// Returns the answers that are consistent with the feedback so far.
+    pub fn candidates(&self) -> impl ExactSizeIterator<Item = &'a str> + '_ {
+        self.candidates.iter().map(|w| w.word)
+    }
+
+    /// Returns the best next guess, or `None` if no answer is consistent with
+    /// the feedback so far.
+    ///
+    /// Ranking every guess evaluates it against every candidate, so the first
+    /// suggestion over large lists is slow; it can be computed once and cached.
+    pub fn suggest(&self) -> Option<&'a str> {
+        if self.candidates.len() <= 2 {
+            return self.candidates.first().map(|w| w.word);
+        }
+        let mut best: Option<(&Word<'a>, f64)> = None;
+        // Candidates are ranked first, so they win ties as they may be the answer
+        for guess in self.candidates.iter().chain(&self.guesses) {
+            let score = self.score(guess);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((guess, score));
+            }
+        }
+        best.map(|(guess, _)| guess.word)
+    }
+
+    /// Returns the score of a guess, which is higher for better guesses.
+    fn score(&self, guess: &Word<'_>) -> f64 {
+        let mut buckets: HashMap<u64, usize> = HashMap::new();
+        let mut feedback = vec![LetterFeedback::Absent; guess.letters.len()];
+        for candidate in &self.candidates {
+            evaluate_letters(&guess.letters, &candidate.letters, &mut feedback);
+            *buckets.entry(encode_pattern(&feedback)).or_insert(0) += 1;
+        }
+        match self.heuristic {
+            Heuristic::Entropy => {
+                let total = self.candidates.len() as f64;
+                buckets
+                    .values()
+                    .map(|&count| {
+                        let p = count as f64 / total;
+                        -p * p.log2()
+                    })
+                    .sum()
+            }
+            Heuristic::Minimax => -(buckets.values().copied().max().unwrap_or(0) as f64),
+        }
+    }
+
+    /// Returns the guesses suggested by the solver until `answer` is guessed,
+    /// including the answer. This measures how hard `answer` is to guess.
+    ///
+    /// Returns `None` if `answer` is not one of the candidates.
+    pub fn solve(&self, answer: &str) -> Option<Vec<&'a str>> {
+        let answer: Vec<char> = answer.chars().collect();
+        if !self.candidates.iter().any(|w| w.letters == answer) {
+            return None;
+        }
+        let mut solver = self.clone();
+        let mut guesses = Vec::new();
+        let mut feedback = vec![LetterFeedback::Absent; answer.len()];
+        loop {
+            let guess = Word::new(solver.suggest()?);
+            guesses.push(guess.word);
+            if guess.letters == answer {
+                return Some(guesses);
+            }
+            evaluate_letters(&guess.letters, &answer, &mut feedback);
+            let before = solver.candidates.len();
+            solver.apply(guess.word, &feedback);
+            if solver.candidates.len() == before {
+                // Only happens if the answers contain duplicates
+                solver.candidates.retain(|w| w.word != guess.word);
+            }
+        }
+    }
+
+    /// Returns a hint for the given game, revealing as much as `level` allows.
+    pub fn hint(&self, game: &Game, level: HintLevel) -> Hint {
+        let answer: Vec<char> = game.answer().chars().collect();
+        let keyboard = game.keyboard();
+        match level {
+            HintLevel::Candidates => return Hint::Candidates(self.candidates.len()),
+            HintLevel::Letter => {
+                let unknown = answer
+                    .iter()
+                    .find(|&&letter| keyboard.state(letter) < LetterState::Present);
+                if let Some(&letter) = unknown {
+                    return Hint::Letter(letter);
+                }
+            }
+            HintLevel::Position => {
+                let unsolved = (0..answer.len()).find(|&pos| {
+                    !game
+                        .guesses()
+                        .iter()
+                        .any(|guess| guess.feedback().get(pos) == Some(&LetterFeedback::Correct))
+                });
+                if let Some(position) = unsolved {
+                    return Hint::Position {
+                        letter: answer[position],
+                        position,
+                    };
+                }
+            }
+            HintLevel::Answer => {}
+        }
+        Hint::Answer(game.answer().into())
+    }
+}
+
+/// How much a [`Hint`] reveals, from least to most.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HintLevel {
+    /// The number of answers consistent with the guesses so far.
+    Candidates,
+    /// A letter of the answer that hasn't been found yet.
+    Letter,
+    /// The position of a letter that hasn't been placed yet.
+    Position,
+    /// The answer itself.
+    Answer,
+}
+
+/// A hint about the answer of a game.
+///
+/// If a hint of the requested level would reveal nothing new (e.g. every
+/// letter was already found), the answer is revealed instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Hint {
+    /// The number of answers consistent with the guesses so far.
+    Candidates(usize),
+    /// A letter of the answer.
+    Letter(char),
+    /// A letter of the answer and its zero-based position.
+    Position {
+        /// The letter.
+        letter: char,
+        /// The position of the letter.
+        position: usize,
+    },
+    /// The answer.
+    Answer(String),
+}
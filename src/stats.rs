@@ -0,0 +1,223 @@
+//! Statistical checks of the distribution of picked words.
+//!
+//! Picks should be indistinguishable from uniformly random picks (or picks
+//! proportional to the weights), apart from the constraints of each window.
+//! [`uniformity_report`] counts the occurrences of every word over a range
+//! of days, and runs a chi-square goodness-of-fit test against the expected
+//! distribution.
+//!
+//! Since no word repeats within a window, picks over complete windows are
+//! *more* even than independent random picks, so a high p-value is expected.
+//! A p-value close to zero indicates that some words are picked too often.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::stats::uniformity_report;
+//! use wordle_generator::{Seed, Wordle};
+//!
+//! let words = ["apple", "grape", "lemon", "mango", "peach"];
+//! let wordle = Wordle::from_seed(&words, 3, Seed::new([7; 32]));
+//! let report = uniformity_report(&wordle, 0..3000);
+//! assert_eq!(report.window_repeats, 0);
+//! assert!(report.is_uniform(0.001));
+//! ```
+
+use crate::{KeyedPermutation, Wordle};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// The result of [`uniformity_report`].
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct UniformityReport {
+    /// The number of analyzed days.
+    pub days: u64,
+    /// The number of distinct windows that overlap the analyzed days.
+    pub windows: u64,
+    /// The number of times each word was picked, by index.
+    pub counts: Vec<u64>,
+    /// The expected number of times each word is picked, by index.
+    pub expected: Vec<f64>,
+    /// The smallest count among the words that can be picked.
+    pub min_count: u64,
+    /// The largest count among the words that can be picked.
+    pub max_count: u64,
+    /// The chi-square statistic of the counts.
+    pub chi_square: f64,
+    /// The degrees of freedom of the chi-square test.
+    pub degrees_of_freedom: u64,
+    /// The probability of a chi-square statistic at least as large under
+    /// uniformly random picks.
+    pub p_value: f64,
+    /// The number of picks that repeat a word picked earlier in the same
+    /// window. This is zero unless the window is longer than the number of
+    /// words that can be picked.
+    pub window_repeats: u64,
+}
+
+impl UniformityReport {
+    /// Returns whether the chi-square test doesn't reject uniformity at the
+    /// given significance level (e.g. `0.01`).
+    pub fn is_uniform(&self, significance: f64) -> bool {
+        self.p_value >= significance
+    }
+}
+
+/// Counts the words picked over a range of days, and tests whether they
+/// follow the expected distribution.
+///
+/// Banned words are expected never to be picked, and weighted words to be
+/// picked proportionally to their weight.
+///
+/// # Panics
+///
+/// Panics if the range is empty.
+pub fn uniformity_report<T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation>(
+    wordle: &Wordle<'_, T, ROUNDS, P>,
+    days: Range<u64>,
+) -> UniformityReport {
+    assert!(!days.is_empty(), "range of days cannot be empty");
+    let config = wordle.config();
+    let word_count = wordle.word_count();
+    let mut weights: Vec<f64> = match &config.weights {
+        Some(weights) => weights.iter().map(|&w| w as f64).collect(),
+        None => vec![1.0; word_count],
+    };
+    for &idx in &config.banned {
+        weights[idx] = 0.0;
+    }
+    let total_weight: f64 = weights.iter().sum();
+
+    let mut counts = vec![0u64; word_count];
+    // The picks of each window, to count repeats
+    let mut windows: BTreeMap<u64, Vec<usize>> = BTreeMap::new();
+    for day in days.clone() {
+        let (idx, _) = wordle.get_with_index(day);
+        counts[idx] += 1;
+        windows.entry(wordle.window_of(day)).or_default().push(idx);
+    }
+    let window_repeats = windows
+        .values_mut()
+        .map(|picks| {
+            let len = picks.len();
+            picks.sort_unstable();
+            picks.dedup();
+            (len - picks.len()) as u64
+        })
+        .sum();
+
+    let day_count = days.end - days.start;
+    let expected: Vec<f64> = weights
+        .iter()
+        .map(|w| day_count as f64 * w / total_weight)
+        .collect();
+    let pickable = || (0..word_count).filter(|&idx| weights[idx] > 0.0);
+    let chi_square = pickable()
+        .map(|idx| {
+            let diff = counts[idx] as f64 - expected[idx];
+            diff * diff / expected[idx]
+        })
+        .sum();
+    let degrees_of_freedom = pickable().count() as u64 - 1;
+    let p_value = match degrees_of_freedom {
+        0 => 1.0,
+        df => upper_gamma(df as f64 / 2.0, chi_square / 2.0),
+    };
+
+    UniformityReport {
+        days: day_count,
+        windows: windows.len() as u64,
+        min_count: pickable().map(|idx| counts[idx]).min().unwrap_or(0),
+        max_count: pickable().map(|idx| counts[idx]).max().unwrap_or(0),
+        counts,
+        expected,
+        chi_square,
+        degrees_of_freedom,
+        p_value,
+        window_repeats,
+    }
+}
+
+/// Returns the natural logarithm of the gamma function, via the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.180_091_729_471_46,
+        -86.505_320_329_416_77,
+        24.014_098_240_830_91,
+        -1.231_739_572_450_155,
+        0.001_208_650_973_866_179,
+        -0.000_005_395_239_384_953,
+    ];
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut series = 1.000_000_000_190_015;
+    for (i, c) in COEFFICIENTS.iter().enumerate() {
+        series += c / (x + 1.0 + i as f64);
+    }
+    -tmp + (2.506_628_274_631_000_5 * series / x).ln()
+}
+
+/// Returns the regularized upper incomplete gamma function `Q(a, x)`.
+fn upper_gamma(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-14;
+    const MAX_ITERATIONS: usize = 10_000;
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // Series expansion of the lower function
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..MAX_ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * prefactor).clamp(0.0, 1.0)
+    } else {
+        // Continued fraction, via the modified Lentz method
+        let tiny = f64::MIN_POSITIVE / EPSILON;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..MAX_ITERATIONS {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (prefactor * h).clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::upper_gamma;
+
+    #[test]
+    fn chi_square_p_values() {
+        // Critical values of the chi-square distribution at p = 0.05
+        for (df, critical) in [(1.0, 3.841), (2.0, 5.991), (10.0, 18.307), (100.0, 124.342)] {
+            let p = upper_gamma(df / 2.0, critical / 2.0);
+            assert!((p - 0.05).abs() < 1e-3, "df {}: {}", df, p);
+        }
+        assert_eq!(upper_gamma(3.0, 0.0), 1.0);
+        assert!(upper_gamma(5.0, 1000.0) < 1e-10);
+    }
+}
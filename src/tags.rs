@@ -0,0 +1,173 @@
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Tags (or categories) of the words of a list, e.g. `animals` or `hard`.
+///
+/// Words are identified by their index in the list, and can have any number
+/// of tags. See [`Wordle::filtered`](crate::Wordle::filtered) to pick only
+/// the words that match a [`TagFilter`].
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::Tags;
+///
+/// // Tags of the words ["horse", "zebra", "apple"]
+/// let mut tags = Tags::new();
+/// tags.insert(0, "animals");
+/// tags.insert(1, "animals");
+/// tags.insert(1, "hard");
+/// assert_eq!(tags.indices("animals").collect::<Vec<_>>(), [0, 1]);
+/// assert_eq!(tags.tags_of(1).collect::<Vec<_>>(), ["animals", "hard"]);
+/// assert!(!tags.has_tag(2, "animals"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tags {
+    // The sorted indices of the words with each tag
+    indices: BTreeMap<String, BTreeSet<usize>>,
+}
+
+impl Tags {
+    /// Creates an empty set of tags.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tags the word at `index` with `tag`.
+    pub fn insert(&mut self, index: usize, tag: &str) {
+        match self.indices.get_mut(tag) {
+            Some(indices) => {
+                indices.insert(index);
+            }
+            None => {
+                self.indices
+                    .insert(String::from(tag), BTreeSet::from([index]));
+            }
+        }
+    }
+
+    /// Tags the word at `index` with each of the given tags.
+    pub fn extend<'t>(&mut self, index: usize, tags: impl IntoIterator<Item = &'t str>) {
+        for tag in tags {
+            self.insert(index, tag);
+        }
+    }
+
+    /// Removes `tag` from the word at `index`, and returns whether the word
+    /// had the tag.
+    pub fn remove(&mut self, index: usize, tag: &str) -> bool {
+        let Some(indices) = self.indices.get_mut(tag) else {
+            return false;
+        };
+        let removed = indices.remove(&index);
+        if indices.is_empty() {
+            self.indices.remove(tag);
+        }
+        removed
+    }
+
+    /// Returns whether the word at `index` has `tag`.
+    pub fn has_tag(&self, index: usize, tag: &str) -> bool {
+        self.indices
+            .get(tag)
+            .is_some_and(|indices| indices.contains(&index))
+    }
+
+    /// Returns the tags of the word at `index`, in alphabetical order.
+    pub fn tags_of(&self, index: usize) -> impl Iterator<Item = &str> + '_ {
+        self.indices
+            .iter()
+            .filter(move |(_, indices)| indices.contains(&index))
+            .map(|(tag, _)| tag.as_str())
+    }
+
+    /// Returns the indices of the words with `tag`, in increasing order.
+    pub fn indices(&self, tag: &str) -> impl Iterator<Item = usize> + '_ {
+        self.indices.get(tag).into_iter().flatten().copied()
+    }
+
+    /// Returns every tag, in alphabetical order.
+    pub fn tags(&self) -> impl Iterator<Item = &str> + '_ {
+        self.indices.keys().map(String::as_str)
+    }
+}
+
+/// A condition on the tags of a word, to restrict a generator to a theme.
+///
+/// A word matches the filter if it has at least one of the tags given to
+/// [`TagFilter::any_of`] (if any), every tag given to [`TagFilter::all_of`],
+/// and none of the tags given to [`TagFilter::none_of`]. The empty filter
+/// matches every word.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{TagFilter, Tags};
+///
+/// let mut tags = Tags::new();
+/// tags.extend(0, ["animals"]);
+/// tags.extend(1, ["animals", "hard"]);
+/// tags.extend(2, ["fruits", "us-only"]);
+///
+/// let filter = TagFilter::new().any_of(["animals", "fruits"]).none_of(["us-only"]);
+/// assert!(filter.matches(&tags, 0));
+/// assert!(filter.matches(&tags, 1));
+/// assert!(!filter.matches(&tags, 2));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TagFilter {
+    any_of: BTreeSet<String>,
+    all_of: BTreeSet<String>,
+    none_of: BTreeSet<String>,
+}
+
+impl TagFilter {
+    /// Creates a filter that matches every word.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires words to have at least one of the given tags, or of the tags
+    /// of previous calls.
+    pub fn any_of<'t>(mut self, tags: impl IntoIterator<Item = &'t str>) -> Self {
+        self.any_of.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Requires words to have every one of the given tags.
+    pub fn all_of<'t>(mut self, tags: impl IntoIterator<Item = &'t str>) -> Self {
+        self.all_of.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Requires words to have none of the given tags.
+    pub fn none_of<'t>(mut self, tags: impl IntoIterator<Item = &'t str>) -> Self {
+        self.none_of.extend(tags.into_iter().map(String::from));
+        self
+    }
+
+    /// Returns whether the word at `index` matches the filter.
+    pub fn matches(&self, tags: &Tags, index: usize) -> bool {
+        let has_tag = |tag: &String| tags.has_tag(index, tag);
+        (self.any_of.is_empty() || self.any_of.iter().any(has_tag))
+            && self.all_of.iter().all(has_tag)
+            && !self.none_of.iter().any(has_tag)
+    }
+
+    /// Returns the label from which the seed of a filtered generator is
+    /// derived, which doesn't depend on the order in which tags were added.
+    pub(crate) fn label(&self) -> Vec<u8> {
+        let mut label = b"wordle-generator tag filter:".to_vec();
+        for tags in [&self.any_of, &self.all_of, &self.none_of] {
+            label.extend_from_slice(&(tags.len() as u64).to_le_bytes());
+            for tag in tags {
+                label.extend_from_slice(&(tag.len() as u64).to_le_bytes());
+                label.extend_from_slice(tag.as_bytes());
+            }
+        }
+        label
+    }
+}
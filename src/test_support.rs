@@ -0,0 +1,99 @@
+//! [Proptest](https://docs.rs/proptest) strategies and invariant checks, to
+//! property-test integrations of this crate.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::test_runner::TestRunner;
+//! use wordle_generator::test_support::{check_window_distinct, seed, window_len, word_list};
+//! use wordle_generator::Wordle;
+//!
+//! let strategy = (word_list(1..50), seed(), window_len(50));
+//! TestRunner::default()
+//!     .run(&strategy, |(words, seed, len)| {
+//!         let wordle = Wordle::from_seed(&words, len, seed);
+//!         check_window_distinct(&wordle, 0)
+//!     })
+//!     .unwrap();
+//! ```
+
+use crate::{KeyedPermutation, Seed, Wordle, SEED_LEN};
+use proptest::collection::{btree_set, SizeRange};
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use std::collections::BTreeSet;
+use std::ops::Range;
+
+/// Returns a strategy that generates uniformly random seeds.
+pub fn seed() -> impl Strategy<Value = Seed> {
+    any::<[u8; SEED_LEN]>().prop_map(Seed::new)
+}
+
+/// Returns a strategy that generates window lengths in `1..=max`.
+///
+/// # Panics
+///
+/// Panics if `max` is zero.
+pub fn window_len(max: u64) -> impl Strategy<Value = u64> {
+    assert!(max > 0, "max window length should be positive");
+    1..=max
+}
+
+/// Returns a strategy that generates five-letter lowercase ASCII words.
+pub fn word() -> impl Strategy<Value = String> {
+    "[a-z]{5}"
+}
+
+/// Returns a strategy that generates lists of distinct words, whose length
+/// is in the given range.
+pub fn word_list(len: impl Into<SizeRange>) -> impl Strategy<Value = Vec<String>> {
+    btree_set(word(), len).prop_map(|words| words.into_iter().collect())
+}
+
+/// Checks that no word repeats within the given window, if the window is
+/// no longer than the number of words that can be picked, and that every
+/// picked word can be picked (i.e. is not banned).
+///
+/// Vetoes and seed rotations within the window can cause repeats, so they
+/// should not be applied to the generator.
+pub fn check_window_distinct<T: AsRef<str>, const ROUNDS: usize, P: KeyedPermutation>(
+    wordle: &Wordle<'_, T, ROUNDS, P>,
+    window: u64,
+) -> Result<(), TestCaseError> {
+    let config = wordle.config();
+    let words = wordle.picker().items();
+    let banned: BTreeSet<&str> = config.banned.iter().map(|&i| words[i].as_ref()).collect();
+    let picks = wordle.window_words(window);
+    prop_assert_eq!(picks.len() as u64, wordle.window_len());
+    for pick in &picks {
+        prop_assert!(!banned.contains(pick), "banned word {} was picked", pick);
+    }
+    let pickable = (words.len() - config.banned.len()) as u64;
+    if wordle.window_len() <= pickable {
+        let distinct: BTreeSet<&str> = picks.iter().copied().collect();
+        prop_assert_eq!(
+            distinct.len(),
+            picks.len(),
+            "window {} repeats words",
+            window
+        );
+    }
+    Ok(())
+}
+
+/// Checks that two generators, e.g. built from the same configuration in
+/// different processes, pick the same words on every day of the range.
+pub fn check_deterministic<T, U, const ROUNDS: usize, P: KeyedPermutation>(
+    a: &Wordle<'_, T, ROUNDS, P>,
+    b: &Wordle<'_, U, ROUNDS, P>,
+    days: Range<u64>,
+) -> Result<(), TestCaseError>
+where
+    T: AsRef<str>,
+    U: AsRef<str>,
+{
+    for day in days {
+        prop_assert_eq!(a.get(day), b.get(day), "generators differ on day {}", day);
+    }
+    Ok(())
+}
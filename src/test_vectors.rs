@@ -0,0 +1,223 @@
+//! Known-answer test (KAT) vectors, to check alternative implementations
+//! (e.g. in JavaScript or Swift) against this crate byte for byte.
+//!
+//! [`generate`] writes the configuration of a generator, the Feistel network
+//! of each window of the given days, and the word of each day, in a canonical
+//! text format:
+//!
+//! ```text
+//! # wordle-generator known-answer tests v1
+//!
+//! [config]
+//! seed = 0707070707070707070707070707070707070707070707070707070707070707
+//! algorithm = V1
+//! rounds = 8
+//! window_len = 5
+//! ...
+//! commitment = 93b1170120279b9366cb08d46f47782c6e21b62fb4446b3428de00c6d4fe2d6b
+//!
+//! [window 0]
+//! seed = 0707070707070707070707070707070707070707070707070707070707070707
+//! domain_len = 16
+//! bit_len = 4
+//! rounds = 8
+//! keys = aaecf2af81ac9a3a fc0cdeb6602f4473 ffa72683b4e7a1e0 ...
+//! whitening = 0000000000000000 0000000000000000
+//! permute 0 = 11
+//! permute 1 = 7
+//! ...
+//!
+//! [days]
+//! 0 = ...
+//! ```
+//!
+//! Sections are separated by an empty line, and every line (including the
+//! last one) ends with `\n`. Integers are decimal, and seeds, keys and labels
+//! are lowercase hexadecimal. Lists are separated by single spaces, and empty
+//! lists are written as `-`.
+//!
+//! The `[config]` section lists every field of the [`WordleConfig`], and the
+//! [`Commitment`](crate::Commitment) to the configuration and the fingerprint
+//! of the words. The `capacity` and `appends` fields are only listed if the
+//! capacity is pinned, the `round_policy` field if a policy is set, and the
+//! `whitening` field if whitening is enabled, so the vectors of other
+//! generators don't change. Each
+//! `[window n]` section lists the round keys derived from the seed of the
+//! window, and the outputs of the network for the first [`FEISTEL_INPUTS`]
+//! inputs, before cycle walking. (Windowless generators use the same network
+//! on every day, so it is written once per seed.) The `[days]` section lists
+//! the index and the word of each day.
+//!
+//! The format never changes within a version, so generated files can be
+//! checked in and compared byte for byte.
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::{test_vectors, Seed, Wordle};
+//!
+//! let words = ["apple", "grape", "lemon", "mango", "peach"];
+//! let wordle = Wordle::builder(&words).seed(Seed::new([7; 32])).build().unwrap();
+//! let kat = test_vectors::generate(&words, &wordle.config(), 0..10).unwrap();
+//! assert!(kat.starts_with("# wordle-generator known-answer tests v1\n"));
+//! let (idx, word) = wordle.picker().get_with_index(9);
+//! assert!(kat.ends_with(&format!("\n9 = {} {}\n", idx, word)));
+//! ```
+
+use crate::{hex, Permutation, Seed, Wordle, WordleConfig, WordleError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use core::ops::Range;
+
+/// The number of inputs of each Feistel network whose outputs are written.
+pub const FEISTEL_INPUTS: u64 = 16;
+
+/// Generates the known-answer test vectors of the generator over `words` with
+/// the given configuration, for the given days.
+///
+/// # Errors
+///
+/// Returns an error if the configuration is invalid for the words.
+pub fn generate<T: AsRef<str>>(
+    words: &[T],
+    config: &WordleConfig,
+    days: Range<u64>,
+) -> Result<String, WordleError> {
+    let wordle = Wordle::builder(words)
+        .runtime_rounds(config.rounds)
+        .config(config.clone())
+        .build()?;
+    let mut out = String::new();
+    // Writing to a string never fails
+    write_vectors(&mut out, &wordle, config, days).unwrap();
+    Ok(out)
+}
+
+fn write_vectors<T: AsRef<str>>(
+    out: &mut String,
+    wordle: &Wordle<'_, T>,
+    config: &WordleConfig,
+    days: Range<u64>,
+) -> fmt::Result {
+    writeln!(out, "# wordle-generator known-answer tests v1")?;
+    writeln!(out)?;
+    writeln!(out, "[config]")?;
+    writeln!(out, "seed = {}", config.seed)?;
+    writeln!(out, "algorithm = {:?}", config.algorithm)?;
+    writeln!(out, "rounds = {}", config.rounds)?;
+    writeln!(out, "window_len = {}", config.window_len)?;
+    writeln!(out, "day_offset = {}", config.day_offset)?;
+    writeln!(out, "windowless = {}", config.windowless)?;
+    write!(out, "label = ")?;
+    match &config.label {
+        Some(label) => hex::write(out, label)?,
+        None => write!(out, "-")?,
+    }
+    writeln!(out)?;
+    write_list(out, "banned", &config.banned)?;
+    match &config.weights {
+        Some(weights) => write_list(out, "weights", weights)?,
+        None => writeln!(out, "weights = -")?,
+    }
+    writeln!(out, "repeat_gap = {}", config.repeat_gap)?;
+    let rotations: Vec<_> = config
+        .rotations
+        .iter()
+        .map(|(day, seed)| Pair(day, seed))
+        .collect();
+    write_list(out, "rotations", &rotations)?;
+    let vetoes: Vec<_> = config
+        .vetoes
+        .iter()
+        .map(|(day, count)| Pair(day, count))
+        .collect();
+    write_list(out, "vetoes", &vetoes)?;
+    if let Some(capacity) = config.capacity {
+        let appends: Vec<_> = config
+            .appends
+            .iter()
+            .map(|(day, first)| Pair(day, first))
+            .collect();
+        writeln!(out, "capacity = {}", capacity)?;
+        write_list(out, "appends", &appends)?;
+    }
+    if let Some(policy) = config.round_policy {
+        writeln!(out, "round_policy = {:?}", policy)?;
+    }
+    if config.whitening {
+        writeln!(out, "whitening = true")?;
+    }
+    writeln!(out, "words = {}", wordle.word_count())?;
+    writeln!(out, "commitment = {}", wordle.commitment())?;
+
+    let picker = wordle.picker();
+    let mut windows: Vec<(u64, &Seed)> = Vec::new();
+    for day in days.clone() {
+        let window = match picker.is_windowless() {
+            true => 0,
+            false => picker.locate(day).0,
+        };
+        let seed = picker.seed_at(day.wrapping_add(picker.day_offset()));
+        if !windows.contains(&(window, seed)) {
+            windows.push((window, seed));
+        }
+    }
+    for (window, seed) in windows {
+        let network = picker.network(seed, window);
+        let network = network.network();
+        writeln!(out)?;
+        writeln!(out, "[window {}]", window)?;
+        writeln!(out, "seed = {}", seed)?;
+        writeln!(out, "domain_len = {}", network.domain_len())?;
+        writeln!(out, "bit_len = {}", network.bit_len())?;
+        writeln!(out, "rounds = {}", network.rounds())?;
+        let keys: Vec<_> = network.keys().iter().map(|&key| Hex(key)).collect();
+        write_list(out, "keys", &keys)?;
+        let [pre, post] = network.whitening();
+        writeln!(out, "whitening = {} {}", Hex(pre), Hex(post))?;
+        let inputs = network.domain_len().min(FEISTEL_INPUTS.into()) as u64;
+        for input in 0..inputs {
+            writeln!(out, "permute {} = {}", input, network.permute(input))?;
+        }
+    }
+
+    writeln!(out)?;
+    writeln!(out, "[days]")?;
+    for day in days {
+        let (idx, word) = picker.get_with_index(day);
+        writeln!(out, "{} = {} {}", day, idx, word.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Writes a line with the items of a list separated by spaces, or `-` if the
+/// list is empty.
+fn write_list<V: fmt::Display>(out: &mut String, name: &str, values: &[V]) -> fmt::Result {
+    write!(out, "{} =", name)?;
+    if values.is_empty() {
+        write!(out, " -")?;
+    }
+    for value in values {
+        write!(out, " {}", value)?;
+    }
+    writeln!(out)
+}
+
+/// A 64-bit key, formatted as 16 hexadecimal digits.
+struct Hex(u64);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+/// A pair of values, formatted as `first:second`.
+struct Pair<A, B>(A, B);
+
+impl<A: fmt::Display, B: fmt::Display> fmt::Display for Pair<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.0, self.1)
+    }
+}
@@ -0,0 +1,171 @@
+//! Daily words backed by a verifiable random function (VRF).
+//!
+//! Unlike [`Wordle`](crate::Wordle), the word of each day is derived from
+//! a VRF over the day number, so the operator can publish a [`DayProof`]
+//! alongside each answer. Anyone with the [`VrfPublicKey`] can check that
+//! the answer was derived from the committed key, without learning the
+//! answers of other days.
+//!
+//! Days are independent of each other, so words can repeat on any two days,
+//! as in windowless mode. The VRF is the Schnorr-based VRF over Ristretto255
+//! of [schnorrkel](https://docs.rs/schnorrkel).
+//!
+//! # Examples
+//!
+//! ```
+//! use wordle_generator::vrf::VrfWordle;
+//! use wordle_generator::Seed;
+//!
+//! let words = ["apple", "grape", "lemon"];
+//! let wordle = VrfWordle::new(&words, Seed::new([7; 32])).unwrap();
+//! let (word, proof) = wordle.get(42);
+//!
+//! let public_key = wordle.public_key();
+//! assert!(public_key.verify(&words, 42, word, &proof));
+//! ```
+
+use crate::{hex, Seed, WordleError};
+use core::fmt;
+use schnorrkel::context::SigningTranscript;
+use schnorrkel::vrf::{VRFInOut, VRFPreOut, VRFProof};
+use schnorrkel::{ExpansionMode, Keypair, MiniSecretKey, PublicKey};
+
+/// The signing context of the VRF inputs.
+const CONTEXT: &[u8] = b"wordle-generator vrf";
+
+/// Returns the VRF input of the given day.
+fn transcript(day: u64) -> impl SigningTranscript {
+    schnorrkel::signing_context(CONTEXT).bytes(&day.to_le_bytes())
+}
+
+/// Returns the index of the word picked by the VRF output.
+fn index(inout: &VRFInOut, len: usize) -> usize {
+    let bytes = inout.make_bytes::<[u8; 16]>(b"wordle-generator index");
+    // The modulo bias is negligible for 128-bit outputs
+    (u128::from_le_bytes(bytes) % len as u128) as usize
+}
+
+/// A generator whose daily words come with a VRF proof.
+pub struct VrfWordle<'a, T> {
+    words: &'a [T],
+    keypair: Keypair,
+}
+
+impl<'a, T: AsRef<str>> VrfWordle<'a, T> {
+    /// Creates a generator over `words`, whose VRF key is derived from the seed.
+    ///
+    /// Returns an error if `words` is empty.
+    pub fn new(words: &'a [T], seed: Seed) -> Result<Self, WordleError> {
+        if words.is_empty() {
+            return Err(WordleError::EmptyWords);
+        }
+        let secret = seed.derive_child(b"vrf");
+        // A mini secret key accepts any 32 bytes
+        let secret = MiniSecretKey::from_bytes(secret.as_bytes()).unwrap();
+        Ok(Self {
+            words,
+            keypair: secret.expand_to_keypair(ExpansionMode::Ed25519),
+        })
+    }
+
+    /// Returns the public key that verifies the proofs of this generator.
+    pub fn public_key(&self) -> VrfPublicKey {
+        VrfPublicKey(self.keypair.public)
+    }
+
+    /// Returns the word for the given day, along with a proof that it was
+    /// derived from the key.
+    pub fn get(&self, day: u64) -> (&str, DayProof) {
+        let (inout, proof, _) = self.keypair.vrf_sign(transcript(day));
+        let word = self.words[index(&inout, self.words.len())].as_ref();
+        let proof = DayProof {
+            output: inout.to_preout(),
+            proof,
+        };
+        (word, proof)
+    }
+}
+
+impl<'a, T> fmt::Debug for VrfWordle<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Avoid leaking the secret key in logs
+        f.debug_struct("VrfWordle")
+            .field("word_count", &self.words.len())
+            .field("public_key", &VrfPublicKey(self.keypair.public))
+            .finish()
+    }
+}
+
+/// The public key of a [`VrfWordle`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct VrfPublicKey(PublicKey);
+
+impl VrfPublicKey {
+    /// Parses a public key from its 32 bytes, or returns `None` if the
+    /// bytes are not a valid key.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        PublicKey::from_bytes(bytes).ok().map(Self)
+    }
+
+    /// Returns the bytes of the public key.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    /// Returns whether `word` is the word of the given day, as proven by `proof`.
+    pub fn verify<T: AsRef<str>>(
+        &self,
+        words: &[T],
+        day: u64,
+        word: &str,
+        proof: &DayProof,
+    ) -> bool {
+        if words.is_empty() {
+            return false;
+        }
+        match self
+            .0
+            .vrf_verify(transcript(day), &proof.output, &proof.proof)
+        {
+            Ok((inout, _)) => words[index(&inout, words.len())].as_ref() == word,
+            Err(_) => false,
+        }
+    }
+}
+
+impl fmt::Debug for VrfPublicKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("VrfPublicKey(")?;
+        hex::write(f, &self.to_bytes())?;
+        f.write_str(")")
+    }
+}
+
+/// A proof that the word of a day was derived from a [`VrfPublicKey`].
+#[derive(Debug, Clone)]
+pub struct DayProof {
+    output: VRFPreOut,
+    proof: VRFProof,
+}
+
+impl DayProof {
+    /// The length of a serialized proof in bytes.
+    pub const LEN: usize = 96;
+
+    /// Parses a proof from its bytes, or returns `None` if they are not a
+    /// valid proof.
+    pub fn from_bytes(bytes: &[u8; Self::LEN]) -> Option<Self> {
+        Some(Self {
+            output: VRFPreOut::from_bytes(&bytes[..32]).ok()?,
+            proof: VRFProof::from_bytes(&bytes[32..]).ok()?,
+        })
+    }
+
+    /// Returns the bytes of the proof.
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0; Self::LEN];
+        bytes[..32].copy_from_slice(&self.output.to_bytes());
+        bytes[32..].copy_from_slice(&self.proof.to_bytes());
+        bytes
+    }
+}
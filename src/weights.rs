@@ -0,0 +1,49 @@
+use alloc::vec::Vec;
+use core::iter;
+
+/// The weights of a list of items, used to pick heavier items more often.
+///
+/// Each item owns a number of consecutive slots equal to its weight, so a
+/// uniform slot maps to an item with probability proportional to its weight.
+#[derive(Clone)]
+pub(crate) struct Weights {
+    // The exclusive end slot of each item
+    ends: Vec<u64>,
+}
+
+impl Weights {
+    /// Creates the weights of a list of items, or returns `None` if the total
+    /// weight overflows.
+    pub fn new(weights: &[u32]) -> Option<Self> {
+        let mut total = 0u64;
+        let mut ends = Vec::with_capacity(weights.len());
+        for &weight in weights {
+            total = total.checked_add(weight as u64)?;
+            ends.push(total);
+        }
+        Some(Self { ends })
+    }
+
+    /// Returns the weight of each item.
+    pub fn to_vec(&self) -> Vec<u32> {
+        let starts = iter::once(0).chain(self.ends.iter().copied());
+        let weights = self.ends.iter().zip(starts).map(|(end, start)| end - start);
+        // Each weight was created from a u32
+        weights.map(|weight| weight as u32).collect()
+    }
+
+    /// Returns the total number of slots.
+    pub fn total(&self) -> u64 {
+        self.ends.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the total number of slots of the first `len` items.
+    pub fn total_of(&self, len: usize) -> u64 {
+        len.checked_sub(1).map_or(0, |last| self.ends[last])
+    }
+
+    /// Returns the index of the item that owns the given slot.
+    pub fn item(&self, slot: u64) -> usize {
+        self.ends.partition_point(|&end| end <= slot)
+    }
+}
@@ -0,0 +1,807 @@
+use crate::{ListFingerprint, Normalizer};
+use alloc::borrow::Cow;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt;
+use core::ops::Deref;
+use core::str;
+#[cfg(feature = "gzip")]
+use flate2::read::MultiGzDecoder;
+#[cfg(feature = "zstd")]
+use ruzstd::decoding::StreamingDecoder;
+#[cfg(feature = "std")]
+use std::{fs, io, path::Path};
+
+/// The first bytes of a gzip member.
+#[cfg(feature = "gzip")]
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// The first bytes of a zstd frame.
+#[cfg(feature = "zstd")]
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+/// An owned list of words parsed from a text file with one word per line.
+///
+/// Lines may end with `\n` or `\r\n`, and a leading byte order mark is
+/// skipped. By default, lines are trimmed, blank lines are skipped, and
+/// everything after a `#` is a comment. See [`ParseOptions`] to change this.
+///
+/// Every word is non-empty and contains no whitespace, and the list has at
+/// least one word. A list dereferences to a slice of words, so it can be
+/// passed to [`Wordle::builder`](crate::Wordle::builder).
+///
+/// A word may be followed by a tab and its weight, e.g. `apple\t120`, so that
+/// the same file sets the frequency of each word. If the first word has a
+/// weight, every word must have one. Use
+/// [`WordleBuilder::from_word_list`](crate::WordleBuilder::from_word_list)
+/// to pick heavier words more often (see
+/// [`WordleBuilder::weights`](crate::WordleBuilder::weights)).
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{Seed, WordList, Wordle};
+///
+/// let text = "# Fruits\r\napple\r\n\r\n  grape  \r\nlemon # sour\r\n";
+/// let words = WordList::parse(text).unwrap();
+/// assert_eq!(words.as_slice(), ["apple", "grape", "lemon"]);
+///
+/// let wordle = Wordle::builder(&words).seed(Seed::new([0; 32])).build().unwrap();
+/// assert!(words.iter().any(|word| word == wordle.get(0)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WordList {
+    words: Vec<String>,
+    // The line number of each word
+    lines: Vec<usize>,
+    // The weight of each word, if the list is weighted
+    weights: Option<Vec<u32>>,
+}
+
+/// The options to parse a [`WordList`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOptions {
+    trim: bool,
+    comments: bool,
+    skip_blank_lines: bool,
+    duplicates: DuplicatePolicy,
+    normalizer: Normalizer,
+}
+
+impl ParseOptions {
+    /// Creates the default options, which trim lines, strip comments and skip
+    /// blank lines.
+    pub fn new() -> Self {
+        Self {
+            trim: true,
+            comments: true,
+            skip_blank_lines: true,
+            duplicates: DuplicatePolicy::Keep,
+            normalizer: Normalizer::new(),
+        }
+    }
+
+    /// Sets whether leading and trailing whitespace is removed from each line.
+    /// Otherwise, lines with surrounding whitespace are rejected.
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether everything from a `#` to the end of its line is ignored.
+    pub fn comments(mut self, comments: bool) -> Self {
+        self.comments = comments;
+        self
+    }
+
+    /// Sets whether lines without a word are skipped. Otherwise, they are
+    /// rejected.
+    pub fn skip_blank_lines(mut self, skip_blank_lines: bool) -> Self {
+        self.skip_blank_lines = skip_blank_lines;
+        self
+    }
+
+    /// Sets what to do with words that appear more than once, which defaults
+    /// to [`DuplicatePolicy::Keep`]. See [`WordList::dedup`].
+    pub fn duplicates(mut self, policy: DuplicatePolicy) -> Self {
+        self.duplicates = policy;
+        self
+    }
+
+    /// Sets the normalizer applied to each word before duplicates are found,
+    /// which doesn't change words by default. See [`WordList::normalize`].
+    pub fn normalizer(mut self, normalizer: Normalizer) -> Self {
+        self.normalizer = normalizer;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WordList {
+    /// Parses a list of words with the default options.
+    pub fn parse(text: &str) -> Result<Self, WordListError> {
+        Self::parse_with(text, &ParseOptions::new())
+    }
+
+    /// Parses a list of words with the given options.
+    pub fn parse_with(text: &str, options: &ParseOptions) -> Result<Self, WordListError> {
+        Self::parse_bytes(text.as_bytes(), options)
+    }
+
+    /// Reads and parses a list of words with the default options.
+    #[cfg(feature = "std")]
+    pub fn from_reader<R: io::Read>(reader: R) -> Result<Self, WordListError> {
+        Self::from_reader_with(reader, &ParseOptions::new())
+    }
+
+    /// Reads and parses a list of words with the given options.
+    #[cfg(feature = "std")]
+    pub fn from_reader_with<R: io::Read>(
+        mut reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, WordListError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).map_err(WordListError::Io)?;
+        Self::parse_bytes(&bytes, options)
+    }
+
+    /// Reads and parses the file at `path` with the default options.
+    ///
+    /// With the `gzip` and `zstd` features, compressed files are decompressed
+    /// first. They are recognized by their first bytes, which never start a
+    /// valid UTF-8 text.
+    #[cfg(feature = "std")]
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, WordListError> {
+        Self::from_path_with(path, &ParseOptions::new())
+    }
+
+    /// Reads and parses the file at `path` with the given options.
+    ///
+    /// See [`WordList::from_path`] for details.
+    #[cfg(feature = "std")]
+    pub fn from_path_with<P: AsRef<Path>>(
+        path: P,
+        options: &ParseOptions,
+    ) -> Result<Self, WordListError> {
+        let bytes = fs::read(path).map_err(WordListError::Io)?;
+        #[cfg(feature = "gzip")]
+        if bytes.starts_with(&GZIP_MAGIC) {
+            return Self::from_gzip_with(bytes.as_slice(), options);
+        }
+        #[cfg(feature = "zstd")]
+        if bytes.starts_with(&ZSTD_MAGIC) {
+            return Self::from_zstd_with(bytes.as_slice(), options);
+        }
+        Self::parse_bytes(&bytes, options)
+    }
+
+    /// Decompresses and parses a gzip-compressed list with the default
+    /// options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Write;
+    /// use flate2::write::GzEncoder;
+    /// use flate2::Compression;
+    /// use wordle_generator::WordList;
+    ///
+    /// let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+    /// encoder.write_all(b"apple\ngrape\n").unwrap();
+    /// let compressed = encoder.finish().unwrap();
+    ///
+    /// let words = WordList::from_gzip(compressed.as_slice()).unwrap();
+    /// assert_eq!(words.as_slice(), ["apple", "grape"]);
+    /// ```
+    #[cfg(feature = "gzip")]
+    pub fn from_gzip<R: io::Read>(reader: R) -> Result<Self, WordListError> {
+        Self::from_gzip_with(reader, &ParseOptions::new())
+    }
+
+    /// Decompresses and parses a gzip-compressed list with the given options.
+    #[cfg(feature = "gzip")]
+    pub fn from_gzip_with<R: io::Read>(
+        reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, WordListError> {
+        Self::from_reader_with(MultiGzDecoder::new(reader), options)
+    }
+
+    /// Decompresses and parses a zstd-compressed list with the default
+    /// options.
+    #[cfg(feature = "zstd")]
+    pub fn from_zstd<R: io::Read>(reader: R) -> Result<Self, WordListError> {
+        Self::from_zstd_with(reader, &ParseOptions::new())
+    }
+
+    /// Decompresses and parses a zstd-compressed list with the given options.
+    #[cfg(feature = "zstd")]
+    pub fn from_zstd_with<R: io::Read>(
+        reader: R,
+        options: &ParseOptions,
+    ) -> Result<Self, WordListError> {
+        let decoder = StreamingDecoder::new(reader)
+            .map_err(|err| WordListError::Io(io::Error::new(io::ErrorKind::InvalidData, err)))?;
+        Self::from_reader_with(decoder, options)
+    }
+
+    fn parse_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self, WordListError> {
+        let mut words = Vec::new();
+        let mut lines = Vec::new();
+        let mut weights = Vec::new();
+        parse_words(bytes, options, |line_number, word, weight| {
+            words.push(options.normalizer.normalize(word).into_owned());
+            lines.push(line_number);
+            weights.extend(weight);
+        })?;
+        let weights = match weights.is_empty() {
+            true => None,
+            false => Some(weights),
+        };
+        let mut list = Self {
+            words,
+            lines,
+            weights,
+        };
+        list.dedup(options.duplicates)?;
+        Ok(list)
+    }
+
+    /// Returns the words that appear earlier in the list, in the order of
+    /// the list.
+    pub fn duplicates(&self) -> Vec<Duplicate> {
+        let mut first = BTreeMap::new();
+        let mut duplicates = Vec::new();
+        for (index, word) in self.words.iter().enumerate() {
+            let first_index = *first.entry(word.as_str()).or_insert(index);
+            if first_index != index {
+                duplicates.push(Duplicate {
+                    index,
+                    line: self.lines[index],
+                    first_index,
+                    first_line: self.lines[first_index],
+                });
+            }
+        }
+        duplicates
+    }
+
+    /// Handles the words that appear more than once as specified by the
+    /// policy, and returns them. Words are compared exactly, so e.g. `apple`
+    /// and `Apple` are distinct.
+    ///
+    /// The indices of the returned duplicates refer to the list before any
+    /// words were dropped. See [`DuplicatePolicy`] for the effect of each
+    /// policy on published schedules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error with the first duplicate if the policy is
+    /// [`DuplicatePolicy::Reject`] and the list has duplicates. The list
+    /// doesn't change in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{DuplicatePolicy, WordList};
+    ///
+    /// let mut words = WordList::parse("apple\ngrape\napple\nlemon\n").unwrap();
+    /// let duplicates = words.dedup(DuplicatePolicy::DropLater).unwrap();
+    /// assert_eq!(duplicates[0].line, 3);
+    /// assert_eq!(duplicates[0].first_line, 1);
+    /// assert_eq!(words.as_slice(), ["apple", "grape", "lemon"]);
+    /// ```
+    pub fn dedup(&mut self, policy: DuplicatePolicy) -> Result<Vec<Duplicate>, WordListError> {
+        let duplicates = self.duplicates();
+        match policy {
+            DuplicatePolicy::Reject => {
+                if let Some(&duplicate) = duplicates.first() {
+                    return Err(WordListError::Duplicate(duplicate));
+                }
+            }
+            DuplicatePolicy::DropLater => {
+                // Both lists are filtered by the same sorted indices
+                for duplicate in duplicates.iter().rev() {
+                    self.words.remove(duplicate.index);
+                    self.lines.remove(duplicate.index);
+                    if let Some(weights) = &mut self.weights {
+                        weights.remove(duplicate.index);
+                    }
+                }
+            }
+            DuplicatePolicy::Keep => {}
+        }
+        Ok(duplicates)
+    }
+
+    /// Normalizes every word with the given normalizer.
+    ///
+    /// Words that only differ in their encoding or case become duplicates, so
+    /// the list should be checked for duplicates afterwards (see
+    /// [`WordList::dedup`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use wordle_generator::{CaseFolding, Normalizer, WordList};
+    ///
+    /// let mut words = WordList::parse("Árbol\nCAMPO\n").unwrap();
+    /// words.normalize(&Normalizer::new().case_folding(CaseFolding::Lowercase));
+    /// assert_eq!(words.as_slice(), ["árbol", "campo"]);
+    /// ```
+    pub fn normalize(&mut self, normalizer: &Normalizer) {
+        for word in &mut self.words {
+            if let Cow::Owned(normalized) = normalizer.normalize(word) {
+                *word = normalized;
+            }
+        }
+    }
+
+    /// Converts every word to lowercase, so that it follows the default rules
+    /// of a [`WordListValidator`].
+    pub fn make_lowercase(&mut self) {
+        for word in &mut self.words {
+            if word.chars().any(char::is_uppercase) {
+                *word = word.to_lowercase();
+            }
+        }
+    }
+
+    /// Returns the words of the list.
+    pub fn as_slice(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Returns the weight of each word, or `None` if the list has no weights.
+    pub fn weights(&self) -> Option<&[u32]> {
+        self.weights.as_deref()
+    }
+
+    /// Returns the fingerprint of the words once normalized by the given
+    /// normalizer, which doesn't cover their weights.
+    ///
+    /// See [`ListFingerprint`] for details.
+    pub fn fingerprint(&self, normalizer: &Normalizer) -> ListFingerprint {
+        ListFingerprint::new(&self.words, normalizer)
+    }
+
+    /// Returns the line number of the word at `index`, starting at 1.
+    pub fn line_of(&self, index: usize) -> Option<usize> {
+        self.lines.get(index).copied()
+    }
+
+    /// Converts the list into its words.
+    pub fn into_vec(self) -> Vec<String> {
+        self.words
+    }
+}
+
+/// Returns the word of a line, if any.
+/// Calls `f` with the line number, the word and the weight of each word of a
+/// list, without normalizing it.
+///
+/// Returns an error if a line is invalid, or if the list has no words.
+pub(crate) fn parse_words<'b>(
+    bytes: &'b [u8],
+    options: &ParseOptions,
+    mut f: impl FnMut(usize, &'b str, Option<u32>),
+) -> Result<(), WordListError> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    // Whether the list is weighted, which is determined by the first word
+    let mut weighted = None;
+    // A trailing newline doesn't start another line
+    let bytes = bytes.strip_suffix(b"\n").unwrap_or(bytes);
+    for (idx, line) in bytes.split(|&byte| byte == b'\n').enumerate() {
+        let line_number = idx + 1;
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let line =
+            str::from_utf8(line).map_err(|_| WordListError::InvalidUtf8 { line: line_number })?;
+        if let Some((word, weight)) = parse_line(line, line_number, options)? {
+            if *weighted.get_or_insert(weight.is_some()) != weight.is_some() {
+                return Err(WordListError::MixedWeights { line: line_number });
+            }
+            f(line_number, word, weight);
+        }
+    }
+    match weighted {
+        Some(_) => Ok(()),
+        None => Err(WordListError::Empty),
+    }
+}
+
+/// Returns the word of a line and its weight, if any.
+fn parse_line<'l>(
+    line: &'l str,
+    line_number: usize,
+    options: &ParseOptions,
+) -> Result<Option<(&'l str, Option<u32>)>, WordListError> {
+    let mut word = line;
+    if options.comments {
+        if let Some(start) = word.find('#') {
+            word = &word[..start];
+            // Whitespace before a comment is never part of the word
+            word = word.trim_end();
+        }
+    }
+    if options.trim {
+        word = word.trim();
+    }
+    let mut weight = None;
+    if let Some((before, after)) = word.split_once('\t') {
+        word = before;
+        let after = if options.trim {
+            after.trim_start()
+        } else {
+            after
+        };
+        let parsed = after.parse().ok();
+        weight = Some(parsed.ok_or(WordListError::InvalidWeight { line: line_number })?);
+        if options.trim {
+            word = word.trim_end();
+        }
+    }
+    if word.is_empty() {
+        // A weight without a word is never skipped
+        return match options.skip_blank_lines && weight.is_none() {
+            true => Ok(None),
+            false => Err(WordListError::BlankLine { line: line_number }),
+        };
+    }
+    if word.contains(char::is_whitespace) {
+        return Err(WordListError::Whitespace { line: line_number });
+    }
+    Ok(Some((word, weight)))
+}
+
+impl Deref for WordList {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl AsRef<[String]> for WordList {
+    fn as_ref(&self) -> &[String] {
+        &self.words
+    }
+}
+
+impl From<WordList> for Vec<String> {
+    fn from(list: WordList) -> Self {
+        list.words
+    }
+}
+
+impl IntoIterator for WordList {
+    type Item = String;
+    type IntoIter = alloc::vec::IntoIter<String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.into_iter()
+    }
+}
+
+impl<'l> IntoIterator for &'l WordList {
+    type Item = &'l String;
+    type IntoIter = core::slice::Iter<'l, String>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.words.iter()
+    }
+}
+
+/// What to do with the words that appear more than once in a [`WordList`].
+///
+/// A duplicated word is picked more often than the other words, and can be
+/// picked twice within a window. Policies differ in how they affect
+/// schedules that were already published with the list:
+///
+/// - [`DuplicatePolicy::Keep`] doesn't change the list, so published schedules
+///   don't change. To stop picking the duplicates while keeping every other
+///   day, ban their indices (see [`WordleBuilder::banned`]), which only changes
+///   the days on which they would have been picked.
+/// - [`DuplicatePolicy::DropLater`] removes every occurrence but the first,
+///   which shortens the list and shifts the indices of later words. This
+///   changes the whole schedule of a generator, so it should only be used
+///   before the first day is published.
+/// - [`DuplicatePolicy::Reject`] fails instead, e.g. to enforce that a curated
+///   list never has duplicates.
+///
+/// [`WordleBuilder::banned`]: crate::WordleBuilder::banned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DuplicatePolicy {
+    /// Fails if any word appears more than once.
+    Reject,
+    /// Keeps the first occurrence of every word, and removes the others.
+    DropLater,
+    /// Keeps every occurrence.
+    #[default]
+    Keep,
+}
+
+/// A word that appears earlier in a [`WordList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Duplicate {
+    /// The index of the word in the list.
+    pub index: usize,
+    /// The line number of the word, starting at 1.
+    pub line: usize,
+    /// The index of the first occurrence of the word.
+    pub first_index: usize,
+    /// The line number of the first occurrence of the word.
+    pub first_line: usize,
+}
+
+/// A set of rules that the words of a [`WordList`] must follow.
+///
+/// By default, every word must have as many letters as the first word, only
+/// contain alphabetic characters, and be lowercase. Lengths are counted in
+/// characters, not bytes.
+///
+/// # Examples
+///
+/// ```
+/// use wordle_generator::{ViolationKind, WordList, WordListValidator};
+///
+/// let words = WordList::parse("apple\nGrape\nkiwi\nle-on\n").unwrap();
+/// let report = WordListValidator::new().word_len(5).validate(&words).unwrap_err();
+/// let violations: Vec<_> = report
+///     .violations()
+///     .iter()
+///     .map(|violation| (violation.line, violation.kind))
+///     .collect();
+/// assert_eq!(
+///     violations,
+///     [
+///         (2, ViolationKind::NotLowercase),
+///         (3, ViolationKind::Length { expected: 5, found: 4 }),
+///         (4, ViolationKind::InvalidChar('-')),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WordListValidator {
+    word_len: Option<usize>,
+    alphabet: Option<String>,
+    allow_uppercase: bool,
+}
+
+impl WordListValidator {
+    /// Creates a validator with the default rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of characters of every word, instead of the number of
+    /// characters of the first word.
+    pub fn word_len(mut self, word_len: usize) -> Self {
+        self.word_len = Some(word_len);
+        self
+    }
+
+    /// Sets the characters that words may contain, instead of any alphabetic
+    /// character.
+    pub fn alphabet(mut self, alphabet: &str) -> Self {
+        self.alphabet = Some(String::from(alphabet));
+        self
+    }
+
+    /// Sets whether words may contain uppercase characters. See
+    /// [`WordList::make_lowercase`] to normalize a list instead.
+    pub fn allow_uppercase(mut self, allow_uppercase: bool) -> Self {
+        self.allow_uppercase = allow_uppercase;
+        self
+    }
+
+    /// Checks every word of the list, and returns a report of the words that
+    /// break a rule, if any.
+    ///
+    /// Each word is reported at most once per kind of violation, and only the
+    /// first invalid character of a word is reported.
+    pub fn validate(&self, words: &WordList) -> Result<(), ValidationReport> {
+        let expected_len = self
+            .word_len
+            .unwrap_or_else(|| words.words[0].chars().count());
+        let mut violations = Vec::new();
+        for (index, word) in words.words.iter().enumerate() {
+            let mut report = |kind| {
+                violations.push(Violation {
+                    index,
+                    line: words.lines[index],
+                    kind,
+                });
+            };
+            let len = word.chars().count();
+            if len != expected_len {
+                report(ViolationKind::Length {
+                    expected: expected_len,
+                    found: len,
+                });
+            }
+            let invalid = word.chars().find(|&c| match &self.alphabet {
+                Some(alphabet) => !alphabet.contains(c),
+                None => !c.is_alphabetic(),
+            });
+            if let Some(c) = invalid {
+                report(ViolationKind::InvalidChar(c));
+            }
+            if !self.allow_uppercase && word.chars().any(char::is_uppercase) {
+                report(ViolationKind::NotLowercase);
+            }
+        }
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(ValidationReport { violations }),
+        }
+    }
+}
+
+/// The words of a [`WordList`] that break the rules of a [`WordListValidator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    // Sorted by index
+    violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    /// Returns the violations, in the order of the list.
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} invalid words", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationReport {}
+
+/// A word of a [`WordList`] that breaks a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Violation {
+    /// The index of the word in the list.
+    pub index: usize,
+    /// The line number of the word, starting at 1.
+    pub line: usize,
+    /// The rule that the word breaks.
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.kind)
+    }
+}
+
+/// The rule broken by a word of a [`WordList`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ViolationKind {
+    /// The word doesn't have the expected number of characters.
+    Length {
+        /// The expected number of characters.
+        expected: usize,
+        /// The number of characters of the word.
+        found: usize,
+    },
+    /// The word contains a character outside of the alphabet.
+    InvalidChar(char),
+    /// The word contains uppercase characters.
+    NotLowercase,
+}
+
+impl fmt::Display for ViolationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationKind::Length { expected, found } => write!(
+                f,
+                "word should have {} letters, but has {}",
+                expected, found
+            ),
+            ViolationKind::InvalidChar(c) => write!(f, "invalid character {:?}", c),
+            ViolationKind::NotLowercase => f.write_str("word should be lowercase"),
+        }
+    }
+}
+
+/// An error that occurs while reading or parsing a [`WordList`].
+///
+/// Line numbers start at 1.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WordListError {
+    /// The list could not be read.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// A line is not valid UTF-8.
+    InvalidUtf8 {
+        /// The number of the line.
+        line: usize,
+    },
+    /// A line has no word, and either blank lines are not skipped or the
+    /// line has a weight.
+    BlankLine {
+        /// The number of the line.
+        line: usize,
+    },
+    /// A line has whitespace within or around its word.
+    Whitespace {
+        /// The number of the line.
+        line: usize,
+    },
+    /// The list has no words.
+    Empty,
+    /// A word appears more than once, and duplicates are rejected.
+    Duplicate(Duplicate),
+    /// The weight of a word is not an integer between 0 and 2^32 - 1.
+    InvalidWeight {
+        /// The number of the line.
+        line: usize,
+    },
+    /// A word has a weight but the first word doesn't, or vice versa.
+    MixedWeights {
+        /// The number of the line.
+        line: usize,
+    },
+}
+
+impl fmt::Display for WordListError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            WordListError::Io(err) => write!(f, "cannot read word list: {}", err),
+            WordListError::InvalidUtf8 { line } => {
+                write!(f, "line {} is not valid UTF-8", line)
+            }
+            WordListError::BlankLine { line } => write!(f, "line {} has no word", line),
+            WordListError::Whitespace { line } => {
+                write!(
+                    f,
+                    "line {} should have a single word without whitespace",
+                    line
+                )
+            }
+            WordListError::Empty => f.write_str("word list cannot be empty"),
+            WordListError::Duplicate(duplicate) => write!(
+                f,
+                "line {} repeats the word of line {}",
+                duplicate.line, duplicate.first_line
+            ),
+            WordListError::InvalidWeight { line } => {
+                write!(f, "line {} has an invalid weight", line)
+            }
+            WordListError::MixedWeights { line } => write!(
+                f,
+                "line {} should have a weight if and only if the first word has one",
+                line
+            ),
+        }
+    }
+}
+
+impl Error for WordListError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            WordListError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
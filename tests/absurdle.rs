@@ -0,0 +1,82 @@
+use lazy_static::lazy_static;
+use wordle_generator::LetterFeedback::Correct;
+use wordle_generator::{GuessError, Seed, Wordle};
+
+lazy_static! {
+    static ref WORDS: Vec<&'static str> = include_str!("words.txt").lines().collect();
+}
+
+#[test]
+fn keeps_largest_bucket() {
+    let wordle = Wordle::from_seed(&WORDS, 365, Seed::new([0; 32]));
+    let mut game = wordle.absurdle(0);
+    assert_eq!(game.candidates().count(), WORDS.len());
+    let feedback = game.guess("crane").unwrap().to_vec();
+    let remaining = game.candidates().count();
+    // Every remaining word gives the same feedback, which is the most common one
+    for word in game.candidates() {
+        let expected: [_; 5] = wordle_generator::evaluate("crane", word);
+        assert_eq!(feedback, expected);
+    }
+    let most_common = WORDS
+        .iter()
+        .map(|word| wordle_generator::evaluate::<5>("crane", word))
+        .fold(std::collections::HashMap::new(), |mut counts, f| {
+            *counts.entry(f).or_insert(0) += 1;
+            counts
+        })
+        .into_values()
+        .max()
+        .unwrap();
+    assert_eq!(remaining, most_common);
+}
+
+#[test]
+fn deterministic() {
+    let play = |seed, day| {
+        let wordle = Wordle::from_seed(&WORDS[..500], 365, Seed::new([seed; 32]));
+        let mut game = wordle.absurdle(day);
+        for guess in ["those", "dingy", "plumb"] {
+            game.guess(guess).unwrap();
+        }
+        game.candidates().map(String::from).collect::<Vec<_>>()
+    };
+    assert_eq!(play(0, 3), play(0, 3));
+    // Ties between the two singleton buckets depend on the seed and day
+    let words = ["those", "whose"];
+    let remaining = |seed, day| {
+        let wordle = Wordle::from_seed(&words, 2, Seed::new([seed; 32]));
+        let mut game = wordle.absurdle(day);
+        game.guess("those").unwrap();
+        let remaining = game.candidates().next().unwrap().to_string();
+        remaining
+    };
+    let outcomes: std::collections::HashSet<_> = (0..8)
+        .map(|day| remaining(0, day))
+        .chain((1..8).map(|seed| remaining(seed, 0)))
+        .collect();
+    assert_eq!(outcomes.len(), 2);
+}
+
+#[test]
+fn ends_when_guessed() {
+    let words = ["those", "whose"];
+    let wordle = Wordle::from_seed(&words, 2, Seed::new([0; 32]));
+    let mut game = wordle.absurdle(0);
+    assert_eq!(
+        game.guess("tho"),
+        Err(GuessError::WordLength {
+            expected: 5,
+            found: 3
+        })
+    );
+    // Both answers are singleton buckets, so the guess is never correct
+    let feedback = game.guess("those").unwrap().to_vec();
+    assert_ne!(feedback, [Correct; 5]);
+    assert_eq!(game.candidates().collect::<Vec<_>>(), ["whose"]);
+    assert!(!game.is_over());
+    game.guess("whose").unwrap();
+    assert!(game.is_over());
+    assert_eq!(game.guess("whose"), Err(GuessError::GameOver));
+    assert_eq!(game.guesses().len(), 2);
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "cli")]
+
+use std::process::Command;
+use wordle_generator::{Normalizer, WordList};
+
+const SEED: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+fn list_fingerprint() -> String {
+    let words = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/words.txt");
+    WordList::from_path(words)
+        .unwrap()
+        .fingerprint(&Normalizer::new())
+        .to_string()
+}
+
+fn wordle_gen(args: &[&str]) -> (bool, String) {
+    let words = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/words.txt");
+    let output = Command::new(env!("CARGO_BIN_EXE_wordle-gen"))
+        .args(args)
+        .args(["--seed", SEED, "--words", words, "--window-len", "365"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    (output.status.success(), stdout)
+}
+
+#[test]
+fn get_day() {
+    assert_eq!(wordle_gen(&["get", "--day", "0"]), (true, "maple\n".into()));
+    assert_eq!(
+        wordle_gen(&["get", "--day", "364"]),
+        (true, "delve\n".into())
+    );
+}
+
+#[test]
+fn get_date() {
+    let args = ["get", "--date", "2022-01-02", "--epoch", "2022-01-01"];
+    assert_eq!(wordle_gen(&args), (true, "swung\n".into()));
+    let args = ["get", "--date", "2021-12-31", "--epoch", "2022-01-01"];
+    assert!(!wordle_gen(&args).0);
+}
+
+#[test]
+fn export_csv() {
+    let args = ["export", "--from", "1970-01-01", "--to", "1970-01-02"];
+    let fingerprint = list_fingerprint();
+    let expected = format!(
+        "date,day,puzzle_id,window,word,list_fingerprint\n\
+         1970-01-01,0,1,0,maple,{fingerprint}\n\
+         1970-01-02,1,2,0,swung,{fingerprint}\n"
+    );
+    assert_eq!(wordle_gen(&args), (true, expected));
+}
+
+#[test]
+fn export_json_hmac() {
+    let args = [
+        "export",
+        "--from",
+        "1971-01-01",
+        "--to",
+        "1971-01-01",
+        "--format",
+        "json",
+        "--hmac-key",
+        "secret",
+    ];
+    let (success, stdout) = wordle_gen(&args);
+    assert!(success);
+    let rows: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(rows[0]["day"], 365);
+    assert_eq!(rows[0]["window"], 1);
+    assert!(rows[0].get("word").is_none());
+    assert_eq!(rows[0]["word_hmac"].as_str().unwrap().len(), 64);
+    assert_eq!(rows[0]["list_fingerprint"], list_fingerprint());
+}
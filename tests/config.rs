@@ -0,0 +1,103 @@
+use wordle_generator::{Algorithm, DailyPicker, Seed, WordleBuilder, WordleError};
+
+fn configured(numbers: &[u32]) -> DailyPicker<'_, u32, 6> {
+    let mut picker = DailyPicker::builder(numbers)
+        .seed(Seed::new([0x42; 32]))
+        .window_len(60)
+        .day_offset(19_000)
+        .algorithm(Algorithm::V2)
+        .label(b"daily-word")
+        .banned([3, 7])
+        .weights((0..200).map(|n| n % 5))
+        .repeat_gap(10)
+        .rotate_seed(100, Seed::new([0x43; 32]))
+        .veto(12)
+        .rounds::<6>()
+        .build_picker()
+        .unwrap();
+    picker.veto(12);
+    picker.veto(40);
+    picker
+}
+
+#[test]
+fn config_round_trip() {
+    let numbers: Vec<u32> = (0..200).collect();
+    let picker = configured(&numbers);
+    let config = picker.config();
+    assert_eq!(config.day_offset, 19_000);
+    assert_eq!(config.rotations, [(100, Seed::new([0x43; 32]))]);
+    assert_eq!(config.vetoes, [(12, 2), (40, 1)]);
+    assert_eq!(config.label.as_deref(), Some(&b"daily-word"[..]));
+
+    let restored = WordleBuilder::new(&numbers)
+        .rounds::<6>()
+        .config(config.clone())
+        .build_picker()
+        .unwrap();
+    assert_eq!(restored.config(), config);
+    assert_eq!(restored.get_range(0..200), picker.get_range(0..200));
+}
+
+#[test]
+fn config_rounds_mismatch() {
+    let numbers: Vec<u32> = (0..200).collect();
+    let config = configured(&numbers).config();
+    let result = DailyPicker::builder(&numbers).config(config).build_picker();
+    assert_eq!(
+        result.err(),
+        Some(WordleError::RoundsMismatch {
+            expected: 8,
+            found: 6
+        })
+    );
+}
+
+#[test]
+fn config_runtime_rounds() {
+    let numbers: Vec<u32> = (0..200).collect();
+    let picker = configured(&numbers);
+    let config = picker.config();
+    let restored = DailyPicker::builder(&numbers)
+        .runtime_rounds(config.rounds)
+        .config(config.clone())
+        .build_picker()
+        .unwrap();
+    assert_eq!(restored.config(), config);
+    assert_eq!(restored.get_range(0..200), picker.get_range(0..200));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn config_serde() {
+    use wordle_generator::WordleConfig;
+
+    let numbers: Vec<u32> = (0..200).collect();
+    let config = configured(&numbers).config();
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json["version"], 4);
+    assert_eq!(json["seed"], Seed::new([0x42; 32]).to_string());
+    assert_eq!(json["algorithm"], "V2");
+
+    let restored: WordleConfig = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(restored, config);
+
+    // Version 1 configurations have no label, and earlier versions have no
+    // pinned capacity, round policy or whitening
+    let mut older = json.clone();
+    older["version"] = 1.into();
+    for field in ["label", "capacity", "appends", "round_policy", "whitening"] {
+        older.as_object_mut().unwrap().remove(field);
+    }
+    let restored: WordleConfig = serde_json::from_value(older).unwrap();
+    assert_eq!(restored.label, None);
+    assert_eq!(restored.capacity, None);
+    assert!(restored.appends.is_empty());
+    assert_eq!(restored.round_policy, None);
+    assert!(!restored.whitening);
+
+    let mut future = json;
+    future["version"] = 5.into();
+    let err = serde_json::from_value::<WordleConfig>(future).unwrap_err();
+    assert!(err.to_string().contains("unsupported config version 5"));
+}
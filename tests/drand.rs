@@ -0,0 +1,81 @@
+#![cfg(feature = "drand")]
+
+use wordle_generator::drand::{DrandBeacon, DrandChain, DrandError, DrandSchedule};
+use wordle_generator::{Seed, Wordle};
+
+const WORDS: [&str; 5] = ["apple", "grape", "lemon", "mango", "peach"];
+
+/// 2024-01-01T00:00:00Z
+const EPOCH: u64 = 1_704_067_200;
+
+#[test]
+fn rounds() {
+    let chain = DrandChain::DEFAULT;
+    assert_eq!(chain.round_at(chain.genesis_time - 1), 0);
+    assert_eq!(chain.round_at(chain.genesis_time), 1);
+    assert_eq!(chain.round_at(chain.genesis_time + 29), 1);
+    assert_eq!(chain.round_at(chain.genesis_time + 30), 2);
+
+    let schedule = DrandSchedule::new(chain, EPOCH);
+    // One day has 2880 rounds of 30 seconds
+    assert_eq!(schedule.round_for_day(1) - schedule.round_for_day(0), 2880);
+    let wordle = Wordle::from_seed(&WORDS, 5, Seed::new([0; 32]));
+    assert_eq!(
+        schedule.round_for_window(&wordle, 2),
+        schedule.round_for_day(10)
+    );
+}
+
+#[test]
+fn parse_beacon() {
+    let json = r#"{
+        "round": 3621206,
+        "randomness": "6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d",
+        "signature": "00"
+    }"#;
+    let beacon = DrandBeacon::from_json(json).unwrap();
+    assert_eq!(beacon, DrandBeacon::new(3_621_206, &[0]));
+
+    let tampered = json.replace("6e34", "6e35");
+    assert_eq!(
+        DrandBeacon::from_json(&tampered),
+        Err(DrandError::RandomnessMismatch)
+    );
+    assert_eq!(
+        DrandBeacon::from_json(r#"{"round": 1, "randomness": "0"}"#),
+        Err(DrandError::InvalidJson)
+    );
+}
+
+#[test]
+fn apply_beacons() {
+    let schedule = DrandSchedule::new(DrandChain::DEFAULT, EPOCH);
+    let mut wordle = Wordle::from_seed(&WORDS, 5, Seed::new([0; 32]));
+    let before: Vec<_> = wordle
+        .get_range(0..10)
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    let round = schedule.round_for_window(&wordle, 1);
+    let wrong = DrandBeacon::new(round + 1, b"signature");
+    assert_eq!(
+        schedule.apply(&mut wordle, 1, &wrong, None),
+        Err(DrandError::WrongRound {
+            expected: round,
+            found: round + 1
+        })
+    );
+
+    let beacon = DrandBeacon::new(round, b"signature");
+    schedule.apply(&mut wordle, 1, &beacon, None).unwrap();
+    // The first window is unchanged
+    assert_eq!(wordle.get_range(0..5), before[..5]);
+    let mut expected = Wordle::from_seed(&WORDS, 5, Seed::new([0; 32]));
+    expected.rotate_seed(5, beacon.seed(None));
+    assert_eq!(wordle.get_range(5..10), expected.get_range(5..10));
+
+    // A secret changes the derived seed
+    let secret = Seed::new([1; 32]);
+    assert_ne!(beacon.seed(Some(&secret)), beacon.seed(None));
+}
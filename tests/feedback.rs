@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use wordle_generator::LetterFeedback::{self, Absent, Correct, Present};
+use wordle_generator::{evaluate, try_evaluate, WordleError};
+
+#[test]
+fn simple() {
+    assert_eq!(evaluate("crane", "crane"), [Correct; 5]);
+    assert_eq!(evaluate("fjord", "blimp"), [Absent; 5]);
+    assert_eq!(
+        evaluate("react", "crate"),
+        [Present, Present, Correct, Present, Present]
+    );
+    assert_eq!(
+        evaluate("crate", "trace"),
+        [Present, Correct, Correct, Present, Correct]
+    );
+}
+
+#[test]
+fn duplicate_letters() {
+    // A green takes precedence over an earlier yellow
+    assert_eq!(
+        evaluate("lolly", "hello"),
+        [Absent, Present, Correct, Correct, Absent]
+    );
+    // Extra occurrences are gray
+    assert_eq!(
+        evaluate("eerie", "crane"),
+        [Absent, Absent, Present, Absent, Correct]
+    );
+    assert_eq!(
+        evaluate("sassy", "essay"),
+        [Present, Present, Correct, Absent, Correct]
+    );
+    // Yellows are assigned from left to right
+    assert_eq!(
+        evaluate("allee", "eagle"),
+        [Present, Present, Absent, Present, Correct]
+    );
+    assert_eq!(
+        evaluate("kebab", "abbey"),
+        [Absent, Present, Correct, Present, Present]
+    );
+    assert_eq!(
+        evaluate("abbey", "kebab"),
+        [Present, Present, Correct, Present, Absent]
+    );
+}
+
+#[test]
+fn unicode() {
+    assert_eq!(evaluate("ñandú", "ñandú"), [Correct; 5]);
+    assert_eq!(evaluate::<3>("éte", "eté"), [Present, Correct, Present]);
+}
+
+#[test]
+fn lengths() {
+    assert_eq!(
+        try_evaluate::<5>("crane", "cranes"),
+        Err(WordleError::WordLength {
+            expected: 5,
+            found: 6
+        })
+    );
+    assert_eq!(
+        try_evaluate::<5>("cran", "crane"),
+        Err(WordleError::WordLength {
+            expected: 5,
+            found: 4
+        })
+    );
+}
+
+#[test]
+#[should_panic]
+fn length_mismatch_panics() {
+    evaluate::<5>("crane", "crab");
+}
+
+/// Computes the feedback by counting the unmatched letters of the answer.
+fn reference(guess: &[char], answer: &[char]) -> Vec<LetterFeedback> {
+    let mut remaining = HashMap::new();
+    for (g, a) in guess.iter().zip(answer) {
+        if g != a {
+            *remaining.entry(a).or_insert(0) += 1;
+        }
+    }
+    guess
+        .iter()
+        .zip(answer)
+        .map(|(g, a)| {
+            if g == a {
+                return Correct;
+            }
+            match remaining.get_mut(g) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    Present
+                }
+                _ => Absent,
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn exhaustive() {
+    // Every pair of 4-letter words over a 3-letter alphabet
+    let words: Vec<String> = (0..81)
+        .map(|mut n| {
+            (0..4)
+                .map(|_| {
+                    let letter = (b'a' + n % 3) as char;
+                    n /= 3;
+                    letter
+                })
+                .collect()
+        })
+        .collect();
+    for guess in &words {
+        for answer in &words {
+            let expected = reference(
+                &guess.chars().collect::<Vec<_>>(),
+                &answer.chars().collect::<Vec<_>>(),
+            );
+            assert_eq!(evaluate::<4>(guess, answer).to_vec(), expected);
+        }
+    }
+}
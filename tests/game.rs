@@ -0,0 +1,130 @@
+use wordle_generator::LetterFeedback::{Absent, Correct, Present};
+use wordle_generator::{
+    CaseFolding, Game, GameStatus, GuessError, Keyboard, LetterState, Normalizer, Seed, Wordle,
+};
+
+#[test]
+fn win() {
+    let mut game = Game::new(3, "those");
+    assert_eq!(game.status(), GameStatus::InProgress);
+    assert_eq!(
+        game.guess("geese"),
+        Ok(&[Absent, Absent, Absent, Correct, Correct][..])
+    );
+    assert_eq!(game.remaining_attempts(), 5);
+    assert_eq!(game.guess("those"), Ok(&[Correct; 5][..]));
+    assert_eq!(game.status(), GameStatus::Won);
+    assert_eq!(game.guess("those"), Err(GuessError::GameOver));
+    assert_eq!(game.guesses().len(), 2);
+    assert_eq!(game.guesses()[0].word(), "geese");
+    assert!(game.guesses()[1].is_correct());
+}
+
+#[test]
+fn lose() {
+    let mut game = Game::new(0, "those").max_guesses(2);
+    assert_eq!(
+        game.guess("thos"),
+        Err(GuessError::WordLength {
+            expected: 5,
+            found: 4
+        })
+    );
+    game.guess("crane").unwrap();
+    assert!(!game.is_over());
+    game.guess("crate").unwrap();
+    assert_eq!(game.status(), GameStatus::Lost);
+    assert_eq!(game.remaining_attempts(), 0);
+}
+
+#[test]
+fn hard_mode() {
+    let mut game = Game::new(0, "those").hard_mode(true);
+    assert_eq!(
+        game.guess("shoes"),
+        Ok(&[Present, Correct, Correct, Present, Absent][..])
+    );
+    assert_eq!(
+        game.guess("crane"),
+        Err(GuessError::MisplacedLetter {
+            letter: 'h',
+            position: 1
+        })
+    );
+    assert_eq!(game.guess("choke"), Err(GuessError::MissingLetter('s')));
+    assert_eq!(game.guess("shows"), Err(GuessError::MissingLetter('e')));
+    // Invalid guesses don't count
+    assert_eq!(game.remaining_attempts(), 5);
+    game.guess("whose").unwrap();
+    game.guess("those").unwrap();
+    assert_eq!(game.status(), GameStatus::Won);
+}
+
+#[test]
+fn keyboard() {
+    let mut game = Game::new(0, "those");
+    game.guess("shoes").unwrap();
+    game.guess("chase").unwrap();
+    let keyboard = game.keyboard();
+    // The second "s" of "shoes" is absent, but the letter is present
+    assert_eq!(keyboard.state('s'), LetterState::Correct);
+    assert_eq!(keyboard.state('h'), LetterState::Correct);
+    assert_eq!(keyboard.state('e'), LetterState::Correct);
+    assert_eq!(keyboard.state('c'), LetterState::Absent);
+    assert_eq!(keyboard.state('t'), LetterState::Unknown);
+    let letters: String = keyboard.iter().map(|(letter, _)| letter).collect();
+    assert_eq!(letters, "acehos");
+
+    // Correct letters stay correct after a later yellow
+    let mut keyboard = Keyboard::new();
+    keyboard.update("ab", &[Correct, Absent]);
+    keyboard.update("ba", &[Absent, Present]);
+    assert_eq!(keyboard.state('a'), LetterState::Correct);
+    assert_eq!(keyboard.state('b'), LetterState::Absent);
+}
+
+#[test]
+fn from_generator() {
+    let words = ["apple", "grape", "lemon"];
+    let wordle = Wordle::from_seed(&words, 3, Seed::new([0; 32]));
+    let game = wordle.new_game(1);
+    assert_eq!(game.day(), 1);
+    assert_eq!(game.answer(), wordle.get(1));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serde() {
+    let mut game = Game::new(7, "those").hard_mode(true);
+    game.guess("shoes").unwrap();
+    let json = serde_json::to_string(&game).unwrap();
+    let mut restored: Game = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, game);
+    assert_eq!(restored.guess("crane"), game.guess("crane"));
+}
+
+#[test]
+fn normalizer() {
+    let normalizer = Normalizer::new().case_folding(CaseFolding::Turkic);
+    let mut game = Game::new(0, "KIYI").normalizer(normalizer);
+    assert_eq!(game.answer(), "kıyı");
+    assert_eq!(
+        game.guess("kiyi"),
+        Ok(&[Correct, Absent, Correct, Absent][..])
+    );
+    assert_eq!(game.guess("KIYI"), Ok(&[Correct; 4][..]));
+    assert_eq!(game.guesses()[1].word(), "kıyı");
+
+    let words = ["ıslak", "irmak"];
+    let wordle = Wordle::builder(&words)
+        .seed(Seed::new([0; 32]))
+        .normalizer(normalizer)
+        .build()
+        .unwrap();
+    assert!(wordle.is_valid_guess("ISLAK"));
+    assert!(wordle.is_valid_guess("İRMAK"));
+    assert!(!wordle.is_valid_guess("IRMAK"));
+    let day = (0..).find(|&day| wordle.get(day) == "ıslak").unwrap();
+    let mut game = wordle.new_game(day);
+    assert!(game.guess("ISLAK").is_ok_and(|f| f == [Correct; 5]));
+}
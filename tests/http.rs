@@ -0,0 +1,57 @@
+#![cfg(feature = "http")]
+
+use axum::body::{to_bytes, Body};
+use axum::http::{Request, StatusCode};
+use serde_json::Value;
+use std::time::{Duration, SystemTime};
+use tower::ServiceExt;
+use wordle_generator::http::WordleService;
+use wordle_generator::{Seed, Wordle};
+
+const WORDS: [&str; 5] = ["apple", "grape", "lemon", "mango", "peach"];
+
+async fn request(uri: &str) -> (StatusCode, Value) {
+    // Day 0 started ten and a half days ago
+    let epoch = SystemTime::now() - Duration::from_secs(10 * 86_400 + 43_200);
+    let wordle = Wordle::from_seed(&WORDS, 5, Seed::new([7; 32]));
+    let router = WordleService::new(wordle, epoch).into_router();
+    let request = Request::get(uri).body(Body::empty()).unwrap();
+    let response = router.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn today() {
+    let wordle = Wordle::from_seed(&WORDS, 5, Seed::new([7; 32]));
+    let (status, body) = request("/v1/today").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["day"], 10);
+    assert_eq!(body["word"], wordle.get(10));
+}
+
+#[tokio::test]
+async fn day() {
+    let wordle = Wordle::from_seed(&WORDS, 5, Seed::new([7; 32]));
+    let (status, body) = request("/v1/day/3").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["word"], wordle.get(3));
+
+    let (status, body) = request("/v1/day/11").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(body.get("word").is_none());
+}
+
+#[tokio::test]
+async fn verify() {
+    let wordle = Wordle::from_seed(&WORDS, 5, Seed::new([7; 32]));
+    let uri = format!("/v1/verify?day=4&word={}", wordle.get(4));
+    assert_eq!(request(&uri).await.1["correct"], true);
+    let wrong = WORDS.iter().find(|&&word| word != wordle.get(4)).unwrap();
+    let uri = format!("/v1/verify?day=4&word={}", wrong);
+    assert_eq!(request(&uri).await.1["correct"], false);
+
+    let (status, _) = request("/v1/verify?day=11&word=apple").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,45 @@
+#![cfg(any(feature = "list-en-5", feature = "list-en-6", feature = "list-es-5"))]
+
+use std::collections::BTreeSet;
+use wordle_generator::{lists, Seed, WordList, WordListValidator, Wordle};
+
+fn check(list: &[&str], word_len: usize) {
+    let words = WordList::parse(&list.join("\n")).unwrap();
+    assert_eq!(words.len(), list.len());
+    WordListValidator::new()
+        .word_len(word_len)
+        .validate(&words)
+        .unwrap();
+    assert!(words.duplicates().is_empty());
+    let unique: BTreeSet<_> = list.iter().collect();
+    assert_eq!(unique.len(), list.len());
+
+    let wordle = Wordle::builder(list)
+        .seed(Seed::new([0; 32]))
+        .build()
+        .unwrap();
+    let picked: BTreeSet<_> = wordle.get_range(0..list.len() as u64).into_iter().collect();
+    assert_eq!(picked.len(), list.len());
+}
+
+#[test]
+#[cfg(feature = "list-en-5")]
+fn en_5() {
+    check(lists::EN_5, 5);
+    assert!(lists::EN_5.is_sorted());
+    assert_eq!(lists::EN_5.len(), 5757);
+}
+
+#[test]
+#[cfg(feature = "list-en-6")]
+fn en_6() {
+    check(lists::EN_6, 6);
+    assert!(lists::EN_6.is_sorted());
+}
+
+#[test]
+#[cfg(feature = "list-es-5")]
+fn es_5() {
+    check(lists::ES_5, 5);
+    assert!(lists::ES_5.contains(&"niños"));
+}
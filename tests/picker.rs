@@ -0,0 +1,310 @@
+use std::collections::{HashMap, HashSet};
+use wordle_generator::{DailyPicker, Seed, Wordle, WordleError};
+
+#[derive(Debug, PartialEq)]
+struct Question {
+    prompt: &'static str,
+    answer: u32,
+}
+
+#[test]
+fn picks_arbitrary_items() {
+    let seed = Seed::new([0x42; 32]);
+    let numbers: Vec<u32> = (0..1000).collect();
+    let picker = DailyPicker::from_seed(&numbers, 1000, seed);
+
+    let mut seen = HashSet::new();
+    for day in 0..1000 {
+        assert!(seen.insert(*picker.get(day)));
+    }
+    assert_eq!(seen.len(), numbers.len());
+}
+
+#[test]
+fn picks_structs() {
+    let seed = Seed::new([0x42; 32]);
+    let questions = [
+        Question {
+            prompt: "2 + 2",
+            answer: 4,
+        },
+        Question {
+            prompt: "3 * 3",
+            answer: 9,
+        },
+        Question {
+            prompt: "10 - 7",
+            answer: 3,
+        },
+    ];
+    let picker = DailyPicker::from_seed(&questions, 3, seed);
+
+    let (idx, question) = picker.get_with_index(17);
+    assert_eq!(&questions[idx], question);
+    assert_eq!(picker.day_of_index(idx, 5), Some(17));
+    assert_eq!(picker.day_of_index(3, 5), None);
+}
+
+#[test]
+fn wordle_wraps_picker() {
+    let seed = Seed::new([0; 32]);
+    let words = ["apple", "grape", "lemon", "melon", "peach"];
+    let picker = DailyPicker::from_seed(&words, 5, seed);
+    let wordle = Wordle::from_seed(&words, 5, seed);
+
+    for day in 0..20 {
+        assert_eq!(*picker.get(day), wordle.get(day));
+    }
+    assert_eq!(Wordle::from(picker).get(3), wordle.get(3));
+}
+
+#[test]
+fn banned_items_are_skipped() {
+    let seed = Seed::new([0x13; 32]);
+    let numbers: Vec<u32> = (0..500).collect();
+    let banned = [3, 17, 42, 99, 250, 499];
+    let picker = DailyPicker::from_seed(&numbers, 400, seed);
+    let filtered = DailyPicker::builder(&numbers)
+        .seed(seed)
+        .window_len(400)
+        .banned(banned)
+        .build_picker()
+        .unwrap();
+
+    for window in 0..3 {
+        let mut seen = HashSet::new();
+        for day in window * 400..(window + 1) * 400 {
+            let (idx, _) = filtered.get_with_index(day);
+            assert!(!banned.contains(&idx));
+            assert!(seen.insert(idx));
+            assert_eq!(filtered.day_of_index(idx, window), Some(day));
+
+            let (original, _) = picker.get_with_index(day);
+            if !banned.contains(&original) && !banned.contains(&((day % 400) as usize)) {
+                assert_eq!(idx, original);
+            }
+        }
+    }
+    assert_eq!(filtered.day_of_index(42, 0), None);
+}
+
+#[test]
+fn banned_words() {
+    let seed = Seed::new([0x13; 32]);
+    let words = ["apple", "grape", "lemon", "melon", "peach"];
+    let wordle = Wordle::builder(&words)
+        .seed(seed)
+        .banned_words(["grape", "kiwis"])
+        .build()
+        .unwrap();
+
+    for day in 0..100 {
+        assert_ne!(wordle.get(day), "grape");
+    }
+    let all = Wordle::builder(&words).banned(0..5).build();
+    assert!(matches!(all, Err(WordleError::AllBanned)));
+    let out_of_bounds = Wordle::builder(&words).banned([5]).build();
+    assert!(matches!(
+        out_of_bounds,
+        Err(WordleError::BannedOutOfBounds(5))
+    ));
+}
+
+#[test]
+fn veto_replaces_single_day() {
+    let seed = Seed::new([0x77; 32]);
+    let numbers: Vec<u32> = (0..300).collect();
+    let picker = DailyPicker::from_seed(&numbers, 100, seed);
+    let mut vetoed = DailyPicker::from_seed(&numbers, 100, seed);
+
+    let original = *picker.get(150);
+    let first = *vetoed.veto(150);
+    let second = *vetoed.veto(150);
+    assert_ne!(first, original);
+    assert_ne!(second, original);
+    assert_ne!(second, first);
+    assert_eq!(*vetoed.get(150), second);
+
+    let window: HashSet<_> = (100..200).map(|day| *vetoed.get(day)).collect();
+    assert_eq!(window.len(), 100);
+    for day in (0..400).filter(|&day| day != 150) {
+        assert_eq!(vetoed.get(day), picker.get(day));
+    }
+    assert_eq!(vetoed.day_of_index(second as usize, 1), Some(150));
+    assert_eq!(vetoed.day_of_index(original as usize, 1), None);
+
+    let built = DailyPicker::builder(&numbers)
+        .seed(seed)
+        .window_len(100)
+        .veto(150)
+        .veto(150)
+        .build_picker()
+        .unwrap();
+    assert_eq!(*built.get(150), second);
+    let collected: Vec<_> = built.iter_from(140).take(20).map(|(_, x)| *x).collect();
+    assert_eq!(collected[10], second);
+}
+
+#[test]
+fn weighted_picks() {
+    let seed = Seed::new([0x5A; 32]);
+    let numbers: Vec<u32> = (0..100).collect();
+    // The first ten items are a hundred times heavier than the rest
+    let weights = (0..100).map(|x| match x {
+        0..=9 => 100,
+        99 => 0,
+        _ => 1,
+    });
+    let picker = DailyPicker::builder(&numbers)
+        .seed(seed)
+        .window_len(20)
+        .weights(weights)
+        .build_picker()
+        .unwrap();
+
+    let mut heavy = 0;
+    for window in 0..50 {
+        let mut seen = HashSet::new();
+        for day in window * 20..(window + 1) * 20 {
+            let (idx, _) = picker.get_with_index(day);
+            assert_ne!(idx, 99);
+            assert!(seen.insert(idx));
+            assert_eq!(picker.day_of_index(idx, window), Some(day));
+            if idx < 10 {
+                heavy += 1;
+            }
+        }
+    }
+    // Every heavy item is almost always picked in each window
+    assert!(heavy > 450, "heavy items picked {} times", heavy);
+
+    let mismatch = DailyPicker::builder(&numbers)
+        .weights([1, 2])
+        .build_picker();
+    assert!(matches!(mismatch, Err(WordleError::WeightsLenMismatch)));
+}
+
+/// Checks that `day_of_index` returns the first day of each window on which
+/// each item is picked, by listing every day of the window.
+fn assert_first_days(picker: &DailyPicker<'_, u32>, windows: std::ops::Range<u64>) {
+    let len = picker.window_len();
+    let offset = picker.day_offset();
+    for window in windows {
+        let mut first = HashMap::new();
+        for offset_day in window * len..(window + 1) * len {
+            let Some(day) = offset_day.checked_sub(offset) else {
+                continue;
+            };
+            first.entry(picker.get_with_index(day).0).or_insert(day);
+        }
+        for idx in 0..picker.item_count() {
+            let expected = first.get(&idx).copied();
+            assert_eq!(
+                picker.day_of_index(idx, window),
+                expected,
+                "{idx} in {window}"
+            );
+        }
+    }
+}
+
+#[test]
+fn banned_default_window_len() {
+    let numbers: Vec<u32> = (0..22).collect();
+    let banned = [0, 9, 12];
+    for seed in 0..20 {
+        let picker = DailyPicker::builder(&numbers)
+            .seed(Seed::new([seed; 32]))
+            .banned(banned)
+            .build_picker()
+            .unwrap();
+        // Banning doesn't shorten the window, but every allowed item is
+        // still picked in each window
+        assert_eq!(picker.window_len(), 22);
+        let window: HashSet<_> = (0..22).map(|day| *picker.get(day)).collect();
+        assert_eq!(window.len(), 19);
+        assert_first_days(&picker, 0..3);
+
+        // Longer windows repeat items, but find their first day
+        let longer = DailyPicker::builder(&numbers)
+            .seed(Seed::new([seed; 32]))
+            .window_len(22)
+            .banned(banned)
+            .build_picker()
+            .unwrap();
+        assert_first_days(&longer, 0..3);
+    }
+}
+
+#[test]
+fn ban_keeps_default_schedule() {
+    let numbers: Vec<u32> = (0..100).collect();
+    for seed in 0..10 {
+        let builder = || DailyPicker::builder(&numbers).seed(Seed::new([seed; 32]));
+        let picker = builder().build_picker().unwrap();
+        let banned = *picker.get(5);
+        let with_ban = builder().banned([banned as usize]).build_picker().unwrap();
+        assert_eq!(with_ban.window_len(), picker.window_len());
+        // Only the days that picked the banned item change
+        for day in 0..300 {
+            match *picker.get(day) == banned {
+                true => assert_ne!(*with_ban.get(day), banned),
+                false => assert_eq!(with_ban.get(day), picker.get(day), "{day}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn day_of_index_long_windows_with_vetoes() {
+    let numbers: Vec<u32> = (0..5).collect();
+    for seed in 0..40 {
+        let mut picker = DailyPicker::builder(&numbers)
+            .seed(Seed::new([seed; 32]))
+            .window_len(7)
+            .build_picker()
+            .unwrap();
+        assert_first_days(&picker, 0..3);
+        picker.veto(3);
+        picker.veto(9);
+        assert_first_days(&picker, 0..3);
+    }
+}
+#[test]
+fn day_of_index_with_day_offset() {
+    let numbers: Vec<u32> = (0..5).collect();
+    for seed in 0..20 {
+        let picker = DailyPicker::builder(&numbers)
+            .seed(Seed::new([seed; 32]))
+            .window_len(5)
+            .day_offset(3)
+            .build_picker()
+            .unwrap();
+        assert_first_days(&picker, 0..3);
+        // The window of day 0 starts before day 0
+        let (first, _) = picker.get_with_index(0);
+        assert_eq!(picker.day_of_index(first, 0), Some(0));
+    }
+}
+
+#[test]
+fn constant_time_picks() {
+    let numbers: Vec<u32> = (0..300).collect();
+    let builder = |config| {
+        let builder = DailyPicker::builder(&numbers).seed(Seed::new([0x42; 32]));
+        match config {
+            0 => builder.window_len(100),
+            1 => builder.banned([3, 7, 200]),
+            2 => builder.window_len(450),
+            3 => builder.weights((0..300).map(|n| n % 4)).window_len(50),
+            // The network of a pinned capacity is much larger than the items
+            _ => builder.window_len(100).capacity(4000),
+        }
+    };
+    for config in 0..5 {
+        let picker = builder(config).build_picker().unwrap();
+        let constant_time = builder(config).constant_time(true).build_picker().unwrap();
+        assert!(constant_time.is_constant_time());
+        assert_eq!(constant_time.get_range(0..500), picker.get_range(0..500));
+    }
+}
@@ -0,0 +1,81 @@
+use wordle_generator::LetterFeedback::{Absent, Correct, Present};
+use wordle_generator::{evaluate, Palette, PuzzleId, Seed, ShareGrid, Wordle};
+
+#[test]
+fn solved() {
+    let rows = [
+        [Absent, Present, Absent, Absent, Absent],
+        [Present, Absent, Correct, Absent, Present],
+        [Correct; 5],
+    ];
+    let grid = ShareGrid::new(42, &rows);
+    assert!(grid.is_solved());
+    assert_eq!(
+        grid.to_string(),
+        "Wordle 42 3/6\n\n⬛🟨⬛⬛⬛\n🟨⬛🟩⬛🟨\n🟩🟩🟩🟩🟩"
+    );
+}
+
+#[test]
+fn failed() {
+    let rows = [[Absent, Absent, Present, Absent]; 6];
+    let grid = ShareGrid::new(1_000_000, &rows).palette(Palette::LIGHT);
+    assert!(!grid.is_solved());
+    let text = grid.to_string();
+    assert!(text.starts_with("Wordle 1,000,000 X/6\n\n⬜⬜🟨⬜\n"));
+    assert_eq!(text.lines().count(), 8);
+}
+
+#[test]
+fn options() {
+    let rows = [[Present, Correct, Absent], [Correct; 3]];
+    let grid = ShareGrid::new(7, &rows)
+        .title("Lingo")
+        .max_attempts(4)
+        .hard_mode(true)
+        .palette(Palette::HIGH_CONTRAST_DARK);
+    assert_eq!(grid.to_string(), "Lingo 7 2/4*\n\n🟦🟧⬛\n🟧🟧🟧");
+
+    let empty: [[_; 5]; 0] = [];
+    assert_eq!(ShareGrid::new(0, &empty).to_string(), "Wordle 0 X/6");
+}
+
+#[test]
+fn from_generator() {
+    let words = ["apple", "grape", "lemon"];
+    let wordle = Wordle::from_seed(&words, 3, Seed::new([0; 32]));
+    let day = 2;
+    let answer = wordle.get(day);
+    let rows: Vec<[_; 5]> = words
+        .iter()
+        .filter(|&&w| w != answer)
+        .chain([&answer])
+        .map(|guess| evaluate(guess, answer))
+        .collect();
+    let grid = ShareGrid::new(day, &rows).to_string();
+    assert!(grid.starts_with("Wordle 2 3/6\n\n"));
+    // The grid doesn't reveal the answer
+    assert!(!grid.contains(answer));
+}
+
+#[test]
+fn puzzle_numbers() {
+    let words = ["apple", "grape", "lemon"];
+    let wordle = Wordle::builder(&words)
+        .seed(Seed::new([0; 32]))
+        .puzzle_offset(810)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.puzzle_id(2), PuzzleId::new(812));
+    assert_eq!(wordle.day_of_puzzle(PuzzleId::new(812)), Some(2));
+    assert_eq!(wordle.day_of_puzzle(PuzzleId::new(809)), None);
+    assert_eq!(wordle.get_puzzle(2), (PuzzleId::new(812), wordle.get(2)));
+    // Owned and derived generators keep the numbering
+    assert_eq!(wordle.clone().into_owned().puzzle_id(0).get(), 810);
+    assert_eq!(wordle.derive_child(b"room").puzzle_id(0).get(), 810);
+
+    let rows = [evaluate::<5>(wordle.get(2), wordle.get(2))];
+    let grid = wordle.share_grid(2, &rows).to_string();
+    assert!(grid.starts_with("Wordle 812 1/6"));
+    assert_eq!(PuzzleId::new(1_234_567).to_string(), "1,234,567");
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "solver")]
+
+use lazy_static::lazy_static;
+use wordle_generator::solver::{Heuristic, Hint, HintLevel, Solver};
+use wordle_generator::{evaluate, Game};
+
+lazy_static! {
+    static ref WORDS: Vec<&'static str> = include_str!("words.txt").lines().collect();
+}
+
+#[test]
+fn apply() {
+    let answers = ["those", "shoes", "whose", "chose", "hoses"];
+    let mut solver = Solver::new(&answers, &[]);
+    let feedback: [_; 5] = evaluate("crane", "whose");
+    solver.apply("crane", &feedback);
+    assert_eq!(solver.candidates().count(), 2);
+    let feedback: [_; 5] = evaluate("those", "whose");
+    solver.apply("those", &feedback);
+    assert_eq!(solver.candidates().collect::<Vec<_>>(), ["whose"]);
+    assert_eq!(solver.suggest(), Some("whose"));
+
+    let feedback: [_; 5] = evaluate("crane", "chose");
+    solver.apply("crane", &feedback);
+    assert_eq!(solver.suggest(), None);
+}
+
+#[test]
+fn solves_every_answer() {
+    let answers = &WORDS[..200];
+    for heuristic in [Heuristic::Entropy, Heuristic::Minimax] {
+        let solver = Solver::new(answers, &WORDS[..1000]).heuristic(heuristic);
+        for answer in &answers[..5] {
+            let guesses = solver.solve(answer).unwrap();
+            assert_eq!(guesses.last(), Some(answer));
+            assert!(guesses.len() <= 6, "{} took {:?}", answer, guesses);
+        }
+    }
+    let solver = Solver::new(answers, &[]);
+    assert_eq!(solver.solve("zzzzz"), None);
+}
+
+#[test]
+fn play_game() {
+    let answers = &WORDS[..200];
+    let mut game = Game::new(0, answers[42]);
+    let mut solver = Solver::new(answers, &[]);
+    while !game.is_over() {
+        let guess = solver.suggest().unwrap();
+        let feedback = game.guess(guess).unwrap().to_vec();
+        solver.apply(guess, &feedback);
+    }
+    assert!(game.guesses().last().unwrap().is_correct());
+
+    // A fresh solver catches up with the game
+    let mut fresh = Solver::new(answers, &[]);
+    fresh.apply_game(&game);
+    assert_eq!(fresh.candidates().collect::<Vec<_>>(), [answers[42]]);
+}
+
+#[test]
+fn hints() {
+    let answers = ["those", "shoes", "whose", "chose", "hoses"];
+    let mut game = Game::new(0, "those");
+    let mut solver = Solver::new(&answers, &[]);
+    assert_eq!(
+        solver.hint(&game, HintLevel::Candidates),
+        Hint::Candidates(5)
+    );
+    assert_eq!(solver.hint(&game, HintLevel::Letter), Hint::Letter('t'));
+    assert_eq!(
+        solver.hint(&game, HintLevel::Position),
+        Hint::Position {
+            letter: 't',
+            position: 0
+        }
+    );
+
+    let feedback = game.guess("chose").unwrap().to_vec();
+    solver.apply("chose", &feedback);
+    assert_eq!(
+        solver.hint(&game, HintLevel::Candidates),
+        Hint::Candidates(2)
+    );
+    // Every letter but "t" was found
+    assert_eq!(solver.hint(&game, HintLevel::Letter), Hint::Letter('t'));
+    assert_eq!(
+        solver.hint(&game, HintLevel::Position),
+        Hint::Position {
+            letter: 't',
+            position: 0
+        }
+    );
+    assert_eq!(
+        solver.hint(&game, HintLevel::Answer),
+        Hint::Answer("those".into())
+    );
+}
@@ -0,0 +1,58 @@
+use lazy_static::lazy_static;
+use wordle_generator::stats::uniformity_report;
+use wordle_generator::{Seed, Wordle};
+
+lazy_static! {
+    static ref WORDS: Vec<&'static str> = include_str!("words.txt").lines().collect();
+}
+
+#[test]
+fn uniform() {
+    let words = &WORDS[..100];
+    let wordle = Wordle::from_seed(words, 100, Seed::new([3; 32]));
+    let report = uniformity_report(&wordle, 0..10_000);
+    assert_eq!(report.days, 10_000);
+    assert_eq!(report.windows, 100);
+    assert_eq!(report.window_repeats, 0);
+    // Every complete window picks each word once
+    assert_eq!((report.min_count, report.max_count), (100, 100));
+    assert_eq!(report.chi_square, 0.0);
+    assert_eq!(report.degrees_of_freedom, 99);
+    assert!(report.is_uniform(0.01));
+}
+
+#[test]
+fn long_windows_repeat() {
+    let words = &WORDS[..50];
+    let wordle = Wordle::from_seed(words, 200, Seed::new([3; 32]));
+    let report = uniformity_report(&wordle, 0..20_000);
+    assert!(report.window_repeats > 0);
+    assert!(report.is_uniform(0.001), "{:?}", report.p_value);
+}
+
+#[test]
+fn weights_and_banned() {
+    let words = ["apple", "grape", "lemon", "mango"];
+    let wordle = Wordle::builder(&words)
+        .seed(Seed::new([3; 32]))
+        .weights([1, 2, 0, 1])
+        .window_len(1)
+        .build()
+        .unwrap();
+    let report = uniformity_report(&wordle, 0..8000);
+    assert_eq!(report.counts[2], 0);
+    assert_eq!(report.expected, [2000.0, 4000.0, 0.0, 2000.0]);
+    assert_eq!(report.degrees_of_freedom, 2);
+    assert!(report.is_uniform(0.001), "{:?}", report);
+
+    // A single pickable word has no degrees of freedom
+    let single = Wordle::builder(&words)
+        .seed(Seed::new([3; 32]))
+        .banned([0, 1, 2])
+        .build()
+        .unwrap();
+    let report = uniformity_report(&single, 0..100);
+    assert_eq!(report.degrees_of_freedom, 0);
+    assert_eq!(report.p_value, 1.0);
+    assert_eq!(report.counts, [0, 0, 0, 100]);
+}
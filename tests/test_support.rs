@@ -0,0 +1,53 @@
+#![cfg(feature = "test_support")]
+
+use proptest::prelude::*;
+use wordle_generator::test_support::{
+    check_deterministic, check_window_distinct, seed, window_len, word_list,
+};
+use wordle_generator::{Seed, Wordle, WordleBuilder};
+
+proptest! {
+    #[test]
+    fn windows_are_distinct(
+        words in word_list(1..40),
+        seed in seed(),
+        len in window_len(60),
+        window in 0..1000u64,
+    ) {
+        let wordle = Wordle::from_seed(&words, len, seed);
+        check_window_distinct(&wordle, window)?;
+    }
+
+    #[test]
+    fn banned_words_are_never_picked(
+        words in word_list(2..40),
+        seed in seed(),
+        window in 0..1000u64,
+    ) {
+        let wordle = Wordle::builder(&words)
+            .seed(seed)
+            .banned([0])
+            .build()
+            .unwrap();
+        check_window_distinct(&wordle, window)?;
+    }
+
+    #[test]
+    fn instances_are_deterministic(words in word_list(1..40), seed in seed(), len in window_len(60)) {
+        let borrowed = Wordle::from_seed(&words, len, seed);
+        let owned = WordleBuilder::from_vec(words.clone())
+            .window_len(len)
+            .seed(seed)
+            .build()
+            .unwrap();
+        check_deterministic(&borrowed, &owned, 0..200)?;
+    }
+}
+
+#[test]
+fn detects_differences() {
+    let words = ["apple", "grape", "lemon"];
+    let a = Wordle::from_seed(&words, 3, Seed::new([0; 32]));
+    let b = Wordle::from_seed(&words, 3, Seed::new([1; 32]));
+    assert!(check_deterministic(&a, &b, 0..30).is_err());
+}
@@ -0,0 +1,89 @@
+use lazy_static::lazy_static;
+use wordle_generator::test_vectors::{self, FEISTEL_INPUTS};
+use wordle_generator::{Algorithm, Seed, Wordle, WordleError};
+
+lazy_static! {
+    static ref WORDS: Vec<&'static str> = include_str!("words.txt").lines().collect();
+}
+
+#[test]
+fn known_answers() {
+    let wordle = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .algorithm(Algorithm::V3)
+        .build()
+        .unwrap();
+    let kat = test_vectors::generate(&WORDS, &wordle.config(), 363..367).unwrap();
+    let lines: Vec<_> = kat.lines().collect();
+    assert_eq!(lines[0], "# wordle-generator known-answer tests v1");
+    assert!(lines.contains(&"algorithm = V3"));
+    assert!(lines.contains(&format!("commitment = {}", wordle.commitment()).as_str()));
+    // The days span two windows
+    assert!(lines.contains(&"[window 0]"));
+    assert!(lines.contains(&"[window 1]"));
+    assert!(lines.contains(&"bit_len = 13"));
+    let permuted = lines
+        .iter()
+        .filter(|line| line.starts_with("permute "))
+        .count();
+    assert_eq!(permuted as u64, 2 * FEISTEL_INPUTS);
+    let days = &lines[lines.iter().position(|&line| line == "[days]").unwrap() + 1..];
+    assert_eq!(days.len(), 4);
+    for (day, line) in (363..).zip(days) {
+        let (idx, word) = wordle.picker().get_with_index(day);
+        assert_eq!(*line, format!("{} = {} {}", day, idx, word));
+    }
+    assert!(kat.ends_with('\n'));
+
+    // The output is canonical
+    let again = test_vectors::generate(&WORDS, &wordle.config(), 363..367).unwrap();
+    assert_eq!(again, kat);
+    // Unpinned generators have no capacity lines
+    assert!(!kat.contains("\ncapacity = "));
+
+    let pinned = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .capacity(6000)
+        .appended(730, 5000)
+        .build()
+        .unwrap();
+    let kat = test_vectors::generate(&WORDS, &pinned.config(), 728..732).unwrap();
+    assert!(kat.contains("\ncapacity = 6000\nappends = 730:5000\nwords = 5757\n"));
+}
+
+#[test]
+fn known_answers_windowless() {
+    let wordle = Wordle::builder(&WORDS[..100])
+        .seed(Seed::new([1; 32]))
+        .windowless()
+        .label(b"daily-word")
+        .rotate_seed(5, Seed::new([2; 32]))
+        .build()
+        .unwrap();
+    let kat = test_vectors::generate(&WORDS[..100], &wordle.config(), 0..10).unwrap();
+    assert!(kat.contains("\nlabel = 6461696c792d776f7264\n"));
+    assert!(kat.contains("\nwindowless = true\n"));
+    assert!(kat.contains("\nrotations = 5:"));
+    // Every seed has a single network over the full 64-bit space
+    assert_eq!(kat.matches("\n[window ").count(), 2);
+    assert_eq!(
+        kat.matches("\ndomain_len = 18446744073709551616\n").count(),
+        2
+    );
+}
+
+#[test]
+fn known_answers_invalid() {
+    let config = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .build()
+        .unwrap()
+        .config();
+    let empty: [&str; 0] = [];
+    assert_eq!(
+        test_vectors::generate(&empty, &config, 0..1),
+        Err(WordleError::EmptyWords)
+    );
+}
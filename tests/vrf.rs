@@ -0,0 +1,41 @@
+#![cfg(feature = "vrf")]
+
+use wordle_generator::vrf::{DayProof, VrfPublicKey, VrfWordle};
+use wordle_generator::Seed;
+
+const WORDS: [&str; 5] = ["apple", "grape", "lemon", "mango", "peach"];
+
+#[test]
+fn proofs_verify() {
+    let wordle = VrfWordle::new(&WORDS, Seed::new([7; 32])).unwrap();
+    let public_key = VrfPublicKey::from_bytes(&wordle.public_key().to_bytes()).unwrap();
+    for day in 0..20 {
+        let (word, proof) = wordle.get(day);
+        let proof = DayProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert!(public_key.verify(&WORDS, day, word, &proof));
+        // Proofs are deterministic in their output
+        assert_eq!(wordle.get(day).0, word);
+    }
+}
+
+#[test]
+fn wrong_answers_fail() {
+    let wordle = VrfWordle::new(&WORDS, Seed::new([7; 32])).unwrap();
+    let public_key = wordle.public_key();
+    let (word, proof) = wordle.get(3);
+    let wrong = WORDS.iter().find(|&&other| other != word).unwrap();
+    assert!(!public_key.verify(&WORDS, 3, wrong, &proof));
+    assert!(!public_key.verify(&WORDS, 4, word, &proof));
+
+    let other = VrfWordle::new(&WORDS, Seed::new([8; 32])).unwrap();
+    assert_ne!(other.public_key(), public_key);
+    assert!(!other.public_key().verify(&WORDS, 3, word, &proof));
+}
+
+#[test]
+fn covers_words() {
+    let wordle = VrfWordle::new(&WORDS, Seed::new([7; 32])).unwrap();
+    for word in WORDS {
+        assert!((0..100).any(|day| wordle.get(day).0 == word));
+    }
+}
@@ -0,0 +1,417 @@
+use std::fs;
+use wordle_generator::{
+    CaseFolding, DuplicatePolicy, ListFingerprint, Normalizer, ParseOptions, Seed, ViolationKind,
+    WordList, WordListError, WordListValidator, Wordle, WordleBuilder,
+};
+
+#[test]
+fn parse() {
+    let text = "\u{FEFF}# A comment\r\napple\r\n\r\n  grape\t\nlemon # sour\nmango";
+    let words = WordList::parse(text).unwrap();
+    assert_eq!(words.as_slice(), ["apple", "grape", "lemon", "mango"]);
+    assert_eq!(words.len(), 4);
+    assert_eq!(words.line_of(1), Some(4));
+    assert_eq!(words.line_of(4), None);
+    assert_eq!(words.into_vec().len(), 4);
+
+    let words = WordList::parse("apple\ngrape\n").unwrap();
+    assert_eq!(words.as_slice(), ["apple", "grape"]);
+}
+
+#[test]
+fn parse_options() {
+    let options = ParseOptions::new().comments(false);
+    let words = WordList::parse_with("c#\nf#\n", &options).unwrap();
+    assert_eq!(words.as_slice(), ["c#", "f#"]);
+
+    let options = ParseOptions::new().trim(false);
+    let err = WordList::parse_with("apple\n grape\n", &options).unwrap_err();
+    assert!(matches!(err, WordListError::Whitespace { line: 2 }));
+
+    let options = ParseOptions::new().skip_blank_lines(false);
+    let err = WordList::parse_with("apple\n\ngrape\n", &options).unwrap_err();
+    assert!(matches!(err, WordListError::BlankLine { line: 2 }));
+    // Comment-only lines are blank
+    let err = WordList::parse_with("# Fruits\napple\n", &options).unwrap_err();
+    assert!(matches!(err, WordListError::BlankLine { line: 1 }));
+    // A trailing newline doesn't end in a blank line
+    assert!(WordList::parse_with("apple\r\n", &options).is_ok());
+}
+
+#[test]
+fn parse_errors() {
+    let err = WordList::parse("apple\nice cream\n").unwrap_err();
+    assert!(matches!(err, WordListError::Whitespace { line: 2 }));
+    assert_eq!(
+        err.to_string(),
+        "line 2 should have a single word without whitespace"
+    );
+    assert!(matches!(
+        WordList::parse("# Nothing\n\n").unwrap_err(),
+        WordListError::Empty
+    ));
+    assert!(matches!(
+        WordList::parse("").unwrap_err(),
+        WordListError::Empty
+    ));
+
+    let bytes: &[u8] = b"apple\n\xFF\xFE\n";
+    let err = WordList::from_reader(bytes).unwrap_err();
+    assert!(matches!(err, WordListError::InvalidUtf8 { line: 2 }));
+}
+
+#[test]
+fn from_path() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/words.txt");
+    let words = WordList::from_path(path).unwrap();
+    let expected: Vec<_> = include_str!("words.txt").lines().collect();
+    assert_eq!(words.as_slice(), expected);
+
+    let dir = std::env::temp_dir().join(format!("word-list-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("words.txt");
+    fs::write(&path, "apple\r\ngrape\r\n").unwrap();
+    let words = WordList::from_path(&path).unwrap();
+    assert_eq!(words.as_slice(), ["apple", "grape"]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let err = WordList::from_path(dir.join("missing.txt")).unwrap_err();
+    assert!(matches!(err, WordListError::Io(_)));
+}
+
+#[test]
+fn validate() {
+    let words = WordList::from_path(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/words.txt"));
+    let words = words.unwrap();
+    assert_eq!(WordListValidator::new().validate(&words), Ok(()));
+    let validator = WordListValidator::new().alphabet("abcdefghijklmnopqrstuvwxyz");
+    assert_eq!(validator.validate(&words), Ok(()));
+
+    // The length of the first word is expected by default
+    let mut words = WordList::parse("# Fruits\ncrème\nPEACH\nfig\nkiwi!\n").unwrap();
+    let report = WordListValidator::new().validate(&words).unwrap_err();
+    let violations: Vec<_> = report
+        .violations()
+        .iter()
+        .map(|violation| (violation.index, violation.line, violation.kind))
+        .collect();
+    assert_eq!(
+        violations,
+        [
+            (1, 3, ViolationKind::NotLowercase),
+            (
+                2,
+                4,
+                ViolationKind::Length {
+                    expected: 5,
+                    found: 3
+                }
+            ),
+            (3, 5, ViolationKind::InvalidChar('!')),
+        ]
+    );
+    assert_eq!(
+        report.to_string(),
+        "3 invalid words\n\
+         line 3: word should be lowercase\n\
+         line 4: word should have 5 letters, but has 3\n\
+         line 5: invalid character '!'"
+    );
+
+    let validator = WordListValidator::new()
+        .word_len(4)
+        .alphabet("abcdefghijklmnopqrstuvwxyz!")
+        .allow_uppercase(true);
+    let report = validator.validate(&words).unwrap_err();
+    let kinds: Vec<_> = report.violations().iter().map(|v| v.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            ViolationKind::Length {
+                expected: 4,
+                found: 5
+            },
+            ViolationKind::InvalidChar('è'),
+            ViolationKind::Length {
+                expected: 4,
+                found: 5
+            },
+            ViolationKind::InvalidChar('P'),
+            ViolationKind::Length {
+                expected: 4,
+                found: 3
+            },
+            ViolationKind::Length {
+                expected: 4,
+                found: 5
+            },
+        ]
+    );
+
+    words.make_lowercase();
+    assert_eq!(words.as_slice(), ["crème", "peach", "fig", "kiwi!"]);
+}
+
+#[test]
+fn dedup() {
+    let text = "apple\ngrape\napple\nlemon\ngrape\napple\n";
+    let words = WordList::parse(text).unwrap();
+    let duplicates = words.duplicates();
+    let found: Vec<_> = duplicates
+        .iter()
+        .map(|d| (d.index, d.line, d.first_index, d.first_line))
+        .collect();
+    assert_eq!(found, [(2, 3, 0, 1), (4, 5, 1, 2), (5, 6, 0, 1)]);
+
+    let mut kept = words.clone();
+    assert_eq!(kept.dedup(DuplicatePolicy::Keep).unwrap(), duplicates);
+    assert_eq!(kept, words);
+
+    let mut dropped = words.clone();
+    assert_eq!(
+        dropped.dedup(DuplicatePolicy::DropLater).unwrap(),
+        duplicates
+    );
+    assert_eq!(dropped.as_slice(), ["apple", "grape", "lemon"]);
+    assert_eq!(dropped.line_of(2), Some(4));
+
+    let mut rejected = words.clone();
+    match rejected.dedup(DuplicatePolicy::Reject) {
+        Err(WordListError::Duplicate(d)) => assert_eq!(d, duplicates[0]),
+        other => panic!("unexpected result: {:?}", other),
+    }
+    assert_eq!(rejected, words);
+
+    let options = ParseOptions::new().duplicates(DuplicatePolicy::DropLater);
+    let parsed = WordList::parse_with(text, &options).unwrap();
+    assert_eq!(parsed, dropped);
+    let options = ParseOptions::new().duplicates(DuplicatePolicy::Reject);
+    assert!(WordList::parse_with(text, &options).is_err());
+    assert!(WordList::parse_with("apple\nApple\n", &options).is_ok());
+}
+
+#[test]
+fn normalize() {
+    let text = "Ámbar\námbar\nCAMPO\n";
+    let normalizer = Normalizer::new().case_folding(CaseFolding::Lowercase);
+    let mut words = WordList::parse(text).unwrap();
+    words.normalize(&normalizer);
+    assert_eq!(words.as_slice(), ["ámbar", "ámbar", "campo"]);
+
+    let options = ParseOptions::new()
+        .normalizer(normalizer)
+        .duplicates(DuplicatePolicy::DropLater);
+    let words = WordList::parse_with(text, &options).unwrap();
+    assert_eq!(words.as_slice(), ["ámbar", "campo"]);
+    assert_eq!(words.line_of(1), Some(3));
+}
+
+#[test]
+#[cfg(feature = "unicode")]
+fn nfc() {
+    // "é" as a single scalar value, and as "e" and a combining acute accent
+    let composed = "caf\u{e9}";
+    let decomposed = "cafe\u{301}";
+    let normalizer = Normalizer::new().nfc(true);
+    assert_eq!(normalizer.normalize(decomposed), composed);
+    assert_eq!(normalizer.normalize(composed), composed);
+
+    let text = format!("{}\n{}\n", composed, decomposed);
+    let options = ParseOptions::new()
+        .normalizer(normalizer)
+        .duplicates(DuplicatePolicy::Reject);
+    assert!(matches!(
+        WordList::parse_with(&text, &options),
+        Err(WordListError::Duplicate(_))
+    ));
+
+    let turkic = normalizer.case_folding(CaseFolding::Turkic);
+    assert_eq!(turkic.normalize("I\u{307}SLAK"), "islak");
+    assert_eq!(turkic.normalize("\u{c9}T\u{c9}"), "\u{e9}t\u{e9}");
+}
+
+#[test]
+fn weights() {
+    let text = "apple\t120\n# Sour\nlemon\t3  # rare\r\n  grape\t 40 \napple\t7\n";
+    let words = WordList::parse(text).unwrap();
+    assert_eq!(words.as_slice(), ["apple", "lemon", "grape", "apple"]);
+    assert_eq!(words.weights(), Some(&[120, 3, 40, 7][..]));
+
+    let mut dropped = words.clone();
+    dropped.dedup(DuplicatePolicy::DropLater).unwrap();
+    assert_eq!(dropped.weights(), Some(&[120, 3, 40][..]));
+
+    let seed = Seed::new([3; 32]);
+    let wordle = WordleBuilder::from_word_list(&dropped)
+        .seed(seed)
+        .build()
+        .unwrap();
+    let expected = Wordle::builder(&dropped)
+        .seed(seed)
+        .weights([120, 3, 40])
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..30), expected.get_range(0..30));
+    assert_eq!(wordle.config().weights, Some(vec![120, 3, 40]));
+
+    let unweighted = WordList::parse("apple\nlemon\n").unwrap();
+    assert_eq!(unweighted.weights(), None);
+    let wordle = WordleBuilder::from_word_list(&unweighted)
+        .seed(seed)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.config().weights, None);
+
+    let error = |text: &str| WordList::parse(text).unwrap_err();
+    assert!(matches!(
+        error("apple\t1\nlemon\n"),
+        WordListError::MixedWeights { line: 2 }
+    ));
+    assert!(matches!(
+        error("apple\nlemon\t1\n"),
+        WordListError::MixedWeights { line: 2 }
+    ));
+    assert!(matches!(
+        error("apple\t-1\n"),
+        WordListError::InvalidWeight { line: 1 }
+    ));
+    assert!(matches!(
+        error("apple\t1 2\n"),
+        WordListError::InvalidWeight { line: 1 }
+    ));
+    let options = ParseOptions::new().trim(false);
+    assert!(matches!(
+        WordList::parse_with("\t5\n", &options),
+        Err(WordListError::BlankLine { line: 1 })
+    ));
+    assert!(matches!(
+        error("red apple\t5\n"),
+        WordListError::Whitespace { line: 1 }
+    ));
+}
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mapped() {
+    use wordle_generator::MappedWordList;
+
+    let dir = std::env::temp_dir().join(format!("mapped-word-list-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("words.txt");
+    let text = "\u{FEFF}# Fruits\r\napple\t5\r\n\r\n  grape\t1  \r\nlemon\t0 # sour\r\n";
+    fs::write(&path, text).unwrap();
+    // SAFETY: the file is not modified while it is mapped
+    let mapped = unsafe { MappedWordList::open(&path) }.unwrap();
+    let parsed = WordList::parse(text).unwrap();
+    assert_eq!(mapped.len(), 3);
+    assert_eq!(mapped.words(), parsed.as_slice());
+    assert_eq!(mapped.weights(), parsed.weights());
+
+    let words = mapped.words();
+    let seed = Seed::new([1; 32]);
+    let wordle = Wordle::builder(&words)
+        .seed(seed)
+        .weights(mapped.weights().unwrap().iter().copied())
+        .build()
+        .unwrap();
+    let expected = WordleBuilder::from_word_list(&parsed)
+        .seed(seed)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..20), expected.get_range(0..20));
+
+    fs::write(&path, "apple\t5\ngrape\n").unwrap();
+    // SAFETY: the file is not modified while it is mapped
+    let err = unsafe { MappedWordList::open(&path) }.unwrap_err();
+    assert!(matches!(err, WordListError::MixedWeights { line: 2 }));
+    fs::write(&path, "").unwrap();
+    // SAFETY: the file is not modified while it is mapped
+    let err = unsafe { MappedWordList::open(&path) }.unwrap_err();
+    assert!(matches!(err, WordListError::Empty));
+    fs::remove_dir_all(&dir).unwrap();
+
+    // SAFETY: the file doesn't exist
+    let err = unsafe { MappedWordList::open(dir.join("missing.txt")) }.unwrap_err();
+    assert!(matches!(err, WordListError::Io(_)));
+}
+
+#[test]
+#[cfg(feature = "gzip")]
+fn gzip() {
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    let text = include_str!("words.txt");
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(text.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+    assert!(compressed.len() < text.len());
+
+    let expected = WordList::parse(text).unwrap();
+    let words = WordList::from_gzip(compressed.as_slice()).unwrap();
+    assert_eq!(words, expected);
+
+    let dir = std::env::temp_dir().join(format!("gzip-word-list-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("words.txt.gz");
+    fs::write(&path, &compressed).unwrap();
+    assert_eq!(WordList::from_path(&path).unwrap(), expected);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let truncated = &compressed[..compressed.len() / 2];
+    let err = WordList::from_gzip(truncated).unwrap_err();
+    assert!(matches!(err, WordListError::Io(_)));
+}
+
+#[test]
+#[cfg(feature = "zstd")]
+fn zstd() {
+    use ruzstd::encoding::{compress_to_vec, CompressionLevel};
+
+    let text = include_str!("words.txt");
+    let compressed = compress_to_vec(text.as_bytes(), CompressionLevel::Fastest);
+    assert!(compressed.len() < text.len());
+
+    let expected = WordList::parse(text).unwrap();
+    let words = WordList::from_zstd(compressed.as_slice()).unwrap();
+    assert_eq!(words, expected);
+
+    let dir = std::env::temp_dir().join(format!("zstd-word-list-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("words.txt.zst");
+    fs::write(&path, &compressed).unwrap();
+    assert_eq!(WordList::from_path(&path).unwrap(), expected);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let err = WordList::from_zstd(&b"apple\n"[..]).unwrap_err();
+    assert!(matches!(err, WordListError::Io(_)));
+}
+
+#[test]
+fn fingerprint() {
+    let list = WordList::parse("apple\t3\ngrape\t1\n").unwrap();
+    let preserve = Normalizer::new();
+    assert_eq!(
+        list.fingerprint(&preserve),
+        ListFingerprint::new(&["apple", "grape"], &preserve)
+    );
+    // Weights aren't covered
+    let unweighted = WordList::parse("apple\ngrape\n").unwrap();
+    assert_eq!(
+        unweighted.fingerprint(&preserve),
+        list.fingerprint(&preserve)
+    );
+
+    // Lists only match when normalized the same way
+    let lowercase = Normalizer::new().case_folding(CaseFolding::Lowercase);
+    let uppercase = WordList::parse("APPLE\nGRAPE\n").unwrap();
+    assert_ne!(
+        uppercase.fingerprint(&preserve),
+        list.fingerprint(&preserve)
+    );
+    assert_eq!(
+        uppercase.fingerprint(&lowercase),
+        list.fingerprint(&preserve)
+    );
+}
@@ -1,6 +1,15 @@
 use lazy_static::lazy_static;
-use std::collections::HashMap;
-use wordle_generator::{Seed, Wordle};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use wordle_generator::feistel::RoundPolicy;
+use wordle_generator::shuffle::SometimesRecurse;
+use wordle_generator::{
+    Algorithm, CaseFolding, FeistelPermutation, FisherYatesPermutation, KeyedPermutation,
+    ListFingerprint, ListSchedule, MultiWordle, Normalizer, Permutation, PermutationKey,
+    SecurityLevel, Seed, ShuffledRange, TagFilter, Tags, Wordle, WordleBuilder, WordleError,
+};
 
 lazy_static! {
     // D. E. Knuth, The Stanford GraphBase: A platform for combinatorial computing.
@@ -10,8 +19,8 @@ lazy_static! {
 
 #[test]
 fn first_window() {
-    let seed: Seed = [0; 32];
-    let mut wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
 
     assert_eq!(wordle.get(0), "maple");
     assert_eq!(wordle.get(1), "swung");
@@ -22,10 +31,10 @@ fn first_window() {
 
 #[test]
 fn same_seed() {
-    let seed: Seed = [0xF0; 32];
-    let other_seed = seed.clone();
-    let mut first = Wordle::from_seed(&WORDS, 200, seed);
-    let mut second = Wordle::from_seed(&WORDS, 200, other_seed);
+    let seed = Seed::new([0xF0; 32]);
+    let other_seed = seed;
+    let first = Wordle::from_seed(&WORDS, 200, seed);
+    let second = Wordle::from_seed(&WORDS, 200, other_seed);
 
     assert_eq!(first.get(0), second.get(0));
     assert_eq!(first.get(1), second.get(1));
@@ -34,10 +43,10 @@ fn same_seed() {
 
 #[test]
 fn different_seed() {
-    let seed: Seed = [0; 32];
-    let other_seed: Seed = [1; 32];
-    let mut first = Wordle::from_seed(&WORDS, 365, seed);
-    let mut second = Wordle::from_seed(&WORDS, 365, other_seed);
+    let seed = Seed::new([0; 32]);
+    let other_seed = Seed::new([1; 32]);
+    let first = Wordle::from_seed(&WORDS, 365, seed);
+    let second = Wordle::from_seed(&WORDS, 365, other_seed);
 
     assert_ne!(first.get(0), second.get(0));
     assert_ne!(first.get(434484), second.get(434484));
@@ -45,8 +54,8 @@ fn different_seed() {
 
 #[test]
 fn change_windows() {
-    let seed: Seed = [0xF0; 32];
-    let mut wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
 
     assert_eq!(wordle.get(0), "riser");
     assert_eq!(wordle.get(365), "owner");
@@ -57,9 +66,9 @@ fn change_windows() {
 
 #[test]
 fn all_words_before_new_start() {
-    let seed: Seed = [0xAB; 32];
+    let seed = Seed::new([0xAB; 32]);
     let window_len = WORDS.len() as u64;
-    let mut wordle = Wordle::from_seed(&WORDS, window_len, seed);
+    let wordle = Wordle::from_seed(&WORDS, window_len, seed);
 
     let mut occurrences = HashMap::new();
     for i in 1..=5 {
@@ -74,4 +83,1212 @@ fn all_words_before_new_start() {
     }
 }
 
+#[test]
+fn shared_between_threads() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4).map(|_| scope.spawn(|| wordle.get(365))).collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "owner");
+        }
+    });
+}
+
+#[test]
+fn try_from_seed_validates() {
+    let seed = Seed::new([0; 32]);
+    let empty: [&str; 0] = [];
+    assert_eq!(
+        Wordle::try_from_seed(&empty, 365, seed).err(),
+        Some(WordleError::EmptyWords)
+    );
+    assert_eq!(
+        Wordle::try_from_seed(&WORDS, 0, seed).err(),
+        Some(WordleError::ZeroWindowLen)
+    );
+    assert!(Wordle::try_new(&WORDS, 365).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn zero_window_len_panics() {
+    Wordle::from_seed(&WORDS, 0, Seed::new([0; 32]));
+}
+
+#[test]
+fn builder_matches_from_seed() {
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .build()
+        .unwrap();
+
+    assert_eq!(wordle.get(0), "maple");
+    assert_eq!(wordle.get(364), "delve");
+}
+
+#[test]
+fn builder_day_offset() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .day_offset(365)
+        .build()
+        .unwrap();
+
+    assert_eq!(wordle.get(0), "owner");
+    assert_eq!(wordle.get(64), "fasts");
+}
+
+#[test]
+fn builder_rounds() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::builder(&WORDS)
+        .seed(seed)
+        .rounds::<12>()
+        .build()
+        .unwrap();
+
+    let mut seen = HashSet::new();
+    for day in 0..WORDS.len() as u64 {
+        assert!(seen.insert(wordle.get(day)));
+    }
+    assert!(matches!(
+        Wordle::builder(&WORDS).rounds::<0>().build(),
+        Err(WordleError::ZeroRounds)
+    ));
+}
+
+#[test]
+fn builder_runtime_rounds() {
+    let builder = || {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([0xF0; 32]))
+            .window_len(365)
+    };
+    let check = |rounds, fixed: Vec<&str>| {
+        let wordle = builder().runtime_rounds(rounds).build().unwrap();
+        assert_eq!(wordle.config().rounds, rounds);
+        assert_eq!(wordle.get_range(0..400), fixed);
+    };
+    check(
+        3,
+        builder().rounds::<3>().build().unwrap().get_range(0..400),
+    );
+    check(8, builder().build().unwrap().get_range(0..400));
+    check(
+        12,
+        builder().rounds::<12>().build().unwrap().get_range(0..400),
+    );
+    assert!(matches!(
+        builder().runtime_rounds(0).build(),
+        Err(WordleError::ZeroRounds)
+    ));
+}
+
+#[test]
+fn owned_words() {
+    let seed = Seed::new([0; 32]);
+    let words: Vec<String> = WORDS.iter().map(|word| word.to_string()).collect();
+    let owned: Wordle<'static, String> = WordleBuilder::from_vec(words)
+        .seed(seed)
+        .window_len(365)
+        .build()
+        .unwrap();
+    let borrowed = Wordle::from_seed(&WORDS, 365, seed);
+
+    assert_eq!(owned.get(0), "maple");
+    assert_eq!(owned.get(364), borrowed.get(364));
+    assert_eq!(borrowed.into_owned().get(246), "yogis");
+}
+
+#[test]
+fn iter_matches_get() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    for (day, word) in wordle.iter_from(300).take(500) {
+        assert_eq!(word, wordle.get(day));
+    }
+    let mut iter = wordle.iter_from(u64::MAX);
+    assert_eq!(iter.next().map(|(day, _)| day), Some(u64::MAX));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn get_range() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    let words = wordle.get_range(360..730);
+    assert_eq!(words.len(), 370);
+    assert_eq!(words[5], "owner");
+    assert_eq!(words[369], wordle.get(729));
+    assert!(wordle.get_range(5..5).is_empty());
+}
+
+#[test]
+fn day_of() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    assert_eq!(wordle.day_of("riser", 0), Some(0));
+    assert_eq!(wordle.day_of("owner", 1), Some(365));
+    assert_eq!(wordle.day_of("fasts", 1), Some(429));
+    assert_eq!(wordle.day_of("oared", 39), Some(14556));
+    assert_eq!(wordle.day_of("zzzzz", 0), None);
+    for day in 0..365 {
+        assert_eq!(wordle.day_of(wordle.get(day), 0), Some(day));
+    }
+    let scheduled = (0..365).map(|day| wordle.get(day)).collect::<HashSet<_>>();
+    let unscheduled = WORDS
+        .iter()
+        .find(|word| !scheduled.contains(*word))
+        .unwrap();
+    assert_eq!(wordle.day_of(unscheduled, 0), None);
+}
+
+#[test]
+fn get_with_index() {
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    for day in [0, 1, 246, 364, 1000] {
+        let (idx, word) = wordle.get_with_index(day);
+        assert_eq!(WORDS[idx], word);
+        assert_eq!(wordle.get(day), word);
+    }
+}
+
+#[test]
+fn get_many() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    let words = wordle.get_many(10, 8);
+    assert_eq!(words.len(), 8);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 8);
+    assert_eq!(wordle.get_many(10, 4), words[..4]);
+    assert_eq!(wordle.get_many(10, 8), words);
+    assert_ne!(wordle.get_many(11, 8), words);
+}
+
+#[test]
+fn window_longer_than_words() {
+    let seed = Seed::new([0x21; 32]);
+    let words = ["apple", "grape", "lemon", "melon", "peach"];
+    let wordle = Wordle::from_seed(&words, 40, seed);
+
+    for day in 0..200 {
+        assert!(words.contains(&wordle.get(day)));
+    }
+}
+
+#[test]
+fn repeat_gap() {
+    let seed = Seed::new([0x3C; 32]);
+    let words = &WORDS[..40];
+    let plain = Wordle::builder(words)
+        .seed(seed)
+        .window_len(30)
+        .build()
+        .unwrap();
+    let wordle = Wordle::builder(words)
+        .seed(seed)
+        .window_len(30)
+        .repeat_gap(10)
+        .build()
+        .unwrap();
+
+    let schedule = wordle.get_range(0..300);
+    for (day, word) in schedule.iter().enumerate() {
+        let recent = &schedule[day.saturating_sub(10)..day];
+        assert!(!recent.contains(word), "{} repeats on day {}", word, day);
+        assert_eq!(wordle.day_of(word, day as u64 / 30), Some(day as u64));
+    }
+    // The days of the first window and the last days of each window are unchanged
+    for day in (0..30).chain(290..300) {
+        assert_eq!(wordle.get(day), plain.get(day));
+    }
+    let too_long = Wordle::builder(words).window_len(30).repeat_gap(11).build();
+    assert!(matches!(too_long, Err(WordleError::RepeatGapTooLong)));
+}
+
+#[test]
+fn window_introspection() {
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    assert_eq!(wordle.window_of(0), 0);
+    assert_eq!(wordle.window_of(364), 0);
+    assert_eq!(wordle.window_of(365), 1);
+    assert_eq!(wordle.window_bounds(400), 365..730);
+    assert_eq!(wordle.days_remaining_in_window(400), 330);
+    assert_eq!(wordle.days_remaining_in_window(729), 1);
+
+    let shifted = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .day_offset(300)
+        .build()
+        .unwrap();
+    assert_eq!(shifted.window_of(64), 0);
+    assert_eq!(shifted.window_of(65), 1);
+    assert_eq!(shifted.window_bounds(10), 0..65);
+    assert_eq!(shifted.window_bounds(100), 65..430);
+}
+
+#[test]
+fn window_words() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+
+    let words = wordle.window_words(1);
+    assert_eq!(words.len(), 365);
+    assert_eq!(words[0], "owner");
+    assert_eq!(words[64], "fasts");
+    assert_eq!(words, wordle.get_range(365..730));
+}
+
+#[test]
+fn derive_child() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let alice = wordle.derive_child(b"alice");
+    let other = Wordle::from_seed(&WORDS, 365, seed);
+
+    assert_eq!(
+        alice.get_range(0..30),
+        other.derive_child(b"alice").get_range(0..30)
+    );
+    assert_ne!(alice.get_range(0..30), wordle.get_range(0..30));
+    assert_ne!(
+        alice.get_range(0..30),
+        wordle.derive_child(b"bob").get_range(0..30)
+    );
+    assert_eq!(alice.window_bounds(400), 365..730);
+}
+
+#[test]
+fn filtered() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let mut tags = Tags::new();
+    for (idx, word) in WORDS.iter().enumerate() {
+        if word.contains('z') {
+            tags.insert(idx, "z");
+        }
+        if word.starts_with('s') {
+            tags.insert(idx, "s");
+        }
+    }
+    let filter = TagFilter::new().all_of(["z"]).none_of(["s"]);
+    let matching = |word: &&str| word.contains('z') && !word.starts_with('s');
+    let count = WORDS.iter().copied().filter(matching).count() as u64;
+    assert_eq!(count, 114);
+
+    let themed = wordle.filtered(&tags, &filter).unwrap();
+    assert_eq!(themed.window_len(), count);
+    let window = themed.get_range(0..count);
+    assert!(window.iter().all(matching));
+    assert_eq!(window.iter().collect::<HashSet<_>>().len() as u64, count);
+    assert_ne!(window, themed.get_range(count..2 * count));
+
+    // The schedule only depends on the seed, the filter and the matching words
+    let mut other_tags = tags.clone();
+    other_tags.insert(0, "unrelated");
+    let filter_again = TagFilter::new().none_of(["s"]).all_of(["z"]);
+    let other = Wordle::from_seed(&WORDS, 365, seed);
+    let again = other.filtered(&other_tags, &filter_again).unwrap();
+    assert_eq!(again.get_range(0..400), themed.get_range(0..400));
+
+    let unfiltered = wordle.filtered(&tags, &TagFilter::new()).unwrap();
+    assert_eq!(unfiltered.window_len(), 365);
+    assert_ne!(unfiltered.get_range(0..30), wordle.get_range(0..30));
+
+    let none = TagFilter::new().all_of(["missing"]);
+    assert_eq!(
+        wordle.filtered(&tags, &none).unwrap_err(),
+        WordleError::AllBanned
+    );
+}
+
+#[test]
+fn multiple_lists() {
+    let seed = Seed::new([0xF0; 32]);
+    let (easy, hard) = WORDS.split_at(4000);
+    let lists = || {
+        vec![
+            Wordle::from_seed(easy, 30, seed.derive_child(b"easy")),
+            Wordle::from_seed(hard, 30, seed.derive_child(b"hard")),
+        ]
+    };
+
+    let schedule = ListSchedule::Windows { window_len: 30 };
+    let wordle = MultiWordle::new(seed, lists(), schedule).unwrap();
+    let mut windows = [0; 2];
+    for window in 0..20 {
+        let (list, _) = wordle.locate(window * 30);
+        windows[list] += 1;
+        for day in window * 30..(window + 1) * 30 {
+            let (day_list, word) = wordle.get_with_list(day);
+            assert_eq!(day_list, list);
+            assert!([easy, hard][list].contains(&word));
+        }
+        // Each list is used once per turn of two windows
+        if window % 2 == 1 {
+            assert_eq!(windows, [window / 2 + 1; 2]);
+        }
+    }
+    let first_lists: Vec<_> = (0..20).map(|w| wordle.locate(w * 30).0).collect();
+    let other = MultiWordle::new(Seed::new([0x0F; 32]), lists(), schedule).unwrap();
+    let other_lists: Vec<_> = (0..20).map(|w| other.locate(w * 30).0).collect();
+    assert_ne!(first_lists, other_lists);
+    // Each list goes through its own windows without gaps
+    let (list, _) = wordle.locate(0);
+    let turn_days: Vec<_> = (0..60)
+        .map(|day| wordle.locate(day))
+        .filter(|&(l, _)| l == list)
+        .map(|(_, list_day)| list_day)
+        .collect();
+    assert_eq!(turn_days, (0..30).collect::<Vec<_>>());
+
+    // Day 0 is a Thursday, so Saturdays and Sundays are at indices 2 and 3
+    let schedule = ListSchedule::Weekdays([0, 0, 1, 1, 0, 0, 0]);
+    let wordle = MultiWordle::new(seed, lists(), schedule).unwrap();
+    assert_eq!(wordle.locate(2), (1, 0));
+    assert_eq!(wordle.locate(3), (1, 1));
+    assert_eq!(wordle.locate(9), (1, 2));
+    assert_eq!(wordle.locate(11), (0, 7));
+    let weekends: Vec<_> = (0..15)
+        .flat_map(|week| [week * 7 + 2, week * 7 + 3])
+        .map(|day| wordle.get(day))
+        .collect();
+    assert_eq!(weekends, wordle.lists()[1].get_range(0..30));
+    assert_eq!(weekends.iter().collect::<HashSet<_>>().len(), 30);
+
+    let error = |schedule| MultiWordle::new(seed, lists(), schedule).unwrap_err();
+    assert_eq!(
+        error(ListSchedule::Windows { window_len: 0 }),
+        WordleError::ZeroWindowLen
+    );
+    assert_eq!(
+        error(ListSchedule::Weekdays([0, 0, 0, 0, 0, 2, 0])),
+        WordleError::ListOutOfBounds(2)
+    );
+    let empty: Vec<Wordle<&str>> = Vec::new();
+    assert_eq!(
+        MultiWordle::new(seed, empty, schedule).unwrap_err(),
+        WordleError::NoLists
+    );
+}
+
+#[test]
+fn rotate_seed() {
+    let seed = Seed::new([0xF0; 32]);
+    let new_seed = Seed::new([0x0F; 32]);
+    let old = Wordle::from_seed(&WORDS, 365, seed);
+    let new = Wordle::from_seed(&WORDS, 365, new_seed);
+    let mut wordle = Wordle::from_seed(&WORDS, 365, seed);
+    wordle.rotate_seed(400, new_seed);
+
+    assert_eq!(wordle.get_range(0..400), old.get_range(0..400));
+    assert_eq!(wordle.get_range(400..1000), new.get_range(400..1000));
+    for (day, word) in wordle.iter_from(390).take(20) {
+        assert_eq!(word, wordle.get(day));
+    }
+    let window = wordle.window_words(1);
+    assert_eq!(window[..35], old.window_words(1)[..35]);
+    assert_eq!(window[35..], new.window_words(1)[35..]);
+    assert_eq!(wordle.day_of(window[40], 1), Some(405));
+
+    let built = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .rotate_seed(400, new_seed)
+        .build()
+        .unwrap();
+    assert_eq!(built.get_range(0..1000), wordle.get_range(0..1000));
+}
+
+#[test]
+fn panic_free_api() {
+    let seed = Seed::new([0xF0; 32]);
+    let words = ["apple", "grape", "lemon"];
+    let wordle = Wordle::from_seed(&words, 2, seed);
+
+    assert_eq!(
+        wordle.try_get_many(0, 4),
+        Err(WordleError::NotEnoughWords {
+            requested: 4,
+            available: 3
+        })
+    );
+    assert_eq!(wordle.try_get_many(0, 3).unwrap().len(), 3);
+    assert_eq!(wordle.day_of("apple", u64::MAX), None);
+    assert_eq!(wordle.get_range(u64::MAX - 1..u64::MAX).len(), 1);
+    wordle.get(u64::MAX);
+
+    let err = WordleError::from("not a seed".parse::<Seed>().unwrap_err());
+    assert!(matches!(err, WordleError::InvalidSeed(_)));
+    assert!(std::error::Error::source(&err).is_some());
+}
+
+#[test]
+fn clone_debug_and_accessors() {
+    let seed = Seed::new([0xF0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let clone = wordle.clone();
+
+    assert_eq!(clone.get_range(0..100), wordle.get_range(0..100));
+    assert_eq!(wordle.word_count(), WORDS.len());
+    assert_eq!(wordle.window_len(), 365);
+    assert_eq!(wordle.seed_fingerprint(), seed.fingerprint());
+
+    let debug = format!("{:?}", wordle);
+    assert!(debug.contains("window_len: 365"));
+    assert!(debug.contains(&format!("{:016x}", seed.fingerprint())));
+    assert!(!debug.contains(&seed.to_string()));
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+    let seed = Seed::new([0; 32]);
+    let wordle = Arc::new(Wordle::from_seed(&WORDS, 365, seed));
+    assert_send_sync(&wordle);
+
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            let wordle = Arc::clone(&wordle);
+            thread::spawn(move || wordle.get(i * 100).to_string())
+        })
+        .collect();
+    let words: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(
+        words,
+        wordle
+            .get_range(0..400)
+            .into_iter()
+            .step_by(100)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn windowless() {
+    let seed = Seed::new([0; 32]);
+    let mut wordle = Wordle::builder(&WORDS)
+        .seed(seed)
+        .windowless()
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..4), ["nines", "champ", "blade", "dosed"]);
+    assert_eq!(wordle.window_len(), 1);
+    assert!(wordle.picker().is_windowless());
+
+    let words: Vec<_> = wordle
+        .iter_from(0)
+        .take(2000)
+        .map(|(_, w)| w.to_string())
+        .collect();
+    for (day, word) in words.iter().enumerate() {
+        assert_eq!(wordle.get(day as u64), word);
+        assert_eq!(wordle.day_of(word, day as u64), Some(day as u64));
+    }
+    // Unlike windowed schedules, the words don't repeat with a period of len
+    let len = WORDS.len() as u64;
+    assert_ne!(wordle.get_range(len..len + 100), words[..100]);
+
+    let before = wordle.get(10).to_string();
+    let replacement = wordle.veto(10).to_string();
+    assert_ne!(before, replacement);
+    assert_eq!(wordle.get(10), replacement);
+    assert_eq!(wordle.get_range(0..10), words[..10]);
+    assert_eq!(wordle.get_range(11..2000), words[11..]);
+
+    let banned = Wordle::builder(&WORDS)
+        .seed(seed)
+        .windowless()
+        .banned_words([words[0].as_str()])
+        .build()
+        .unwrap();
+    assert!(!banned.get_range(0..2000).contains(&words[0].as_str()));
+    assert_eq!(banned.get(1), words[1]);
+}
+
+#[test]
+fn pinned_algorithm() {
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .algorithm(Algorithm::V1)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.algorithm(), Algorithm::V1);
+    assert_eq!(wordle.get(0), "maple");
+    assert_eq!(wordle.get(364), "delve");
+
+    let default = Wordle::from_seed(&WORDS, 365, seed);
+    assert_eq!(default.algorithm(), Algorithm::V1);
+}
+
+#[test]
+fn little_endian_algorithm() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V2)
+    };
+    // These vectors hold on every host
+    let wordle = builder(0).build().unwrap();
+    assert_eq!(wordle.get_range(0..3), ["maple", "swung", "koala"]);
+    assert_eq!(wordle.get(364), "delve");
+    let wordle = builder(0xF0).rounds::<12>().veto(3).build().unwrap();
+    assert_eq!(
+        wordle.get_range(0..5),
+        ["excon", "logic", "madly", "posse", "cream"]
+    );
+    assert_eq!(wordle.get_many(5, 3), ["livid", "wonks", "aquae"]);
+    let wordle = builder(0).windowless().build().unwrap();
+    assert_eq!(wordle.get_range(0..4), ["nines", "champ", "blade", "dosed"]);
+
+    if cfg!(target_endian = "little") {
+        let legacy = Wordle::from_seed(&WORDS, 365, Seed::new([0xAB; 32]));
+        let wordle = builder(0xAB).build().unwrap();
+        assert_eq!(wordle.get_range(0..1000), legacy.get_range(0..1000));
+    }
+}
+
+#[test]
+fn unbalanced_algorithm() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V3)
+    };
+    // The 5757 words fit in a 13-bit network, instead of a 14-bit one
+    let wordle = builder(0).build().unwrap();
+    assert_eq!(wordle.get_range(0..3), ["steel", "awoke", "colon"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+
+    let v2 = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .algorithm(Algorithm::V2)
+        .build()
+        .unwrap();
+    assert_ne!(wordle.get_range(0..365), v2.get_range(0..365));
+
+    // Windows longer than the list still pick every word
+    let words = &WORDS[..300];
+    let wordle = Wordle::builder(words)
+        .window_len(300)
+        .algorithm(Algorithm::V3)
+        .build()
+        .unwrap();
+    let picked: HashSet<_> = wordle.window_words(0).into_iter().collect();
+    assert_eq!(picked.len(), 300);
+}
+
+#[test]
+fn siphash_algorithm() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V4)
+    };
+    let wordle = builder(0).build().unwrap();
+    assert_eq!(wordle.get_range(0..3), ["dough", "deity", "asset"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let v3 = builder(0).algorithm(Algorithm::V3).build().unwrap();
+    assert_ne!(wordle.get_range(0..365), v3.get_range(0..365));
+
+    let wordle = builder(0).windowless().build().unwrap();
+    assert_eq!(wordle.get_range(0..4), ["guild", "sebum", "coxed", "inner"]);
+}
+
+#[test]
+fn whitening() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V3)
+            .whitening(true)
+    };
+    let wordle = builder(0).build().unwrap();
+    assert!(wordle.picker().is_whitened());
+    assert_eq!(wordle.get_range(0..3), ["rough", "smote", "lager"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let plain = builder(0).whitening(false).build().unwrap();
+    assert_ne!(wordle.get_range(0..365), plain.get_range(0..365));
+    assert_ne!(wordle.commitment(), plain.commitment());
+    let restored = Wordle::builder(&WORDS)
+        .config(wordle.config())
+        .build()
+        .unwrap();
+    assert_eq!(restored.get_range(0..365), wordle.get_range(0..365));
+
+    let wordle = builder(0).windowless().build().unwrap();
+    assert_eq!(wordle.get_range(0..4), ["ester", "miner", "perdu", "orbit"]);
+
+    // Whitening is independent of the round function
+    let siphash = builder(0).algorithm(Algorithm::V4).build().unwrap();
+    let plain = builder(0)
+        .algorithm(Algorithm::V4)
+        .whitening(false)
+        .build()
+        .unwrap();
+    let words = siphash.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    assert_ne!(siphash.get_range(0..365), plain.get_range(0..365));
+}
+
+#[test]
+fn hmac_sha256_algorithm() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V5)
+    };
+    let wordle = builder(0).build().unwrap();
+    assert_eq!(wordle.get_range(0..3), ["goopy", "birch", "unbar"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let v4 = builder(0).algorithm(Algorithm::V4).build().unwrap();
+    assert_ne!(wordle.get_range(0..365), v4.get_range(0..365));
+}
+
+#[test]
+fn round_policy() {
+    let builder = || {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([0; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V3)
+    };
+    let wordle = builder().round_policy(RoundPolicy::V1).build().unwrap();
+    assert_eq!(wordle.picker().round_policy(), Some(RoundPolicy::V1));
+    assert_eq!(wordle.get_range(0..3), ["sores", "sails", "brava"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let plain = builder().build().unwrap();
+    assert_ne!(wordle.get_range(0..365), plain.get_range(0..365));
+    assert_ne!(wordle.commitment(), plain.commitment());
+    let restored = Wordle::builder(&WORDS)
+        .config(wordle.config())
+        .build()
+        .unwrap();
+    assert_eq!(restored.get_range(0..365), wordle.get_range(0..365));
+    // The configured rounds are a minimum
+    let plain = builder().runtime_rounds(24).build().unwrap();
+    let wordle = builder()
+        .round_policy(RoundPolicy::V1)
+        .runtime_rounds(24)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..365), plain.get_range(0..365));
+
+    // The policy is independent of the round function
+    let siphash = builder()
+        .algorithm(Algorithm::V4)
+        .round_policy(RoundPolicy::V1)
+        .build()
+        .unwrap();
+    let plain = builder().algorithm(Algorithm::V4).build().unwrap();
+    assert_ne!(siphash.get_range(0..365), plain.get_range(0..365));
+}
+
+#[test]
+fn half_siphash_algorithm() {
+    let builder = |seed| {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([seed; 32]))
+            .window_len(365)
+            .algorithm(Algorithm::V6)
+    };
+    let wordle = builder(0).build().unwrap();
+    assert_eq!(wordle.get_range(0..3), ["frame", "emend", "owing"]);
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let v3 = builder(0).algorithm(Algorithm::V3).build().unwrap();
+    assert_ne!(wordle.get_range(0..365), v3.get_range(0..365));
+
+    let wordle = builder(0).windowless().build().unwrap();
+    assert_eq!(wordle.get_range(0..4), ["hussy", "offen", "guess", "nuked"]);
+}
+
+#[test]
+fn security_levels() {
+    let builder = || {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([0; 32]))
+            .window_len(365)
+    };
+    for level in [
+        SecurityLevel::Fast,
+        SecurityLevel::Standard,
+        SecurityLevel::Paranoid,
+    ] {
+        let wordle = builder().security_level(level).build().unwrap();
+        let expected = builder()
+            .algorithm(level.algorithm())
+            .runtime_rounds(level.rounds());
+        let expected = match level.round_policy() {
+            Some(policy) => expected.round_policy(policy),
+            None => expected,
+        };
+        let expected = expected.build().unwrap();
+        assert_eq!(wordle.get_range(0..365), expected.get_range(0..365));
+        let words = wordle.window_words(0);
+        assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    }
+    let config = builder()
+        .security_level(SecurityLevel::Standard)
+        .build()
+        .unwrap()
+        .config();
+    assert_eq!(config.algorithm, Algorithm::V4);
+    assert_eq!(config.rounds, 12);
+    assert_eq!(config.round_policy, Some(RoundPolicy::V1));
+    // Later options override the level
+    let wordle = builder()
+        .security_level(SecurityLevel::Paranoid)
+        .algorithm(Algorithm::V3)
+        .runtime_rounds(8)
+        .build()
+        .unwrap();
+    // The round policy of the level is kept
+    let fast = builder()
+        .security_level(SecurityLevel::Fast)
+        .round_policy(RoundPolicy::V1)
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..365), fast.get_range(0..365));
+}
+
+#[test]
+fn labels() {
+    let builder = || {
+        Wordle::builder(&WORDS)
+            .seed(Seed::new([0; 32]))
+            .window_len(365)
+    };
+    let unlabelled = builder().build().unwrap();
+    assert_eq!(unlabelled.label(), None);
+    let daily = builder().label(b"daily-word").build().unwrap();
+    let bonus = builder().label(b"bonus-word").build().unwrap();
+    assert_eq!(daily.get_range(0..3), ["foils", "decaf", "gases"]);
+    let words = daily.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    assert_ne!(daily.get_range(0..365), bonus.get_range(0..365));
+    assert_ne!(daily.get_range(0..365), unlabelled.get_range(0..365));
+    // An empty label is still a label
+    let empty = builder().label(b"").build().unwrap();
+    assert_ne!(empty.get_range(0..365), unlabelled.get_range(0..365));
+    // Labels apply to vetoes and windowless mode
+    let daily = builder().label(b"daily-word").veto(0).build().unwrap();
+    let bonus = builder().label(b"bonus-word").veto(0).build().unwrap();
+    assert_ne!(daily.get(0), bonus.get(0));
+    let daily = builder().label(b"daily-word").windowless().build().unwrap();
+    let bonus = builder().label(b"bonus-word").windowless().build().unwrap();
+    assert_ne!(daily.get_range(0..365), bonus.get_range(0..365));
+}
+
+#[derive(Clone)]
+struct Identity(u128);
+
+impl Permutation for Identity {
+    fn permute(&self, input: u64) -> u64 {
+        input
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        output
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.0
+    }
+}
+
+impl KeyedPermutation for Identity {
+    fn from_key(domain_len: u128, _key: &PermutationKey<'_>) -> Self {
+        Identity(domain_len)
+    }
+}
+
+#[derive(Clone)]
+struct Shuffle(SometimesRecurse);
+
+impl Permutation for Shuffle {
+    fn permute(&self, input: u64) -> u64 {
+        self.0.permute(input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        self.0.permute_inv(output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.0.domain_len().into()
+    }
+}
+
+impl KeyedPermutation for Shuffle {
+    fn from_key(domain_len: u128, key: &PermutationKey<'_>) -> Self {
+        let mut shuffle = SometimesRecurse::new(domain_len.try_into().unwrap(), 64);
+        let mut bytes = vec![0; 8 * shuffle.keys_mut().len()];
+        key.fill_bytes(&mut bytes);
+        for (key, bytes) in shuffle.keys_mut().iter_mut().zip(bytes.chunks(8)) {
+            *key = u64::from_le_bytes(bytes.try_into().unwrap());
+        }
+        Shuffle(shuffle)
+    }
+}
+
+/// The domain of the last network created by [`Recorded`].
+static NETWORK_LEN: AtomicU64 = AtomicU64::new(0);
+
+/// A Feistel permutation that records the domain of its network.
+#[derive(Clone)]
+struct Recorded(FeistelPermutation);
+
+impl Permutation for Recorded {
+    fn permute(&self, input: u64) -> u64 {
+        self.0.permute(input)
+    }
+
+    fn permute_inv(&self, output: u64) -> u64 {
+        self.0.permute_inv(output)
+    }
+
+    fn domain_len(&self) -> u128 {
+        self.0.domain_len()
+    }
+}
+
+impl KeyedPermutation for Recorded {
+    fn from_key(domain_len: u128, key: &PermutationKey<'_>) -> Self {
+        let permutation = FeistelPermutation::from_key(domain_len, key);
+        let network_len = permutation.domain_len().try_into().unwrap();
+        NETWORK_LEN.store(network_len, Ordering::Relaxed);
+        Recorded(permutation)
+    }
+}
+
+#[test]
+fn capacity_above_u32() {
+    // The network only depends on the domain, not on the width of usize
+    let capacity = 5_000_000_000;
+    let wordle = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .capacity(capacity)
+        .permutation::<Recorded>()
+        .build()
+        .unwrap();
+    wordle.get(0);
+    assert_eq!(NETWORK_LEN.load(Ordering::Relaxed), 1 << 34);
+}
+
+#[test]
+fn custom_permutation() {
+    let wordle = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .permutation::<Identity>()
+        .build()
+        .unwrap();
+    assert_eq!(wordle.get_range(0..3), WORDS[..3]);
+    assert_eq!(wordle.get(365), WORDS[0]);
+    assert_eq!(wordle.day_of(WORDS[5], 1), Some(370));
+
+    let wordle = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .permutation::<Shuffle>()
+        .build()
+        .unwrap();
+    let words = wordle.window_words(0);
+    assert_eq!(words.iter().collect::<HashSet<_>>().len(), 365);
+    let feistel = Wordle::from_seed(&WORDS, 365, Seed::new([0; 32]));
+    assert_ne!(words, feistel.window_words(0));
+}
+
+#[test]
+fn fisher_yates_permutation() {
+    let words = &WORDS[..300];
+    let wordle = Wordle::builder(words)
+        .seed(Seed::new([0; 32]))
+        .window_len(300)
+        .permutation::<FisherYatesPermutation>()
+        .build()
+        .unwrap();
+    let window = wordle.window_words(1);
+    assert_eq!(window.iter().collect::<HashSet<_>>().len(), 300);
+    assert_eq!(wordle.get_range(300..303), window[..3]);
+    assert_eq!(wordle.day_of(window[42], 1), Some(342));
+    let feistel = Wordle::from_seed(words, 300, Seed::new([0; 32]));
+    assert_ne!(window, feistel.window_words(1));
+
+    // Larger lists and windowless pickers fall back to Feistel networks
+    let wordle = Wordle::builder(&WORDS)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .permutation::<FisherYatesPermutation>()
+        .build()
+        .unwrap();
+    let feistel = Wordle::from_seed(&WORDS, 365, Seed::new([0; 32]));
+    assert_eq!(wordle.window_words(0), feistel.window_words(0));
+}
+
+#[test]
+fn commitment() {
+    let seed = Seed::new([0; 32]);
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+    let commitment = wordle.commitment();
+    assert_eq!(commitment.to_string().parse(), Ok(commitment));
+
+    // A third party rebuilds the generator once the seed is revealed
+    let rebuilt = Wordle::from_seed(&WORDS, 365, seed);
+    assert!(rebuilt.verify_answer(0, "maple", &commitment));
+    assert!(!rebuilt.verify_answer(0, "swung", &commitment));
+
+    let other_seed = Wordle::from_seed(&WORDS, 365, Seed::new([1; 32]));
+    assert!(!other_seed.verify_answer(0, other_seed.get(0), &commitment));
+    let other_words = Wordle::from_seed(&WORDS[1..], 365, seed);
+    assert!(!other_words.verify_answer(0, other_words.get(0), &commitment));
+    let other_window = Wordle::from_seed(&WORDS, 364, seed);
+    assert_ne!(other_window.commitment(), commitment);
+    let labelled = Wordle::builder(&WORDS)
+        .seed(seed)
+        .window_len(365)
+        .label(b"daily-word")
+        .build()
+        .unwrap();
+    assert!(!labelled.verify_answer(0, labelled.get(0), &commitment));
+
+    let mut vetoed = Wordle::from_seed(&WORDS, 365, seed);
+    let replacement = vetoed.veto(0).to_string();
+    assert!(!vetoed.verify_answer(0, &replacement, &commitment));
+}
+
+#[test]
+fn commitment_tags_optional_fields() {
+    // Untagged, a label of 8 zero bytes hashes like a capacity of 8 without
+    // appends: both are the integers 8 and 0
+    let words = ["cigar", "rebut", "sissy", "humph"];
+    let labelled = Wordle::builder(&words)
+        .seed(Seed::new([0; 32]))
+        .window_len(4)
+        .label(&[0; 8])
+        .build()
+        .unwrap();
+    let pinned = Wordle::builder(&words)
+        .seed(Seed::new([0; 32]))
+        .window_len(4)
+        .capacity(8)
+        .build()
+        .unwrap();
+    assert_ne!(labelled.get_range(0..8), pinned.get_range(0..8));
+    assert_ne!(labelled.commitment(), pinned.commitment());
+    let commitment = pinned.commitment();
+    assert!(!labelled.verify_answer(0, labelled.get(0), &commitment));
+}
+
+#[test]
+fn list_fingerprint() {
+    let wordle = Wordle::from_seed(&WORDS, 365, Seed::new([0; 32]));
+    let fingerprint = wordle.list_fingerprint();
+    assert_eq!(
+        fingerprint,
+        ListFingerprint::new(&WORDS, &Normalizer::new())
+    );
+    assert_eq!(fingerprint.to_string().parse(), Ok(fingerprint));
+    assert!("abc".parse::<ListFingerprint>().is_err());
+
+    // The fingerprint only covers the words
+    let other_seed = Wordle::from_seed(&WORDS, 364, Seed::new([1; 32]));
+    assert_eq!(other_seed.list_fingerprint(), fingerprint);
+    let other_words = Wordle::from_seed(&WORDS[1..], 365, Seed::new([0; 32]));
+    assert_ne!(other_words.list_fingerprint(), fingerprint);
+    // Words are length-prefixed
+    let normalizer = Normalizer::new();
+    assert_ne!(
+        ListFingerprint::new(&["ab", "c"], &normalizer),
+        ListFingerprint::new(&["a", "bc"], &normalizer)
+    );
+
+    // Words are normalized by the normalizer of the generator
+    let uppercase: Vec<_> = WORDS.iter().map(|word| word.to_uppercase()).collect();
+    let lowercase = Normalizer::new().case_folding(CaseFolding::Lowercase);
+    let folded = Wordle::builder(&uppercase)
+        .seed(Seed::new([0; 32]))
+        .window_len(365)
+        .normalizer(lowercase)
+        .build()
+        .unwrap();
+    assert_eq!(folded.list_fingerprint(), fingerprint);
+    assert_eq!(folded.commitment(), wordle.commitment());
+}
+
+#[test]
+#[cfg(feature = "zeroize")]
+fn zeroize() {
+    use zeroize::Zeroize;
+
+    let mut seed = Seed::new([7; 32]);
+    seed.zeroize();
+    assert_eq!(seed, Seed::new([0; 32]));
+
+    // Wiping the hashers and round keys doesn't change the picks
+    let wordle = Wordle::from_seed(&WORDS, 365, seed);
+    assert_eq!(wordle.get(0), "maple");
+    let owned = wordle.clone().into_owned();
+    drop(wordle);
+    assert_eq!(owned.get(364), "delve");
+}
+
+#[test]
+fn allowed_guesses() {
+    let answers = ["apple", "grape"];
+    let guesses = ["zesty", "aback", "quirk"];
+    let wordle = Wordle::builder(&answers)
+        .seed(Seed::new([0; 32]))
+        .allowed_guesses(&guesses)
+        .build()
+        .unwrap();
+    for word in answers.iter().chain(&guesses) {
+        assert!(wordle.is_valid_guess(word));
+    }
+    assert!(!wordle.is_valid_guess("lemon"));
+    assert!(!wordle.is_valid_guess("Apple"));
+    // Guesses are never picked
+    assert!(wordle.get_range(0..10).iter().all(|w| answers.contains(w)));
+
+    let owned = WordleBuilder::from_vec(answers.map(String::from).into())
+        .seed(Seed::new([0; 32]))
+        .allowed_guesses_vec(guesses.map(String::from).into())
+        .build()
+        .unwrap();
+    assert!(owned.derive_child(b"room").is_valid_guess("quirk"));
+
+    let without = Wordle::from_seed(&answers, 2, Seed::new([0; 32]));
+    assert!(without.is_valid_guess("grape"));
+    assert!(!without.is_valid_guess("zesty"));
+}
+
 // todo: test non-periodicity
+
+#[test]
+fn shuffled_range() {
+    let range = ShuffledRange::new(10, b"a secret key");
+    let order: Vec<_> = range.clone().collect();
+    assert_eq!(order, [9, 6, 0, 5, 8, 4, 7, 3, 2, 1]);
+    let mut sorted = order.clone();
+    sorted.sort();
+    assert!(sorted.into_iter().eq(0..10));
+    let reversed: Vec<_> = range.clone().rev().collect();
+    assert!(reversed.into_iter().eq(order.iter().copied().rev()));
+    assert_eq!(range.clone().nth(4), Some(order[4]));
+    assert_eq!(range.clone().nth(10), None);
+    assert_eq!(range.size_hint(), (10, Some(10)));
+    assert_ne!(
+        ShuffledRange::new(10, b"another key").collect::<Vec<_>>(),
+        order
+    );
+
+    // Meeting in the middle yields every integer once
+    let mut range = ShuffledRange::new(5, b"a secret key");
+    let (first, last) = (range.next(), range.next_back());
+    assert_eq!(range.by_ref().count(), 3);
+    assert_ne!(first, last);
+    assert_eq!(range.next(), None);
+
+    assert_eq!(ShuffledRange::new(0, b"a secret key").next(), None);
+    assert!(ShuffledRange::new(1, b"a secret key").eq([0]));
+    let mut powers: Vec<_> = ShuffledRange::new(64, b"a secret key").collect();
+    powers.sort();
+    assert!(powers.into_iter().eq(0..64));
+
+    let huge = ShuffledRange::new(u64::MAX, b"a secret key");
+    let value = huge.get(u64::MAX - 1);
+    assert!(value < u64::MAX);
+    assert_eq!(huge.position(value), u64::MAX - 1);
+}
+
+#[test]
+fn append_only() {
+    let seed = Seed::new([0; 32]);
+    let builder = |words| {
+        Wordle::builder(words)
+            .seed(seed)
+            .window_len(365)
+            .capacity(6000)
+    };
+    let before = builder(&WORDS[..5000]).build().unwrap();
+    let after = builder(&WORDS).appended(730, 5000).build().unwrap();
+    assert_eq!(after.get_range(0..730), before.get_range(0..730));
+    assert_eq!(after.get_many(100, 20), before.get_many(100, 20));
+    assert_eq!(after.clone().veto(10), before.clone().veto(10));
+    assert_eq!(after.picker().day_of_index(5100, 1), None);
+    // Appended words are picked from the first window on or after the day
+    let appended = (730..1460)
+        .filter(|&day| after.get_with_index(day).0 >= 5000)
+        .count();
+    assert!(appended > 0);
+    let rounded = builder(&WORDS).appended(400, 5000).build().unwrap();
+    assert_eq!(rounded.get_range(0..730), after.get_range(0..730));
+    assert_ne!(after.get_range(730..1460), before.get_range(730..1460));
+
+    // The configuration restores the schedule
+    let config = after.config();
+    assert_eq!(config.capacity, Some(6000));
+    assert_eq!(config.appends, [(730, 5000)]);
+    let restored = Wordle::builder(&WORDS).config(config).build().unwrap();
+    assert_eq!(restored.get_range(700..760), after.get_range(700..760));
+    assert_eq!(restored.commitment(), after.commitment());
+
+    // Windowless generators only pick appended words from the given day
+    let windowless = |words| builder(words).windowless();
+    let before = windowless(&WORDS[..5000]).build().unwrap();
+    let after = windowless(&WORDS).appended(100, 5000).build().unwrap();
+    assert_eq!(after.get_range(0..100), before.get_range(0..100));
+
+    let err = |builder: WordleBuilder<'_, &str>| builder.build().unwrap_err();
+    assert_eq!(
+        err(builder(&WORDS).capacity(5000)),
+        WordleError::CapacityExceeded
+    );
+    assert_eq!(
+        err(Wordle::builder(&WORDS).appended(730, 5000)),
+        WordleError::MissingCapacity
+    );
+    assert_eq!(
+        err(builder(&WORDS).appended(730, 0)),
+        WordleError::AppendOutOfBounds(0)
+    );
+    assert_eq!(
+        err(builder(&WORDS).appended(730, 5758)),
+        WordleError::AppendOutOfBounds(5758)
+    );
+    assert_eq!(
+        err(builder(&WORDS).banned(0..10).appended(730, 10)),
+        WordleError::AllBanned
+    );
+}
@@ -0,0 +1,109 @@
+//! Swift and Kotlin bindings for the `wordle-generator` crate, via UniFFI.
+//!
+//! Apps embed the generator to compute the daily word locally from a
+//! committed seed. The bindings are generated by the `uniffi-bindgen`
+//! binary of this crate.
+
+use std::sync::Arc;
+use wordle_generator::{WordleBuilder, WordleError};
+
+uniffi::setup_scaffolding!();
+
+/// The error type of the fallible functions.
+#[derive(Debug, uniffi::Error)]
+#[uniffi(flat_error)]
+pub enum WordleFfiError {
+    /// The seed is neither a hexadecimal nor a base64 string of 32 bytes.
+    InvalidSeed,
+    /// The configuration is invalid.
+    InvalidConfig(WordleError),
+}
+
+impl std::fmt::Display for WordleFfiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WordleFfiError::InvalidSeed => f.write_str("invalid seed"),
+            WordleFfiError::InvalidConfig(err) => err.fmt(f),
+        }
+    }
+}
+
+/// A cryptographically secure random wordle generator.
+#[derive(uniffi::Object)]
+pub struct Wordle {
+    wordle: wordle_generator::Wordle<'static, String>,
+}
+
+#[uniffi::export]
+impl Wordle {
+    /// Creates a generator over `words`, using a hexadecimal or base64 seed.
+    ///
+    /// The window length defaults to the number of words. The day offset
+    /// shifts the schedule, so that day `0` corresponds to day `day_offset`.
+    #[uniffi::constructor(default(window_len = None, day_offset = 0))]
+    pub fn new(
+        words: Vec<String>,
+        seed: String,
+        window_len: Option<u64>,
+        day_offset: u64,
+    ) -> Result<Arc<Self>, WordleFfiError> {
+        let seed = seed.parse().map_err(|_| WordleFfiError::InvalidSeed)?;
+        let mut builder = WordleBuilder::from_vec(words)
+            .seed(seed)
+            .day_offset(day_offset);
+        if let Some(window_len) = window_len {
+            builder = builder.window_len(window_len);
+        }
+        let wordle = builder.build().map_err(WordleFfiError::InvalidConfig)?;
+        Ok(Arc::new(Self { wordle }))
+    }
+
+    /// Returns the word for the given day.
+    pub fn get(&self, day: u64) -> String {
+        self.wordle.get(day).to_owned()
+    }
+
+    /// Returns the words for every day in `start..end`.
+    pub fn get_range(&self, start: u64, end: u64) -> Vec<String> {
+        let words = self.wordle.get_range(start..end);
+        words.into_iter().map(str::to_owned).collect()
+    }
+
+    /// Returns the number of consecutive days in which no word repeats.
+    pub fn window_len(&self) -> u64 {
+        self.wordle.window_len()
+    }
+
+    /// Returns a fingerprint that identifies the seed without revealing it.
+    pub fn seed_fingerprint(&self) -> u64 {
+        self.wordle.seed_fingerprint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let words: Vec<_> = ["apple", "grape", "lemon"].map(String::from).into();
+        let seed = "07".repeat(32);
+        let wordle = Wordle::new(words.clone(), seed, None, 0).unwrap();
+        let mut range = wordle.get_range(0, 3);
+        assert_eq!(range[1], wordle.get(1));
+        range.sort();
+        assert_eq!(range, words);
+    }
+
+    #[test]
+    fn errors() {
+        let words = vec![String::from("apple")];
+        let result = Wordle::new(words.clone(), "nope".into(), None, 0);
+        assert!(matches!(result, Err(WordleFfiError::InvalidSeed)));
+        let result = Wordle::new(words, "07".repeat(32), Some(0), 0);
+        assert!(matches!(
+            result,
+            Err(WordleFfiError::InvalidConfig(WordleError::ZeroWindowLen))
+        ));
+    }
+}